@@ -0,0 +1,181 @@
+/// How many leading bytes of a body are inspected when sniffing its content.
+const SNIFF_LEN: usize = 8192;
+
+/// Whether a content type is known to hold text.
+pub fn is_text_content_type(content_type: &str) -> bool {
+    let mime = mime_type(content_type);
+    mime.starts_with("text/")
+        || mime.ends_with("+json")
+        || mime.ends_with("+xml")
+        || matches!(
+            mime.as_str(),
+            "application/json"
+                | "application/xml"
+                | "application/javascript"
+                | "application/ecmascript"
+                | "application/x-www-form-urlencoded"
+                | "application/yaml"
+                | "application/x-yaml"
+                | "application/toml"
+                | "application/sql"
+                | "application/graphql"
+        )
+}
+
+/// Whether a content type is known to hold binary data.
+pub fn is_binary_content_type(content_type: &str) -> bool {
+    let mime = mime_type(content_type);
+    if is_text_content_type(&mime) {
+        return false;
+    }
+    ["image/", "audio/", "video/", "font/"]
+        .iter()
+        .any(|prefix| mime.starts_with(prefix))
+        || matches!(
+            mime.as_str(),
+            "application/octet-stream"
+                | "application/pdf"
+                | "application/zip"
+                | "application/gzip"
+                | "application/x-gzip"
+                | "application/x-tar"
+                | "application/x-7z-compressed"
+                | "application/x-bzip2"
+                | "application/x-xz"
+                | "application/zstd"
+                | "application/wasm"
+                | "application/x-protobuf"
+                | "application/protobuf"
+                | "application/vnd.google.protobuf"
+                | "application/grpc"
+                | "application/msword"
+                | "application/vnd.ms-excel"
+                | "application/x-msdownload"
+        )
+        || mime.starts_with("application/vnd.openxmlformats")
+}
+
+/// Whether a response body should be treated as binary rather than text.
+///
+/// The content type decides when it is clearly textual or binary. Otherwise the
+/// start of the body is inspected, and NUL bytes or invalid UTF-8 mark it as binary.
+pub fn is_binary(content_type: Option<&str>, body: &[u8]) -> bool {
+    match content_type {
+        Some(content_type) if is_text_content_type(content_type) => false,
+        Some(content_type) if is_binary_content_type(content_type) => true,
+        _ => looks_binary(body),
+    }
+}
+
+/// Sniff the start of `body` for NUL bytes or invalid UTF-8.
+pub fn looks_binary(body: &[u8]) -> bool {
+    let sample = &body[..body.len().min(SNIFF_LEN)];
+    if sample.contains(&0) {
+        return true;
+    }
+    match std::str::from_utf8(sample) {
+        Ok(_) => false,
+        // A multi-byte character cut off at the end of the sample is still text
+        Err(e) => e.error_len().is_some(),
+    }
+}
+
+/// The lowercase `type/subtype` part of a content type, without parameters.
+pub fn mime_type(content_type: &str) -> String {
+    content_type
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_lowercase()
+}
+
+/// Guess the content type of a text body that arrived without a usable header.
+///
+/// Only the kinds of document that convert differently are told apart: JSON,
+/// HTML, RSS and Atom feeds, other XML, and plain text for everything else.
+pub fn sniff_content_type(text: &str) -> &'static str {
+    let text = text.trim_start_matches('\u{feff}').trim_start();
+    if (text.starts_with('{') || text.starts_with('['))
+        && serde_json::from_str::<serde_json::Value>(text).is_ok()
+    {
+        return "application/json";
+    }
+    if !text.starts_with('<') {
+        return "text/plain";
+    }
+
+    let head: String = text.chars().take(1024).collect::<String>().to_lowercase();
+    if head.starts_with("<!doctype html")
+        || head.starts_with("<html")
+        || head.contains("<head")
+        || head.contains("<body")
+    {
+        "text/html"
+    } else if let Some(feed) = crate::feed_content_type(text) {
+        feed
+    } else if head.starts_with("<?xml") {
+        "application/xml"
+    } else {
+        "text/html"
+    }
+}
+
+/// The code block language for a source file, judged by the extension or name
+/// of the last segment of a path or URL.
+///
+/// Prose formats such as markdown and plain text have no language.
+pub fn source_language(path: &str) -> Option<&'static str> {
+    let path = path.split(['?', '#']).next().unwrap_or_default();
+    let name = path.rsplit('/').next().unwrap_or_default().to_lowercase();
+    match name.as_str() {
+        "dockerfile" => return Some("dockerfile"),
+        "makefile" | "gnumakefile" => return Some("makefile"),
+        _ => {}
+    }
+    let (_, extension) = name.rsplit_once('.')?;
+    let language = match extension {
+        "rs" => "rust",
+        "py" | "pyi" => "python",
+        "js" | "mjs" | "cjs" => "javascript",
+        "jsx" => "jsx",
+        "ts" | "mts" | "cts" => "typescript",
+        "tsx" => "tsx",
+        "go" => "go",
+        "java" => "java",
+        "kt" | "kts" => "kotlin",
+        "scala" => "scala",
+        "c" | "h" => "c",
+        "cc" | "cpp" | "cxx" | "hh" | "hpp" | "hxx" => "cpp",
+        "cs" => "csharp",
+        "swift" => "swift",
+        "rb" => "ruby",
+        "php" => "php",
+        "lua" => "lua",
+        "r" => "r",
+        "dart" => "dart",
+        "hs" => "haskell",
+        "ex" | "exs" => "elixir",
+        "erl" => "erlang",
+        "clj" | "cljs" => "clojure",
+        "sh" | "bash" | "zsh" => "bash",
+        "ps1" => "powershell",
+        "sql" => "sql",
+        "html" | "htm" => "html",
+        "css" => "css",
+        "scss" => "scss",
+        "vue" => "vue",
+        "svelte" => "svelte",
+        "json" => "json",
+        "yaml" | "yml" => "yaml",
+        "toml" => "toml",
+        "xml" => "xml",
+        "ini" | "cfg" => "ini",
+        "csv" => "csv",
+        "tsv" => "tsv",
+        "wit" => "wit",
+        "proto" => "protobuf",
+        _ => return None,
+    };
+    Some(language)
+}
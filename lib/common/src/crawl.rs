@@ -0,0 +1,219 @@
+use std::collections::HashSet;
+use std::future::Future;
+
+use url::Url;
+
+use crate::{DEFAULT_CONCURRENCY, LinkKind, extract_links, fetch_many, outline};
+
+/// Limits for [`crawl`]
+#[derive(Debug, Clone)]
+pub struct CrawlOptions {
+    /// How many links away from the start page to follow; 0 fetches only the start page
+    pub max_depth: usize,
+    /// Maximum number of pages to return; must be at least 1
+    pub max_pages: usize,
+    /// Number of pages fetched at once
+    pub concurrency: usize,
+}
+
+impl Default for CrawlOptions {
+    fn default() -> Self {
+        Self {
+            max_depth: 2,
+            max_pages: 20,
+            concurrency: DEFAULT_CONCURRENCY,
+        }
+    }
+}
+
+/// A page found by [`crawl`]
+#[derive(Debug, Clone)]
+pub struct CrawledPage {
+    /// Normalised URL of the page
+    pub url: String,
+    /// The page's first heading, or its URL if it has none
+    pub title: String,
+    pub markdown: String,
+}
+
+/// A page returned by the fetch function given to [`crawl`]
+#[derive(Debug, Clone)]
+pub struct FetchedPage {
+    /// The URL the page was served from, after following redirects
+    pub url: String,
+    pub markdown: String,
+}
+
+/// The part of a site a crawl stays within: the start URL's origin and the
+/// directory containing it.
+#[derive(Debug, Clone)]
+pub struct CrawlScope {
+    origin: url::Origin,
+    path_prefix: String,
+}
+
+impl CrawlScope {
+    /// The scope of a crawl starting at `start`.
+    ///
+    /// A start path ending in `/` or in a segment without a file extension is
+    /// taken as a directory, so `/docs` and `/docs/intro.html` both give `/docs/`.
+    pub fn new(start: &Url) -> Self {
+        let path = start.path();
+        let last_segment = path.rsplit('/').next().unwrap_or_default();
+        let path_prefix = if path.ends_with('/') {
+            path.to_string()
+        } else if last_segment.contains('.') {
+            path[..path.len() - last_segment.len()].to_string()
+        } else {
+            format!("{path}/")
+        };
+        Self {
+            origin: start.origin(),
+            path_prefix,
+        }
+    }
+
+    /// Whether `url` is on the same origin and under the same path prefix.
+    pub fn contains(&self, url: &Url) -> bool {
+        url.origin() == self.origin
+            && (url.path().starts_with(&self.path_prefix)
+                || url.path() == self.path_prefix.trim_end_matches('/'))
+    }
+}
+
+/// Normalise a URL so that different spellings of the same page compare equal.
+///
+/// The fragment and an empty query are removed, and a trailing `index.html` or
+/// `index.htm` is dropped. Scheme and host case, default ports and dot segments
+/// are already normalised by parsing.
+pub fn normalize_url(url: &Url) -> Url {
+    let mut url = url.clone();
+    url.set_fragment(None);
+    if url.query() == Some("") {
+        url.set_query(None);
+    }
+    let path = url.path().to_string();
+    for index in ["index.html", "index.htm"] {
+        if let Some(directory) = path.strip_suffix(index)
+            && directory.ends_with('/')
+        {
+            url.set_path(directory);
+        }
+    }
+    url
+}
+
+/// Crawl a site breadth-first from `start`, converting each page with `fetch`.
+///
+/// `fetch` takes a URL and returns the page as markdown, along with the URL it
+/// was served from after redirects. Redirects are only accepted within the start
+/// URL's origin, and a page reached by redirect is recorded under its final URL,
+/// which relative links are resolved against. Links found in the markdown are
+/// followed while they stay within the [`CrawlScope`] of the start URL, each
+/// normalised URL is fetched and returned at most once, and the crawl stops at
+/// the depth and page limits. Pages that fail to fetch are skipped, except for
+/// the start page, whose failure fails the crawl. A `max_pages` of 0 is an error.
+pub async fn crawl<F, Fut>(
+    start: &str,
+    options: &CrawlOptions,
+    fetch: F,
+) -> anyhow::Result<Vec<CrawledPage>>
+where
+    F: Fn(String) -> Fut,
+    Fut: Future<Output = Result<FetchedPage, String>>,
+{
+    if options.max_pages == 0 {
+        return Err(anyhow::anyhow!("max-pages must be at least 1"));
+    }
+    let start = Url::parse(start).map_err(|e| anyhow::anyhow!("invalid URL \"{start}\": {e}"))?;
+    if !matches!(start.scheme(), "http" | "https") {
+        return Err(anyhow::anyhow!(
+            "unsupported URL scheme \"{}\"; only http and https can be crawled",
+            start.scheme()
+        ));
+    }
+    let start = normalize_url(&start);
+    let scope = CrawlScope::new(&start);
+
+    let mut seen: HashSet<Url> = HashSet::from([start.clone()]);
+    let mut frontier = vec![start];
+    let mut pages: Vec<CrawledPage> = Vec::new();
+
+    for depth in 0..=options.max_depth {
+        let mut next = Vec::new();
+        while !frontier.is_empty() && pages.len() < options.max_pages {
+            let take = frontier.len().min(options.max_pages - pages.len());
+            let batch: Vec<Url> = frontier.drain(..take).collect();
+            let urls = batch.iter().map(Url::to_string).collect();
+            let results = fetch_many(urls, options.concurrency, &fetch).await;
+
+            for (requested, result) in batch.into_iter().zip(results) {
+                let result = result.and_then(|page| redirected(&requested, page, &scope));
+                let (url, markdown) = match result {
+                    Ok(page) => page,
+                    Err(e) if depth == 0 => {
+                        return Err(anyhow::anyhow!("failed to fetch {requested}: {e}"));
+                    }
+                    Err(_) => continue,
+                };
+                // Several URLs can redirect to the same page
+                if url != requested && !seen.insert(url.clone()) {
+                    continue;
+                }
+                if depth < options.max_depth {
+                    for link in extract_links(&markdown) {
+                        if link.kind != LinkKind::Link {
+                            continue;
+                        }
+                        let Ok(target) = url.join(&link.target) else {
+                            continue;
+                        };
+                        let target = normalize_url(&target);
+                        if scope.contains(&target) && seen.insert(target.clone()) {
+                            next.push(target);
+                        }
+                    }
+                }
+                pages.push(CrawledPage {
+                    title: page_title(&markdown).unwrap_or_else(|| url.to_string()),
+                    url: url.to_string(),
+                    markdown,
+                });
+            }
+        }
+        if next.is_empty() || pages.len() >= options.max_pages {
+            break;
+        }
+        frontier = next;
+    }
+
+    Ok(pages)
+}
+
+/// The normalised final URL and markdown of a fetched page, or an error if it
+/// was redirected off the start URL's origin.
+fn redirected(
+    requested: &Url,
+    page: FetchedPage,
+    scope: &CrawlScope,
+) -> Result<(Url, String), String> {
+    let url = match Url::parse(&page.url) {
+        Ok(url) => normalize_url(&url),
+        Err(_) => requested.clone(),
+    };
+    if url.origin() != scope.origin {
+        return Err(format!("redirected to another origin: {url}"));
+    }
+    Ok((url, page.markdown))
+}
+
+/// The text of the first top-level heading, or of the first heading of any level.
+fn page_title(markdown: &str) -> Option<String> {
+    let headings = outline(markdown);
+    headings
+        .iter()
+        .find(|heading| heading.level == 1)
+        .or_else(|| headings.first())
+        .map(|heading| heading.text.clone())
+        .filter(|text| !text.is_empty())
+}
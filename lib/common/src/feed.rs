@@ -0,0 +1,211 @@
+use roxmltree::{Document, Node};
+
+use crate::html::{escape_text, link_destination};
+
+/// A single entry of an RSS or Atom feed
+#[derive(Debug, Clone, Default)]
+pub struct FeedEntry {
+    pub title: String,
+    pub link: Option<String>,
+    /// Publication date, normalised to RFC 3339 when it can be parsed
+    pub published: Option<String>,
+    pub author: Option<String>,
+    /// Summary as plain text, with any embedded HTML stripped
+    pub summary: Option<String>,
+}
+
+/// A parsed RSS 2.0, RSS 1.0 (RDF) or Atom feed
+#[derive(Debug, Clone, Default)]
+pub struct Feed {
+    pub title: String,
+    pub entries: Vec<FeedEntry>,
+}
+
+/// Parse an RSS or Atom feed document.
+pub fn parse_feed(xml: &str) -> anyhow::Result<Feed> {
+    let document = Document::parse(xml).map_err(|e| anyhow::anyhow!("invalid XML: {e}"))?;
+    let root = document.root_element();
+
+    match root.tag_name().name() {
+        "rss" => {
+            let channel = child(root, "channel")
+                .ok_or_else(|| anyhow::anyhow!("RSS feed has no channel element"))?;
+            Ok(Feed {
+                title: child_text(channel, "title").unwrap_or_default(),
+                entries: children(channel, "item").map(rss_entry).collect(),
+            })
+        }
+        "RDF" => Ok(Feed {
+            title: child(root, "channel")
+                .and_then(|channel| child_text(channel, "title"))
+                .unwrap_or_default(),
+            entries: children(root, "item").map(rss_entry).collect(),
+        }),
+        "feed" => Ok(Feed {
+            title: child_text(root, "title").unwrap_or_default(),
+            entries: children(root, "entry").map(atom_entry).collect(),
+        }),
+        other => Err(anyhow::anyhow!(
+            "unsupported feed format: root element is <{other}>"
+        )),
+    }
+}
+
+/// The feed content type of an XML document, judged by its root element:
+/// `<rss>` for RSS 2.0, `<rdf:RDF>` for RSS 1.0 and `<feed>` for Atom.
+///
+/// Feeds are often served as plain `text/xml` or `application/xml`, so this
+/// looks past the XML declaration, comments and doctype at the first element.
+pub fn feed_content_type(xml: &str) -> Option<&'static str> {
+    let mut rest = xml.trim_start_matches('\u{feff}');
+    loop {
+        rest = rest.trim_start();
+        let skip_to = if rest.starts_with("<?") {
+            "?>"
+        } else if rest.starts_with("<!--") {
+            "-->"
+        } else if rest.starts_with("<!") {
+            ">"
+        } else {
+            break;
+        };
+        let end = rest.find(skip_to)?;
+        rest = &rest[end + skip_to.len()..];
+    }
+    let name = rest
+        .strip_prefix('<')?
+        .split(|c: char| c.is_whitespace() || c == '>' || c == '/')
+        .next()?;
+    // The prefix of an RDF root varies, so only the local name is compared
+    match name.rsplit(':').next()? {
+        "rss" => Some("application/rss+xml"),
+        "RDF" => Some("application/rdf+xml"),
+        "feed" => Some("application/atom+xml"),
+        _ => None,
+    }
+}
+
+/// Render a feed as a markdown digest with one section per entry.
+pub fn feed_to_markdown(feed: &Feed) -> String {
+    let mut markdown = String::new();
+    if !feed.title.is_empty() {
+        markdown.push_str(&format!("# {}\n\n", feed.title));
+    }
+
+    for entry in &feed.entries {
+        let title = if entry.title.is_empty() {
+            "Untitled"
+        } else {
+            &entry.title
+        };
+        match &entry.link {
+            Some(link) => markdown.push_str(&format!(
+                "## [{}]({})\n\n",
+                escape_text(title),
+                link_destination(link)
+            )),
+            None => markdown.push_str(&format!("## {title}\n\n")),
+        }
+
+        let mut meta = Vec::new();
+        if let Some(published) = &entry.published {
+            meta.push(format!("Published: {published}"));
+        }
+        if let Some(author) = &entry.author {
+            meta.push(format!("Author: {author}"));
+        }
+        if !meta.is_empty() {
+            markdown.push_str(&format!("*{}*\n\n", meta.join(" · ")));
+        }
+
+        if let Some(summary) = &entry.summary {
+            markdown.push_str(&format!("{summary}\n\n"));
+        }
+    }
+
+    markdown.trim().to_string()
+}
+
+fn rss_entry(item: Node) -> FeedEntry {
+    FeedEntry {
+        title: child_text(item, "title").unwrap_or_default(),
+        link: child_text(item, "link"),
+        published: child_text(item, "pubDate")
+            .or_else(|| child_text(item, "date"))
+            .map(|date| normalize_date(&date)),
+        author: child_text(item, "author").or_else(|| child_text(item, "creator")),
+        summary: child_text(item, "description")
+            .or_else(|| child_text(item, "encoded"))
+            .map(|summary| html_to_text(&summary))
+            .filter(|summary| !summary.is_empty()),
+    }
+}
+
+fn atom_entry(entry: Node) -> FeedEntry {
+    // Prefer the alternate link, which points at the human-readable page
+    let link = children(entry, "link")
+        .find(|link| matches!(link.attribute("rel"), None | Some("alternate")))
+        .or_else(|| child(entry, "link"))
+        .and_then(|link| link.attribute("href"))
+        .map(str::to_string);
+
+    FeedEntry {
+        title: child_text(entry, "title").unwrap_or_default(),
+        link,
+        published: child_text(entry, "published")
+            .or_else(|| child_text(entry, "updated"))
+            .map(|date| normalize_date(&date)),
+        author: child(entry, "author").and_then(|author| child_text(author, "name")),
+        summary: child_text(entry, "summary")
+            .or_else(|| child_text(entry, "content"))
+            .map(|summary| html_to_text(&summary))
+            .filter(|summary| !summary.is_empty()),
+    }
+}
+
+/// Iterate over the child elements of `node` with the given local name, ignoring namespaces.
+pub(crate) fn children<'a, 'input>(
+    node: Node<'a, 'input>,
+    name: &'static str,
+) -> impl Iterator<Item = Node<'a, 'input>> {
+    node.children()
+        .filter(move |child| child.is_element() && child.tag_name().name() == name)
+}
+
+pub(crate) fn child<'a, 'input>(
+    node: Node<'a, 'input>,
+    name: &'static str,
+) -> Option<Node<'a, 'input>> {
+    children(node, name).next()
+}
+
+/// Trimmed text content of the first child element with the given name, if non-empty.
+pub(crate) fn child_text(node: Node, name: &'static str) -> Option<String> {
+    let element = child(node, name)?;
+    let text = element
+        .descendants()
+        .filter(|n| n.is_text())
+        .filter_map(|n| n.text())
+        .collect::<String>();
+    let text = text.trim();
+    (!text.is_empty()).then(|| text.to_string())
+}
+
+/// Convert RFC 2822 (RSS) or RFC 3339 (Atom) dates to RFC 3339, keeping anything else as-is.
+fn normalize_date(date: &str) -> String {
+    chrono::DateTime::parse_from_rfc2822(date)
+        .or_else(|_| chrono::DateTime::parse_from_rfc3339(date))
+        .map(|dt| dt.to_rfc3339())
+        .unwrap_or_else(|_| date.to_string())
+}
+
+/// Strip HTML markup from a feed summary and collapse whitespace.
+fn html_to_text(html: &str) -> String {
+    let fragment = scraper::Html::parse_fragment(html);
+    fragment
+        .root_element()
+        .text()
+        .flat_map(str::split_whitespace)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
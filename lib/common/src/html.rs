@@ -0,0 +1,679 @@
+use scraper::error::SelectorErrorKind;
+use scraper::{ElementRef, Html, Node, Selector};
+
+use crate::markdown::fenced_code_block;
+
+/// How headings are written in the generated markdown
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HeadingStyle {
+    /// `# Heading`
+    #[default]
+    Atx,
+    /// `Heading` underlined with `===` or `---`, for levels 1 and 2 only
+    Setext,
+}
+
+/// How links and images are written in the generated markdown
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LinkStyle {
+    /// `[text](url)`
+    #[default]
+    Inline,
+    /// `[text][1]` with a `[1]: url` definition at the end of the document
+    Reference,
+}
+
+/// Options controlling HTML to markdown conversion
+#[derive(Debug, Clone)]
+pub struct ConversionOptions {
+    pub heading_style: HeadingStyle,
+    /// Bullet used for unordered list items, one of `-`, `*` or `+`
+    pub bullet: char,
+    /// Whether to keep images, or drop them from the output
+    pub keep_images: bool,
+    pub link_style: LinkStyle,
+    /// Wrap paragraphs at this many characters, or never when zero
+    pub max_line_width: usize,
+    /// Whether to keep elements without a markdown equivalent as raw HTML,
+    /// instead of converting only their content
+    pub preserve_raw_html: bool,
+}
+
+impl Default for ConversionOptions {
+    fn default() -> Self {
+        Self {
+            heading_style: HeadingStyle::default(),
+            bullet: '-',
+            keep_images: true,
+            link_style: LinkStyle::default(),
+            max_line_width: 0,
+            preserve_raw_html: false,
+        }
+    }
+}
+
+/// Elements whose content is never part of the readable page.
+const SKIPPED_TAGS: &[&str] = &[
+    "head", "script", "style", "noscript", "template", "title", "meta", "link",
+];
+
+/// Elements that only group other content and are converted transparently.
+const CONTAINER_TAGS: &[&str] = &[
+    "html",
+    "body",
+    "div",
+    "main",
+    "article",
+    "section",
+    "header",
+    "footer",
+    "nav",
+    "aside",
+    "figure",
+    "figcaption",
+    "address",
+    "details",
+    "summary",
+    "dl",
+    "dt",
+    "dd",
+    "li",
+    "form",
+    "fieldset",
+    "center",
+    "caption",
+    "thead",
+    "tbody",
+    "tfoot",
+    "tr",
+    "th",
+    "td",
+];
+
+/// Inline elements that have no markdown syntax but whose text is kept as-is.
+const TRANSPARENT_INLINE_TAGS: &[&str] = &[
+    "span", "abbr", "cite", "small", "sub", "sup", "mark", "u", "ins", "q", "time", "label", "kbd",
+    "var", "samp", "font", "bdi", "bdo", "data", "dfn",
+];
+
+/// Convert HTML to markdown with the given options.
+///
+/// Unlike [`crate::html_to_markdown`], which keeps only the text of headings,
+/// paragraphs, links and divs, this converts the full document structure:
+/// lists, tables, code, emphasis and images. Text that would otherwise read as
+/// markdown syntax is escaped.
+pub fn html_to_markdown_with(html: &str, options: &ConversionOptions) -> anyhow::Result<String> {
+    if !matches!(options.bullet, '-' | '*' | '+') {
+        return Err(anyhow::anyhow!(
+            "invalid bullet character '{}', expected one of '-', '*' or '+'",
+            options.bullet
+        ));
+    }
+    Ok(convert(html, options))
+}
+
+/// Narrow an HTML document down to the parts matching CSS selectors.
+///
+/// Elements matching any of `exclude` are removed first. If `include` is given,
+/// only the elements it matches are kept, in document order and without
+/// repeating elements nested in an earlier match; it is an error for it to
+/// match nothing. The result is an HTML fragment ready for conversion.
+pub fn select_html(
+    html: &str,
+    include: Option<&str>,
+    exclude: &[String],
+) -> anyhow::Result<String> {
+    let mut document = Html::parse_document(html);
+
+    for selector in exclude {
+        let selector = parse_selector(selector)?;
+        let ids: Vec<_> = document
+            .select(&selector)
+            .map(|element| element.id())
+            .collect();
+        for id in ids {
+            if let Some(mut node) = document.tree.get_mut(id) {
+                node.detach();
+            }
+        }
+    }
+
+    let Some(include) = include else {
+        return Ok(document.root_element().html());
+    };
+    let selector = parse_selector(include)?;
+    let matches: Vec<ElementRef> = document.select(&selector).collect();
+    if matches.is_empty() {
+        return Err(anyhow::anyhow!("selector \"{include}\" matched nothing"));
+    }
+    let selected: Vec<String> = matches
+        .iter()
+        .filter(|element| {
+            !element
+                .ancestors()
+                .any(|ancestor| matches.iter().any(|m| m.id() == ancestor.id()))
+        })
+        .map(|element| element.html())
+        .collect();
+    Ok(selected.join("\n"))
+}
+
+pub(crate) fn parse_selector(selector: &str) -> anyhow::Result<Selector> {
+    Selector::parse(selector).map_err(|e| {
+        // scraper's message for these asks to report a bug; the kind alone reads better
+        let reason = match e {
+            SelectorErrorKind::UnexpectedSelectorParseError(kind) => format!("{kind:?}"),
+            other => other.to_string(),
+        };
+        anyhow::anyhow!("invalid CSS selector \"{selector}\": {reason}")
+    })
+}
+
+fn convert(html: &str, options: &ConversionOptions) -> String {
+    let document = Html::parse_document(html);
+    let mut converter = Converter {
+        options,
+        indent: 0,
+        references: Vec::new(),
+    };
+
+    let mut blocks = Vec::new();
+    converter.blocks(document.root_element(), &mut blocks);
+    if !converter.references.is_empty() {
+        let definitions = converter
+            .references
+            .iter()
+            .enumerate()
+            .map(|(i, (url, title))| match title {
+                Some(title) => format!(
+                    "[{}]: {} {}",
+                    i + 1,
+                    link_destination(url),
+                    link_title(title)
+                ),
+                None => format!("[{}]: {}", i + 1, link_destination(url)),
+            })
+            .collect::<Vec<_>>();
+        blocks.push(definitions.join("\n"));
+    }
+
+    blocks.join("\n\n").trim().to_string()
+}
+
+struct Converter<'a> {
+    options: &'a ConversionOptions,
+    /// Width of the list and blockquote prefixes around the current block
+    indent: usize,
+    /// Link targets and titles in order of first use, for reference-style links
+    references: Vec<(String, Option<String>)>,
+}
+
+impl Converter<'_> {
+    /// Convert the children of `element` into markdown blocks.
+    ///
+    /// Consecutive inline content is gathered into a single paragraph.
+    fn blocks(&mut self, element: ElementRef, blocks: &mut Vec<String>) {
+        let mut paragraph = String::new();
+
+        for child in element.children() {
+            let child_element = match child.value() {
+                Node::Text(text) => {
+                    paragraph.push_str(&escape_text(&collapse_whitespace(text)));
+                    continue;
+                }
+                Node::Element(_) => match ElementRef::wrap(child) {
+                    Some(child_element) => child_element,
+                    None => continue,
+                },
+                _ => continue,
+            };
+
+            let name = child_element.value().name();
+            if SKIPPED_TAGS.contains(&name) {
+                continue;
+            }
+            if is_block(name) || (is_unknown(name) && self.options.preserve_raw_html) {
+                self.flush_paragraph(&mut paragraph, blocks);
+                self.block(child_element, blocks);
+            } else if is_unknown(name) && has_block_children(child_element) {
+                // Unknown wrappers around block content are treated as containers
+                self.flush_paragraph(&mut paragraph, blocks);
+                self.blocks(child_element, blocks);
+            } else {
+                paragraph.push_str(&self.inline_element(child_element));
+            }
+        }
+
+        self.flush_paragraph(&mut paragraph, blocks);
+    }
+
+    /// Emit the gathered inline content as a paragraph, turning the line breaks
+    /// left by `<br>` into markdown hard breaks.
+    fn flush_paragraph(&mut self, paragraph: &mut String, blocks: &mut Vec<String>) {
+        let lines = paragraph
+            .lines()
+            .map(|line| line.split_whitespace().collect::<Vec<_>>().join(" "))
+            .filter(|line| !line.is_empty())
+            .map(|line| self.wrap(&escape_line_start(&line)))
+            .collect::<Vec<_>>();
+        if !lines.is_empty() {
+            blocks.push(lines.join("\\\n"));
+        }
+        paragraph.clear();
+    }
+
+    /// Convert a single block-level element.
+    fn block(&mut self, element: ElementRef, blocks: &mut Vec<String>) {
+        let name = element.value().name();
+        match name {
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+                let level = name[1..].parse::<usize>().unwrap_or(1);
+                let text = self.inline_children(element);
+                let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+                if text.is_empty() {
+                    return;
+                }
+                let underline = match level {
+                    1 => '=',
+                    _ => '-',
+                };
+                if self.options.heading_style == HeadingStyle::Setext && level <= 2 {
+                    let width = text.chars().count().max(3);
+                    blocks.push(format!("{text}\n{}", underline.to_string().repeat(width)));
+                } else {
+                    blocks.push(format!("{} {text}", "#".repeat(level)));
+                }
+            }
+            "p" => {
+                let mut paragraph = self.inline_children(element);
+                self.flush_paragraph(&mut paragraph, blocks);
+            }
+            "pre" => {
+                let code = element.text().collect::<String>();
+                let language = element
+                    .children()
+                    .filter_map(ElementRef::wrap)
+                    .find(|child| child.value().name() == "code")
+                    .and_then(|code| code.value().attr("class"))
+                    .and_then(|class| {
+                        class
+                            .split_whitespace()
+                            .find_map(|c| c.strip_prefix("language-"))
+                    })
+                    .unwrap_or_default();
+                if !code.trim().is_empty() {
+                    blocks.push(fenced_code_block(&code, language));
+                }
+            }
+            "blockquote" => {
+                self.indent += 2;
+                let mut inner = Vec::new();
+                self.blocks(element, &mut inner);
+                self.indent -= 2;
+                if !inner.is_empty() {
+                    blocks.push(prefix_lines(&inner.join("\n\n"), "> ", "> "));
+                }
+            }
+            "ul" | "ol" => {
+                if let Some(list) = self.list(element) {
+                    blocks.push(list);
+                }
+            }
+            "table" => {
+                if let Some(table) = self.table(element) {
+                    blocks.push(table);
+                }
+            }
+            "hr" => blocks.push("---".to_string()),
+            _ if is_unknown(name) && self.options.preserve_raw_html => {
+                blocks.push(element.html());
+            }
+            _ => self.blocks(element, blocks),
+        }
+    }
+
+    fn list(&mut self, element: ElementRef) -> Option<String> {
+        let ordered = element.value().name() == "ol";
+        let mut number = element
+            .value()
+            .attr("start")
+            .and_then(|start| start.parse::<usize>().ok())
+            .unwrap_or(1);
+
+        let mut items = Vec::new();
+        for item in element
+            .children()
+            .filter_map(ElementRef::wrap)
+            .filter(|child| child.value().name() == "li")
+        {
+            let marker = if ordered {
+                format!("{number}. ")
+            } else {
+                format!("{} ", self.options.bullet)
+            };
+            number += 1;
+
+            self.indent += marker.len();
+            let mut inner = Vec::new();
+            self.blocks(item, &mut inner);
+            self.indent -= marker.len();
+
+            // An item holding a single paragraph and nested lists stays tight
+            let nested_lists = item
+                .children()
+                .filter_map(ElementRef::wrap)
+                .filter(|child| matches!(child.value().name(), "ul" | "ol"))
+                .count();
+            let separator = if inner.len() <= nested_lists + 1 {
+                "\n"
+            } else {
+                "\n\n"
+            };
+            let content = inner.join(separator);
+            let continuation = " ".repeat(marker.len());
+            items.push(prefix_lines(&content, &marker, &continuation));
+        }
+
+        (!items.is_empty()).then(|| items.join("\n"))
+    }
+
+    fn table(&mut self, element: ElementRef) -> Option<String> {
+        // Only the table's own rows, not those of tables nested in its cells
+        let rows = element
+            .children()
+            .filter_map(ElementRef::wrap)
+            .flat_map(|child| match child.value().name() {
+                "thead" | "tbody" | "tfoot" => child
+                    .children()
+                    .filter_map(ElementRef::wrap)
+                    .collect::<Vec<_>>(),
+                _ => vec![child],
+            })
+            .filter(|row| row.value().name() == "tr")
+            .map(|row| {
+                row.children()
+                    .filter_map(ElementRef::wrap)
+                    .filter(|cell| matches!(cell.value().name(), "th" | "td"))
+                    .map(|cell| {
+                        self.inline_children(cell)
+                            .split_whitespace()
+                            .collect::<Vec<_>>()
+                            .join(" ")
+                            .replace('|', "\\|")
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .filter(|row| !row.is_empty())
+            .collect::<Vec<_>>();
+
+        let width = rows.iter().map(Vec::len).max()?;
+        let mut lines = Vec::new();
+        for (i, row) in rows.into_iter().enumerate() {
+            let mut cells = row;
+            cells.resize(width, String::new());
+            lines.push(format!("| {} |", cells.join(" | ")));
+            if i == 0 {
+                lines.push(format!("|{}", " --- |".repeat(width)));
+            }
+        }
+        Some(lines.join("\n"))
+    }
+
+    /// Convert the children of `element` into inline markdown.
+    fn inline_children(&mut self, element: ElementRef) -> String {
+        let mut text = String::new();
+        for child in element.children() {
+            match child.value() {
+                Node::Text(t) => text.push_str(&escape_text(&collapse_whitespace(t))),
+                Node::Element(_) => {
+                    if let Some(child_element) = ElementRef::wrap(child) {
+                        text.push_str(&self.inline_element(child_element));
+                    }
+                }
+                _ => {}
+            }
+        }
+        text
+    }
+
+    /// Convert a single element appearing in inline context.
+    fn inline_element(&mut self, element: ElementRef) -> String {
+        let name = element.value().name();
+        match name {
+            _ if SKIPPED_TAGS.contains(&name) => String::new(),
+            "br" => "\n".to_string(),
+            "strong" | "b" => wrap_inline(&self.inline_children(element), "**"),
+            "em" | "i" => wrap_inline(&self.inline_children(element), "*"),
+            "del" | "s" | "strike" => wrap_inline(&self.inline_children(element), "~~"),
+            "code" => inline_code(&element.text().collect::<String>()),
+            "a" => {
+                let text = self.inline_children(element);
+                match element.value().attr("href") {
+                    Some(href) if !text.trim().is_empty() => {
+                        let title = element.value().attr("title");
+                        self.link(text.trim(), href, title)
+                    }
+                    _ => text,
+                }
+            }
+            "img" => {
+                let src = element.value().attr("src").unwrap_or_default();
+                if !self.options.keep_images || src.is_empty() {
+                    return String::new();
+                }
+                let alt = escape_text(element.value().attr("alt").unwrap_or_default());
+                let title = element.value().attr("title");
+                format!("!{}", self.link(&alt, src, title))
+            }
+            _ if is_unknown(name) && self.options.preserve_raw_html => element.html(),
+            _ => self.inline_children(element),
+        }
+    }
+
+    fn link(&mut self, text: &str, url: &str, title: Option<&str>) -> String {
+        match self.options.link_style {
+            LinkStyle::Inline => match title {
+                Some(title) => format!("[{text}]({} {})", link_destination(url), link_title(title)),
+                None => format!("[{text}]({})", link_destination(url)),
+            },
+            LinkStyle::Reference => {
+                let reference = (url.to_string(), title.map(str::to_string));
+                let index = match self.references.iter().position(|known| *known == reference) {
+                    Some(index) => index,
+                    None => {
+                        self.references.push(reference);
+                        self.references.len() - 1
+                    }
+                };
+                format!("[{text}][{}]", index + 1)
+            }
+        }
+    }
+
+    /// Wrap paragraph text to the configured line width, accounting for the
+    /// indentation of enclosing lists and blockquotes.
+    fn wrap(&self, text: &str) -> String {
+        if self.options.max_line_width == 0 {
+            return text.to_string();
+        }
+        let width = self
+            .options
+            .max_line_width
+            .saturating_sub(self.indent)
+            .max(20);
+
+        let mut wrapped = Vec::new();
+        let mut current = String::new();
+        for word in text.split_whitespace() {
+            let too_long = current.chars().count() + 1 + word.chars().count() > width;
+            if !current.is_empty() && too_long && !could_start_block(word) {
+                wrapped.push(std::mem::take(&mut current));
+            }
+            if !current.is_empty() {
+                current.push(' ');
+            }
+            current.push_str(word);
+        }
+        wrapped.push(current);
+        wrapped.join("\n")
+    }
+}
+
+fn is_block(name: &str) -> bool {
+    matches!(
+        name,
+        "p" | "h1"
+            | "h2"
+            | "h3"
+            | "h4"
+            | "h5"
+            | "h6"
+            | "pre"
+            | "blockquote"
+            | "ul"
+            | "ol"
+            | "table"
+            | "hr"
+    ) || CONTAINER_TAGS.contains(&name)
+}
+
+/// Whether an element has no markdown equivalent and is not a plain container.
+fn is_unknown(name: &str) -> bool {
+    !is_block(name)
+        && !SKIPPED_TAGS.contains(&name)
+        && !TRANSPARENT_INLINE_TAGS.contains(&name)
+        && !matches!(
+            name,
+            "a" | "img" | "br" | "strong" | "b" | "em" | "i" | "del" | "s" | "strike" | "code"
+        )
+}
+
+/// Whether a word at the start of a line could be read as block syntax, such as
+/// a heading, list marker or blockquote, so wrapping must not break before it.
+fn could_start_block(word: &str) -> bool {
+    let digits = word.chars().take_while(char::is_ascii_digit).count();
+    word.starts_with(['#', '-', '*', '+', '>', '=', '|', '`', '~'])
+        || (digits > 0 && word[digits..].starts_with(['.', ')']))
+}
+
+fn has_block_children(element: ElementRef) -> bool {
+    element
+        .children()
+        .filter_map(ElementRef::wrap)
+        .any(|child| is_block(child.value().name()))
+}
+
+/// Backslash-escape characters in text that markdown would read as inline syntax.
+pub(crate) fn escape_text(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '~') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Escape text at the start of a line that markdown would read as a block:
+/// a heading, blockquote, list item, thematic break or setext underline.
+///
+/// Converted inline markup never starts with these characters, so a line that
+/// does begins with text from the page.
+fn escape_line_start(line: &str) -> String {
+    if line.starts_with(['#', '>', '-', '+', '=']) {
+        return format!("\\{line}");
+    }
+    let digits = line.chars().take_while(char::is_ascii_digit).count();
+    if digits > 0 && line[digits..].starts_with(['.', ')']) {
+        return format!("{}\\{}", &line[..digits], &line[digits..]);
+    }
+    line.to_string()
+}
+
+/// Write inline code with a backtick fence longer than any run inside it,
+/// padded with spaces when the code starts or ends with a backtick.
+fn inline_code(code: &str) -> String {
+    let longest_run = code
+        .split(|c| c != '`')
+        .map(str::len)
+        .max()
+        .unwrap_or_default();
+    let fence = "`".repeat(longest_run + 1);
+    if code.starts_with('`') || code.ends_with('`') {
+        format!("{fence} {code} {fence}")
+    } else {
+        format!("{fence}{code}{fence}")
+    }
+}
+
+/// Write a link destination, in angle brackets when it contains spaces or
+/// characters that would end it early.
+pub(crate) fn link_destination(url: &str) -> String {
+    if url.is_empty()
+        || url.contains(|c: char| c.is_whitespace() || matches!(c, '(' | ')' | '<' | '>'))
+    {
+        format!("<{}>", url.replace('<', "%3C").replace('>', "%3E"))
+    } else {
+        url.to_string()
+    }
+}
+
+/// Quote a link title, escaping the quotes inside it.
+fn link_title(title: &str) -> String {
+    format!("\"{}\"", title.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Collapse runs of whitespace in a text node to single spaces.
+fn collapse_whitespace(text: &str) -> String {
+    let mut collapsed = String::with_capacity(text.len());
+    let mut in_space = false;
+    for c in text.chars() {
+        if c.is_whitespace() {
+            if !in_space {
+                collapsed.push(' ');
+            }
+            in_space = true;
+        } else {
+            collapsed.push(c);
+            in_space = false;
+        }
+    }
+    collapsed
+}
+
+/// Wrap inline text in emphasis markers, keeping surrounding spaces outside them.
+fn wrap_inline(text: &str, marker: &str) -> String {
+    let trimmed = text.trim();
+    if trimmed.is_empty() {
+        return text.to_string();
+    }
+    let leading = if text.starts_with(char::is_whitespace) {
+        " "
+    } else {
+        ""
+    };
+    let trailing = if text.ends_with(char::is_whitespace) {
+        " "
+    } else {
+        ""
+    };
+    format!("{leading}{marker}{trimmed}{marker}{trailing}")
+}
+
+/// Prefix the first line of `text` with `first` and every other non-empty line with `rest`.
+fn prefix_lines(text: &str, first: &str, rest: &str) -> String {
+    text.lines()
+        .enumerate()
+        .map(|(i, line)| {
+            if i == 0 {
+                format!("{first}{line}")
+            } else if line.is_empty() {
+                rest.trim_end().to_string()
+            } else {
+                format!("{rest}{line}")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
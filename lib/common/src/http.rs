@@ -1,5 +1,17 @@
-use spin_sdk::http::{Method as SMethod, Request, Response, send};
+use futures::{SinkExt, StreamExt};
+use spin_sdk::http::conversions::{TryFromIncomingResponse, TryIntoOutgoingRequest};
+use spin_sdk::http::{IncomingResponse, Method as SMethod, Request, Response, send};
+use spin_sdk::wit::wasi::http0_2_0::{outgoing_handler, types::RequestOptions};
 use std::collections::HashMap;
+use std::future::Future;
+use std::task::Poll;
+use std::time::Duration;
+
+/// Maximum number of redirects followed by [`request`].
+const MAX_REDIRECTS: usize = 10;
+
+/// Number of requests [`fetch_many`] runs at once when no limit is given.
+pub const DEFAULT_CONCURRENCY: usize = 4;
 
 pub async fn get(url: &str, headers: &HashMap<String, String>) -> anyhow::Result<Response> {
     let mut request = Request::get(url);
@@ -17,20 +29,27 @@ pub async fn get(url: &str, headers: &HashMap<String, String>) -> anyhow::Result
     Ok(response)
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Method {
     Get,
+    Head,
     Post,
     Put,
+    Patch,
     Delete,
+    Options,
 }
 
 impl From<Method> for SMethod {
     fn from(value: Method) -> Self {
         match value {
             Method::Get => SMethod::Get,
+            Method::Head => SMethod::Head,
             Method::Post => SMethod::Post,
             Method::Put => SMethod::Put,
+            Method::Patch => SMethod::Patch,
             Method::Delete => SMethod::Delete,
+            Method::Options => SMethod::Options,
         }
     }
 }
@@ -65,3 +84,205 @@ pub async fn post_json(
     }
     Ok(response)
 }
+
+/// A fully specified HTTP request for [`request`]
+#[derive(Debug, Clone)]
+pub struct HttpRequest {
+    pub method: Method,
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    pub body: Option<Vec<u8>>,
+    /// Applied separately to connecting, waiting for the first response byte
+    /// and waiting between body chunks
+    pub timeout: Option<Duration>,
+}
+
+/// The response to an [`HttpRequest`]
+#[derive(Debug, Clone)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+    /// The URL that produced this response, after following redirects
+    pub final_url: String,
+}
+
+/// Headers carrying credentials, which are not forwarded to another origin.
+const CREDENTIAL_HEADERS: &[&str] = &["authorization", "cookie", "proxy-authorization"];
+
+/// Headers describing the request body, which are dropped along with it.
+const BODY_HEADERS: &[&str] = &["content-type", "content-length"];
+
+/// Send an HTTP request, following redirects, and return the response whatever its status.
+///
+/// Only transport failures, such as connection errors and timeouts, are errors.
+/// Each redirect is followed as described by [`redirect_request`].
+pub async fn request(request: HttpRequest) -> anyhow::Result<HttpResponse> {
+    let url = url::Url::parse(&request.url)
+        .map_err(|e| anyhow::anyhow!("invalid URL {}: {e}", request.url))?;
+    let mut request = HttpRequest {
+        url: url.to_string(),
+        ..request
+    };
+
+    for _ in 0..=MAX_REDIRECTS {
+        let mut builder = Request::builder();
+        builder.method(request.method.into()).uri(&request.url);
+        for (name, value) in &request.headers {
+            builder.header(name, value);
+        }
+        if let Some(body) = &request.body {
+            builder.body(body.clone());
+        }
+
+        let response = send_with_timeout(builder.build(), request.timeout).await?;
+        let status = *response.status();
+        let location = response
+            .header("location")
+            .and_then(|value| value.as_str())
+            .filter(|_| matches!(status, 301 | 302 | 303 | 307 | 308));
+
+        let Some(location) = location else {
+            return Ok(HttpResponse {
+                status,
+                headers: response
+                    .headers()
+                    .map(|(name, value)| {
+                        (
+                            name.to_string(),
+                            String::from_utf8_lossy(value.as_bytes()).into_owned(),
+                        )
+                    })
+                    .collect(),
+                body: response.into_body(),
+                final_url: request.url,
+            });
+        };
+        request = redirect_request(&request, status, location)?;
+    }
+
+    Err(anyhow::anyhow!(
+        "too many redirects (more than {MAX_REDIRECTS})"
+    ))
+}
+
+/// The request to send next after `request` was answered with a redirect.
+///
+/// Redirects with status 303, or 301 and 302 for non-GET requests, are followed
+/// with a bodyless GET as browsers do, dropping the headers that described the
+/// body, while 307 and 308 repeat the request as-is. Credential headers such as
+/// `Authorization` and `Cookie` are dropped when the redirect leaves the origin.
+pub fn redirect_request(
+    request: &HttpRequest,
+    status: u16,
+    location: &str,
+) -> anyhow::Result<HttpRequest> {
+    let current = url::Url::parse(&request.url)
+        .map_err(|e| anyhow::anyhow!("invalid URL {}: {e}", request.url))?;
+    let url = current
+        .join(location)
+        .map_err(|e| anyhow::anyhow!("invalid redirect location {location}: {e}"))?;
+
+    let mut method = request.method;
+    let mut body = request.body.clone();
+    let downgraded = status == 303 || (matches!(status, 301 | 302) && method != Method::Get);
+    if downgraded {
+        if method != Method::Head {
+            method = Method::Get;
+        }
+        body = None;
+    }
+    let cross_origin = url.origin() != current.origin();
+    let headers = request
+        .headers
+        .iter()
+        .filter(|(name, _)| {
+            let name = name.to_ascii_lowercase();
+            !(cross_origin && CREDENTIAL_HEADERS.contains(&name.as_str())
+                || downgraded && BODY_HEADERS.contains(&name.as_str()))
+        })
+        .cloned()
+        .collect();
+
+    Ok(HttpRequest {
+        method,
+        url: url.to_string(),
+        headers,
+        body,
+        timeout: request.timeout,
+    })
+}
+
+/// Send a request, applying `timeout` through wasi-http request options.
+///
+/// `spin_sdk::http::send` always sends requests without options, so requests with
+/// a timeout are handed to the outgoing handler directly.
+async fn send_with_timeout(
+    request: Request,
+    timeout: Option<Duration>,
+) -> anyhow::Result<Response> {
+    let Some(timeout) = timeout else {
+        return send(request)
+            .await
+            .map_err(|e| anyhow::anyhow!("error sending request: {e}"));
+    };
+
+    let (outgoing, body) = request.try_into_outgoing_request()?;
+    let nanos = u64::try_from(timeout.as_nanos()).unwrap_or(u64::MAX);
+    let options = RequestOptions::new();
+    options
+        .set_connect_timeout(Some(nanos))
+        .and_then(|()| options.set_first_byte_timeout(Some(nanos)))
+        .and_then(|()| options.set_between_bytes_timeout(Some(nanos)))
+        .map_err(|()| anyhow::anyhow!("the host does not support request timeouts"))?;
+
+    let mut body_sink = outgoing.take_body();
+    let future_response = outgoing_handler::handle(outgoing, Some(options))
+        .map_err(|e| anyhow::anyhow!("error sending request: {e}"))?;
+    if let Some(body) = body {
+        body_sink
+            .send(body)
+            .await
+            .map_err(|e| anyhow::anyhow!("error sending request body: {e}"))?;
+    }
+    drop(body_sink);
+
+    let mut registration: Option<spin_executor::CancelOnDropToken> = None;
+    let incoming: IncomingResponse = std::future::poll_fn(|context| match future_response.get() {
+        Some(Ok(result)) => {
+            Poll::Ready(result.map_err(|e| anyhow::anyhow!("error sending request: {e}")))
+        }
+        Some(Err(())) => Poll::Ready(Err(anyhow::anyhow!("response was already consumed"))),
+        None => {
+            let token = spin_executor::push_waker_and_get_token(
+                future_response.subscribe(),
+                context.waker().clone(),
+            );
+            registration = Some(token.into());
+            Poll::Pending
+        }
+    })
+    .await?;
+    drop(registration);
+
+    Response::try_from_incoming_response(incoming)
+        .await
+        .map_err(|e| anyhow::anyhow!("error reading response: {e}"))
+}
+
+/// Run `fetch` for every URL with at most `concurrency` requests in flight,
+/// returning the results in input order.
+///
+/// Each URL gets its own result so that one failure does not fail the batch.
+/// A concurrency of zero is treated as one.
+pub async fn fetch_many<T, F, Fut>(urls: Vec<String>, concurrency: usize, fetch: F) -> Vec<T>
+where
+    F: Fn(String) -> Fut,
+    Fut: Future<Output = T>,
+{
+    futures::stream::iter(urls)
+        .map(fetch)
+        .buffered(concurrency.max(1))
+        .collect()
+        .await
+}
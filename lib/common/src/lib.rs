@@ -1,5 +1,29 @@
+mod content;
+mod crawl;
+mod feed;
+mod html;
 mod http;
 mod markdown;
+mod outline;
+mod paginate;
+mod pdf;
+mod query;
+mod render;
+mod sanitize;
+mod scrape;
+mod sitemap;
 
+pub use content::*;
+pub use crawl::*;
+pub use feed::*;
+pub use html::*;
 pub use http::*;
 pub use markdown::*;
+pub use outline::*;
+pub use paginate::*;
+pub use pdf::*;
+pub use query::*;
+pub use render::*;
+pub use sanitize::*;
+pub use scrape::*;
+pub use sitemap::*;
@@ -38,12 +38,27 @@ pub fn html_to_markdown(html: &str) -> String {
     markdown.trim().to_string()
 }
 
-pub fn json_to_markdown(json: &str) -> String {
-    let value: serde_json::Value = serde_json::from_str(json).unwrap_or_default();
-    json_value_to_markdown(&value)
+/// Convert a JSON document to markdown, failing if `json` is not valid JSON.
+pub fn json_to_markdown(json: &str) -> anyhow::Result<String> {
+    let value: serde_json::Value =
+        serde_json::from_str(json).map_err(|e| anyhow::anyhow!("invalid JSON: {e}"))?;
+    Ok(json_value_to_markdown(&value))
 }
 
-fn json_value_to_markdown(value: &serde_json::Value) -> String {
+/// Wrap `text` in a fenced code block, using a fence longer than any backtick
+/// run inside the text so the block cannot be closed early.
+pub fn fenced_code_block(text: &str, info: &str) -> String {
+    let longest_run = text
+        .split(|c| c != '`')
+        .map(str::len)
+        .max()
+        .unwrap_or_default();
+    let fence = "`".repeat(longest_run.max(2) + 1);
+    let text = text.trim_end_matches('\n');
+    format!("{fence}{info}\n{text}\n{fence}")
+}
+
+pub fn json_value_to_markdown(value: &serde_json::Value) -> String {
     match value {
         serde_json::Value::Object(map) => {
             let mut markdown = String::new();
@@ -72,3 +87,173 @@ fn json_value_to_markdown(value: &serde_json::Value) -> String {
         serde_json::Value::Null => "null".to_string(),
     }
 }
+
+/// Convert delimited text (CSV, or TSV with `b'\t'`) to a markdown table.
+///
+/// The first record is used as the header row. Short rows are padded with empty
+/// cells so every row has as many columns as the widest record.
+pub fn csv_to_markdown(text: &str, delimiter: u8) -> anyhow::Result<String> {
+    let mut reader = csv::ReaderBuilder::new()
+        .delimiter(delimiter)
+        .has_headers(false)
+        .flexible(true)
+        .from_reader(text.as_bytes());
+
+    let mut rows = Vec::new();
+    for record in reader.records() {
+        let record = record.map_err(|e| anyhow::anyhow!("invalid CSV: {e}"))?;
+        rows.push(record.iter().map(table_cell).collect::<Vec<_>>());
+    }
+    let Some(width) = rows.iter().map(Vec::len).max() else {
+        return Ok(String::new());
+    };
+
+    let mut markdown = String::new();
+    for (i, row) in rows.iter().enumerate() {
+        let mut cells = row.clone();
+        cells.resize(width, String::new());
+        markdown.push_str(&format!("| {} |\n", cells.join(" | ")));
+        if i == 0 {
+            markdown.push_str(&format!("|{}\n", " --- |".repeat(width)));
+        }
+    }
+    Ok(markdown.trim_end().to_string())
+}
+
+/// Escape a value so it fits in a single markdown table cell.
+fn table_cell(value: &str) -> String {
+    value
+        .trim()
+        .replace('|', "\\|")
+        .replace("\r\n", " ")
+        .replace('\n', " ")
+}
+
+/// Convert a YAML document to markdown using the same layout as JSON.
+///
+/// Mapping keys need not be strings: scalar keys are written as they are and
+/// sequence or mapping keys in YAML flow style, such as `[a, b]`.
+pub fn yaml_to_markdown(yaml: &str) -> anyhow::Result<String> {
+    let value: serde_yaml::Value =
+        serde_yaml::from_str(yaml).map_err(|e| anyhow::anyhow!("invalid YAML: {e}"))?;
+    Ok(yaml_value_to_markdown(&value))
+}
+
+fn yaml_value_to_markdown(value: &serde_yaml::Value) -> String {
+    match value {
+        serde_yaml::Value::Mapping(map) => {
+            let mut markdown = String::new();
+            for (key, val) in map {
+                markdown.push_str(&format!(
+                    "### {}\n\n{}\n\n",
+                    yaml_inline(key),
+                    yaml_value_to_markdown(val)
+                ));
+            }
+            markdown
+        }
+        serde_yaml::Value::Sequence(seq) => {
+            let mut markdown = String::new();
+            for (i, val) in seq.iter().enumerate() {
+                markdown.push_str(&format!("1. {}\n", yaml_value_to_markdown(val)));
+                if i < seq.len() - 1 {
+                    markdown.push('\n');
+                }
+            }
+            markdown
+        }
+        serde_yaml::Value::Tagged(tagged) => yaml_value_to_markdown(&tagged.value),
+        scalar => yaml_inline(scalar),
+    }
+}
+
+/// Write a YAML value on one line, using flow style for collections.
+fn yaml_inline(value: &serde_yaml::Value) -> String {
+    match value {
+        serde_yaml::Value::Null => "null".to_string(),
+        serde_yaml::Value::Bool(b) => b.to_string(),
+        serde_yaml::Value::Number(n) => n.to_string(),
+        serde_yaml::Value::String(s) => s.clone(),
+        serde_yaml::Value::Sequence(seq) => {
+            let items: Vec<String> = seq.iter().map(yaml_inline).collect();
+            format!("[{}]", items.join(", "))
+        }
+        serde_yaml::Value::Mapping(map) => {
+            let entries: Vec<String> = map
+                .iter()
+                .map(|(key, val)| format!("{}: {}", yaml_inline(key), yaml_inline(val)))
+                .collect();
+            format!("{{{}}}", entries.join(", "))
+        }
+        serde_yaml::Value::Tagged(tagged) => yaml_inline(&tagged.value),
+    }
+}
+
+/// Convert a TOML document to markdown using the same layout as JSON.
+pub fn toml_to_markdown(toml: &str) -> anyhow::Result<String> {
+    let table: toml::Table = toml
+        .parse()
+        .map_err(|e| anyhow::anyhow!("invalid TOML: {e}"))?;
+    let value = toml_to_json(toml::Value::Table(table));
+    Ok(json_value_to_markdown(&value))
+}
+
+fn toml_to_json(value: toml::Value) -> serde_json::Value {
+    match value {
+        toml::Value::String(s) => serde_json::Value::String(s),
+        toml::Value::Integer(i) => serde_json::Value::from(i),
+        toml::Value::Float(f) => serde_json::Value::from(f),
+        toml::Value::Boolean(b) => serde_json::Value::Bool(b),
+        toml::Value::Datetime(dt) => serde_json::Value::String(dt.to_string()),
+        toml::Value::Array(arr) => arr.into_iter().map(toml_to_json).collect(),
+        toml::Value::Table(table) => table
+            .into_iter()
+            .map(|(key, val)| (key, toml_to_json(val)))
+            .collect(),
+    }
+}
+
+/// Convert a generic XML document to a nested markdown list.
+///
+/// Each element becomes a list item with its name in bold, followed by its
+/// attributes and its own text content. Child elements are nested beneath it.
+pub fn xml_to_markdown(xml: &str) -> anyhow::Result<String> {
+    let document =
+        roxmltree::Document::parse(xml).map_err(|e| anyhow::anyhow!("invalid XML: {e}"))?;
+    let mut markdown = String::new();
+    xml_element_to_markdown(document.root_element(), 0, &mut markdown);
+    Ok(markdown.trim_end().to_string())
+}
+
+fn xml_element_to_markdown(node: roxmltree::Node, depth: usize, markdown: &mut String) {
+    markdown.push_str(&format!(
+        "{}- **{}**",
+        "  ".repeat(depth),
+        node.tag_name().name()
+    ));
+
+    let attributes = node
+        .attributes()
+        .map(|attr| format!("{}=\"{}\"", attr.name(), attr.value()))
+        .collect::<Vec<_>>();
+    if !attributes.is_empty() {
+        markdown.push_str(&format!(" ({})", attributes.join(", ")));
+    }
+
+    let text = node
+        .children()
+        .filter(|child| child.is_text())
+        .filter_map(|child| child.text())
+        .map(str::trim)
+        .filter(|text| !text.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+    if !text.is_empty() {
+        markdown.push_str(&format!(": {text}"));
+    }
+    markdown.push('\n');
+
+    for child in node.children().filter(|child| child.is_element()) {
+        xml_element_to_markdown(child, depth + 1, markdown);
+    }
+}
@@ -0,0 +1,191 @@
+use std::collections::HashMap;
+
+use pulldown_cmark::{Event, Parser, Tag, TagEnd};
+
+use crate::render::gfm_options;
+
+/// A heading in a markdown document
+#[derive(Debug, Clone)]
+pub struct Heading {
+    /// Heading level, from 1 to 6
+    pub level: u8,
+    /// Heading text with inline markup removed
+    pub text: String,
+    /// GitHub-style anchor slug, unique within the document
+    pub anchor: String,
+    /// Index of the enclosing heading in the outline, if any
+    pub parent: Option<usize>,
+    /// Character offset where the heading starts
+    pub start: usize,
+    /// Character offset where the heading's section ends, i.e. the start of the
+    /// next heading of the same or a higher level, or the end of the document
+    pub end: usize,
+}
+
+/// Whether a [`Link`] is a hyperlink or an image
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LinkKind {
+    Link,
+    Image,
+}
+
+/// A link or image reference in a markdown document
+#[derive(Debug, Clone)]
+pub struct Link {
+    pub kind: LinkKind,
+    /// Link text, or alt text for images
+    pub text: String,
+    /// Link destination, with reference-style links resolved
+    pub target: String,
+    pub title: String,
+}
+
+/// Build the heading outline of a markdown document, in document order.
+///
+/// The tree structure is given by each heading's `parent` index.
+pub fn outline(markdown: &str) -> Vec<Heading> {
+    let mut headings: Vec<Heading> = Vec::new();
+    let mut current: Option<(u8, usize, String)> = None;
+
+    for (event, range) in Parser::new_ext(markdown, gfm_options()).into_offset_iter() {
+        match event {
+            Event::Start(Tag::Heading { level, .. }) => {
+                current = Some((level as u8, range.start, String::new()));
+            }
+            Event::Text(text) | Event::Code(text) => {
+                if let Some((_, _, heading_text)) = current.as_mut() {
+                    heading_text.push_str(&text);
+                }
+            }
+            Event::End(TagEnd::Heading(_)) => {
+                if let Some((level, start, text)) = current.take() {
+                    headings.push(Heading {
+                        level,
+                        text: text.trim().to_string(),
+                        anchor: String::new(),
+                        parent: None,
+                        start,
+                        end: markdown.len(),
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // Offsets are collected as byte offsets and only converted to character
+    // offsets once the section boundaries are known.
+    let mut stack: Vec<usize> = Vec::new();
+    let mut slugs: HashMap<String, usize> = HashMap::new();
+    for i in 0..headings.len() {
+        while let Some(&top) = stack.last() {
+            if headings[top].level < headings[i].level {
+                break;
+            }
+            headings[top].end = headings[i].start;
+            stack.pop();
+        }
+        headings[i].parent = stack.last().copied();
+        headings[i].anchor = unique_slug(&headings[i].text, &mut slugs);
+        stack.push(i);
+    }
+
+    for heading in &mut headings {
+        heading.start = markdown[..heading.start].chars().count();
+        heading.end = markdown[..heading.end].chars().count();
+    }
+    headings
+}
+
+/// Extract every link and image in a markdown document, in document order.
+///
+/// A link is placed where it starts, so an image inside a link comes after it.
+pub fn extract_links(markdown: &str) -> Vec<Link> {
+    let mut links: Vec<Link> = Vec::new();
+    // Links can contain images, so keep a stack of the ones still open
+    let mut open: Vec<usize> = Vec::new();
+
+    for event in Parser::new_ext(markdown, gfm_options()) {
+        let (kind, dest_url, title) = match event {
+            Event::Start(Tag::Link {
+                dest_url, title, ..
+            }) => (LinkKind::Link, dest_url, title),
+            Event::Start(Tag::Image {
+                dest_url, title, ..
+            }) => (LinkKind::Image, dest_url, title),
+            Event::Text(text) | Event::Code(text) => {
+                for &i in &open {
+                    links[i].text.push_str(&text);
+                }
+                continue;
+            }
+            Event::End(TagEnd::Link | TagEnd::Image) => {
+                if let Some(i) = open.pop() {
+                    links[i].text = links[i].text.trim().to_string();
+                }
+                continue;
+            }
+            _ => continue,
+        };
+        open.push(links.len());
+        links.push(Link {
+            kind,
+            text: String::new(),
+            target: dest_url.to_string(),
+            title: title.to_string(),
+        });
+    }
+
+    links
+}
+
+/// Return the markdown of the section under a heading path, including the heading itself.
+///
+/// Each element of `path` is matched case-insensitively against heading text,
+/// and each subsequent element is searched for within the previous match's section.
+pub fn section(markdown: &str, path: &[String]) -> anyhow::Result<String> {
+    let headings = outline(markdown);
+    let mut found: Option<&Heading> = None;
+    for name in path {
+        let wanted = name.trim().to_lowercase();
+        let heading = headings
+            .iter()
+            .filter(|h| found.is_none_or(|parent| h.start > parent.start && h.start < parent.end))
+            .find(|h| h.text.to_lowercase() == wanted)
+            .ok_or_else(|| anyhow::anyhow!("no heading matching \"{name}\""))?;
+        found = Some(heading);
+    }
+
+    let Some(heading) = found else {
+        return Err(anyhow::anyhow!("heading path is empty"));
+    };
+    Ok(markdown
+        .chars()
+        .skip(heading.start)
+        .take(heading.end - heading.start)
+        .collect::<String>()
+        .trim_end()
+        .to_string())
+}
+
+/// Generate a GitHub-style anchor for `text`, suffixing repeats with `-1`, `-2`, ...
+fn unique_slug(text: &str, seen: &mut HashMap<String, usize>) -> String {
+    let slug: String = text
+        .to_lowercase()
+        .chars()
+        .filter_map(|c| match c {
+            ' ' => Some('-'),
+            c if c.is_alphanumeric() || c == '-' || c == '_' => Some(c),
+            _ => None,
+        })
+        .collect();
+
+    let count = seen.entry(slug.clone()).or_insert(0);
+    let anchor = if *count == 0 {
+        slug
+    } else {
+        format!("{slug}-{count}")
+    };
+    *count += 1;
+    anchor
+}
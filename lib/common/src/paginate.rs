@@ -0,0 +1,42 @@
+/// Return a window of `text`, measured in characters, for callers that cannot
+/// consume a whole document at once.
+///
+/// Without a start index or maximum length the text is returned unchanged.
+/// Otherwise the window is followed by a note giving its position in the total
+/// length and, when more content remains, the start index to continue from.
+/// A maximum length of zero is an error, since its window would never advance.
+pub fn paginate(
+    text: &str,
+    start_index: Option<u32>,
+    max_length: Option<u32>,
+) -> anyhow::Result<String> {
+    if start_index.is_none() && max_length.is_none() {
+        return Ok(text.to_string());
+    }
+
+    if max_length == Some(0) {
+        return Err(anyhow::anyhow!("max-length must be greater than zero"));
+    }
+
+    let total = text.chars().count();
+    let start = start_index.unwrap_or(0) as usize;
+    if start > total || (start == total && total > 0) {
+        return Err(anyhow::anyhow!(
+            "start-index {start} is past the end of the content ({total} characters)"
+        ));
+    }
+    let end = match max_length {
+        Some(max_length) => total.min(start.saturating_add(max_length as usize)),
+        None => total,
+    };
+
+    let window: String = text.chars().skip(start).take(end - start).collect();
+    let note = if end < total {
+        format!(
+            "[Showing characters {start}-{end} of {total}. Content truncated, continue at start-index {end}.]"
+        )
+    } else {
+        format!("[Showing characters {start}-{end} of {total}. End of content.]")
+    };
+    Ok(format!("{window}\n\n{note}"))
+}
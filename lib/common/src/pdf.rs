@@ -0,0 +1,374 @@
+use std::collections::HashMap;
+
+use pdf_extract::{Dictionary, Document, MediaBox, ObjectId, OutputDev, OutputError, Transform};
+
+/// Lines set at least this much larger than the body text are treated as headings.
+const HEADING_SCALE: f64 = 1.15;
+
+/// Lines longer than this are never headings, whatever their size.
+const MAX_HEADING_LEN: usize = 120;
+
+/// A vertical gap between lines larger than this many times the font size starts
+/// a new paragraph.
+const PARAGRAPH_GAP: f64 = 1.6;
+
+/// How far into the file the `%PDF-` header may start, as readers commonly allow.
+const HEADER_SEARCH_LEN: usize = 1024;
+
+/// Largest PDF converted, in bytes.
+const MAX_PDF_SIZE: usize = 50 * 1024 * 1024;
+
+/// How many `Parent` links are followed when looking up an inherited page attribute.
+const MAX_PAGE_TREE_DEPTH: usize = 32;
+
+/// Characters that mark a bulleted list item at the start of a line.
+const BULLETS: &[char] = &['•', '◦', '▪', '‣'];
+
+/// A line of text on a page, with its largest font size and its distance from
+/// the line above.
+struct Line {
+    text: String,
+    font_size: f64,
+    y: f64,
+    gap: f64,
+}
+
+/// Collects the text of each page as lines, using the same spacing heuristics
+/// as pdf-extract's plain text output.
+#[derive(Default)]
+struct LineCollector {
+    pages: Vec<Vec<Line>>,
+    page_height: f64,
+    last_end: f64,
+    last_y: f64,
+    word_start: bool,
+}
+
+impl LineCollector {
+    fn start_line(&mut self, y: f64) -> Result<(), OutputError> {
+        let page = self
+            .pages
+            .last_mut()
+            .ok_or_else(|| collector_error("characters were output outside a page"))?;
+        let gap = page.last().map_or(0.0, |line| (y - line.y).abs());
+        page.push(Line {
+            text: String::new(),
+            font_size: 0.0,
+            y,
+            gap,
+        });
+        Ok(())
+    }
+}
+
+fn collector_error(message: &str) -> OutputError {
+    OutputError::IoError(std::io::Error::other(message))
+}
+
+impl OutputDev for LineCollector {
+    fn begin_page(
+        &mut self,
+        _page_num: u32,
+        media_box: &MediaBox,
+        _art_box: Option<(f64, f64, f64, f64)>,
+    ) -> Result<(), OutputError> {
+        self.pages.push(Vec::new());
+        self.page_height = media_box.ury - media_box.lly;
+        Ok(())
+    }
+
+    fn end_page(&mut self) -> Result<(), OutputError> {
+        Ok(())
+    }
+
+    fn output_character(
+        &mut self,
+        trm: &Transform,
+        width: f64,
+        _spacing: f64,
+        font_size: f64,
+        char: &str,
+    ) -> Result<(), OutputError> {
+        let size_x = font_size * (trm.m11 + trm.m21);
+        let size_y = font_size * (trm.m12 + trm.m22);
+        let size = (size_x * size_y).abs().sqrt();
+        let (x, y) = (trm.m31, self.page_height - trm.m32);
+
+        let new_line = self.pages.last().is_none_or(|page| page.is_empty())
+            || (self.word_start
+                && ((y - self.last_y).abs() > size * 1.5
+                    || (x < self.last_end && (y - self.last_y).abs() > size * 0.5)));
+        if new_line {
+            self.start_line(y)?;
+        }
+        let line = self
+            .pages
+            .last_mut()
+            .and_then(|page| page.last_mut())
+            .ok_or_else(|| collector_error("no line was started"))?;
+        if !new_line && self.word_start && x > self.last_end + size * 0.1 {
+            line.text.push(' ');
+        }
+        line.text.push_str(char);
+        if !char.trim().is_empty() {
+            line.font_size = line.font_size.max(size);
+        }
+
+        self.word_start = false;
+        self.last_y = y;
+        self.last_end = x + width * size;
+        Ok(())
+    }
+
+    fn begin_word(&mut self) -> Result<(), OutputError> {
+        self.word_start = true;
+        Ok(())
+    }
+
+    fn end_word(&mut self) -> Result<(), OutputError> {
+        Ok(())
+    }
+
+    fn end_line(&mut self) -> Result<(), OutputError> {
+        Ok(())
+    }
+}
+
+/// Extract the text of a PDF document as markdown.
+///
+/// Pages are separated by a horizontal rule and introduced by a `<!-- page N -->`
+/// comment. Lines set noticeably larger than the body text become headings, with
+/// the largest size as level 1, and wrapped lines are joined into paragraphs.
+/// Scanned documents without a text layer are reported as an error.
+pub fn pdf_to_markdown(bytes: &[u8]) -> anyhow::Result<String> {
+    // pdf-extract panics on some malformed documents, and a panic traps the whole
+    // component on wasm. Oversized input and anything that is clearly not a PDF
+    // are turned away first, and the pages are checked before extraction.
+    if bytes.len() > MAX_PDF_SIZE {
+        return Err(anyhow::anyhow!(
+            "PDF is too large to convert ({} bytes, limit {} MiB)",
+            bytes.len(),
+            MAX_PDF_SIZE / (1024 * 1024)
+        ));
+    }
+    let header = &bytes[..bytes.len().min(HEADER_SEARCH_LEN)];
+    if !header.windows(5).any(|window| window == b"%PDF-") {
+        return Err(anyhow::anyhow!("invalid PDF: missing %PDF- header"));
+    }
+    let mut document =
+        Document::load_mem(bytes).map_err(|e| anyhow::anyhow!("invalid PDF: {e}"))?;
+    if document.is_encrypted() {
+        document
+            .decrypt("")
+            .map_err(|e| anyhow::anyhow!("encrypted PDF could not be opened: {e}"))?;
+    }
+    check_pages(&document)?;
+
+    let mut collector = LineCollector::default();
+    pdf_extract::output_doc(&document, &mut collector)
+        .map_err(|e| anyhow::anyhow!("failed to extract PDF text: {e}"))?;
+    let mut pages = collector.pages;
+    for page in &mut pages {
+        for line in page.iter_mut() {
+            line.text = collapse_spaces(&line.text);
+        }
+        page.retain(|line| !line.text.is_empty());
+    }
+    if pages.iter().all(Vec::is_empty) {
+        return Err(anyhow::anyhow!(
+            "PDF has no extractable text; it may contain only scanned images"
+        ));
+    }
+
+    let levels = heading_levels(&pages);
+    let rendered: Vec<String> = pages
+        .iter()
+        .enumerate()
+        .map(|(i, page)| {
+            let mut markdown = format!("<!-- page {} -->", i + 1);
+            let body = render_page(page, &levels);
+            if !body.is_empty() {
+                markdown.push_str("\n\n");
+                markdown.push_str(&body);
+            }
+            markdown
+        })
+        .collect();
+    Ok(rendered.join("\n\n---\n\n"))
+}
+
+/// Check the parts of each page that pdf-extract unwraps rather than reporting
+/// as errors: the page dictionary, an inherited media box of four numbers, and
+/// content streams that decode.
+fn check_pages(document: &Document) -> anyhow::Result<()> {
+    for (number, id) in document.get_pages() {
+        let page = document
+            .get_dictionary(id)
+            .map_err(|e| anyhow::anyhow!("invalid PDF: page {number} is damaged: {e}"))?;
+        if !has_media_box(document, page) {
+            return Err(anyhow::anyhow!(
+                "invalid PDF: page {number} has no valid media box"
+            ));
+        }
+        document
+            .get_and_decode_page_content(id)
+            .map_err(|e| anyhow::anyhow!("invalid PDF: page {number} content is damaged: {e}"))?;
+    }
+    Ok(())
+}
+
+/// Whether a page, or one of the page tree nodes above it, has a media box of
+/// four numbers.
+fn has_media_box(document: &Document, page: &Dictionary) -> bool {
+    let mut node = page;
+    for _ in 0..MAX_PAGE_TREE_DEPTH {
+        if let Ok(media_box) = node.get(b"MediaBox") {
+            return document
+                .dereference(media_box)
+                .and_then(|(_, media_box)| media_box.as_array())
+                .is_ok_and(|values| {
+                    values.len() == 4
+                        && values.iter().all(|value| {
+                            document
+                                .dereference(value)
+                                .is_ok_and(|(_, value)| value.as_float().is_ok())
+                        })
+                });
+        }
+        let parent: Option<ObjectId> = node
+            .get(b"Parent")
+            .and_then(|parent| parent.as_reference())
+            .ok();
+        match parent.and_then(|id| document.get_dictionary(id).ok()) {
+            Some(parent) => node = parent,
+            None => return false,
+        }
+    }
+    false
+}
+
+/// Font sizes are compared at half-point precision.
+fn size_key(size: f64) -> i64 {
+    (size * 2.0).round() as i64
+}
+
+/// Map the font sizes used for headings to heading levels.
+///
+/// The body size is the one used for the most characters. Larger sizes on short
+/// lines are heading sizes, ranked from level 1 for the largest down to level 6.
+fn heading_levels(pages: &[Vec<Line>]) -> HashMap<i64, usize> {
+    let mut usage: HashMap<i64, usize> = HashMap::new();
+    for line in pages.iter().flatten() {
+        *usage.entry(size_key(line.font_size)).or_default() += line.text.chars().count();
+    }
+    let Some(body) = usage
+        .iter()
+        .max_by_key(|(size, count)| (**count, -**size))
+        .map(|(size, _)| *size as f64 / 2.0)
+    else {
+        return HashMap::new();
+    };
+
+    let mut sizes: Vec<i64> = pages
+        .iter()
+        .flatten()
+        .filter(|line| is_heading_candidate(line, body))
+        .map(|line| size_key(line.font_size))
+        .collect();
+    sizes.sort_unstable_by(|a, b| b.cmp(a));
+    sizes.dedup();
+    sizes
+        .into_iter()
+        .take(6)
+        .enumerate()
+        .map(|(i, size)| (size, i + 1))
+        .collect()
+}
+
+fn is_heading_candidate(line: &Line, body_size: f64) -> bool {
+    line.font_size >= body_size * HEADING_SCALE && line.text.chars().count() <= MAX_HEADING_LEN
+}
+
+/// Render the lines of one page as markdown blocks.
+fn render_page(lines: &[Line], levels: &HashMap<i64, usize>) -> String {
+    let mut blocks: Vec<String> = Vec::new();
+    let mut paragraph = String::new();
+    let mut previous_heading: Option<usize> = None;
+
+    for line in lines {
+        let level = levels
+            .get(&size_key(line.font_size))
+            .copied()
+            .filter(|_| line.text.chars().count() <= MAX_HEADING_LEN);
+        let new_block = line.gap > line.font_size * PARAGRAPH_GAP;
+
+        if let Some(level) = level {
+            flush(&mut paragraph, &mut blocks);
+            // Long titles wrap onto several lines of the same size
+            if previous_heading == Some(level) && !new_block {
+                if let Some(heading) = blocks.last_mut() {
+                    heading.push(' ');
+                    heading.push_str(&line.text);
+                }
+            } else {
+                blocks.push(format!("{} {}", "#".repeat(level), line.text));
+            }
+            previous_heading = Some(level);
+            continue;
+        }
+        previous_heading = None;
+
+        if let Some(item) = line.text.strip_prefix(BULLETS) {
+            flush(&mut paragraph, &mut blocks);
+            paragraph = format!("- {}", item.trim_start());
+            continue;
+        }
+        if new_block {
+            flush(&mut paragraph, &mut blocks);
+        }
+        join_line(&mut paragraph, &line.text);
+    }
+    flush(&mut paragraph, &mut blocks);
+
+    // Consecutive list items form one tight list
+    let mut markdown = String::new();
+    for (i, block) in blocks.iter().enumerate() {
+        if i > 0 {
+            let tight = block.starts_with("- ") && blocks[i - 1].starts_with("- ");
+            markdown.push_str(if tight { "\n" } else { "\n\n" });
+        }
+        markdown.push_str(block);
+    }
+    markdown
+}
+
+/// Append a wrapped line to a paragraph, rejoining words hyphenated across lines.
+fn join_line(paragraph: &mut String, line: &str) {
+    if paragraph.is_empty() {
+        paragraph.push_str(line);
+        return;
+    }
+    let hyphenated = paragraph.ends_with('-')
+        && paragraph[..paragraph.len() - 1]
+            .chars()
+            .last()
+            .is_some_and(char::is_alphabetic)
+        && line.chars().next().is_some_and(char::is_lowercase);
+    if hyphenated {
+        paragraph.pop();
+    } else {
+        paragraph.push(' ');
+    }
+    paragraph.push_str(line);
+}
+
+fn flush(paragraph: &mut String, blocks: &mut Vec<String>) {
+    if !paragraph.is_empty() {
+        blocks.push(std::mem::take(paragraph));
+    }
+}
+
+/// Trim a line and collapse runs of whitespace into single spaces.
+fn collapse_spaces(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
@@ -0,0 +1,45 @@
+use serde_json::Value;
+use serde_json_path::JsonPath;
+
+/// An optional JSONPath (RFC 9535) expression, parsed ahead of the document
+/// it is applied to so that a bad expression is reported before any fetch.
+#[derive(Debug, Clone)]
+pub struct JsonQuery(Option<JsonPath>);
+
+impl JsonQuery {
+    /// Parse a query; an empty or blank one selects the whole document.
+    pub fn parse(query: Option<&str>) -> anyhow::Result<Self> {
+        let path = query
+            .map(str::trim)
+            .filter(|q| !q.is_empty())
+            .map(|query| {
+                JsonPath::parse(query)
+                    .map_err(|e| anyhow::anyhow!("invalid JSONPath expression \"{query}\": {e}"))
+            })
+            .transpose()?;
+        Ok(Self(path))
+    }
+
+    /// Parse a JSON document, select parts of it with the query, and return the
+    /// result pretty-printed.
+    ///
+    /// A query returns a JSON array of every matching value, in document order;
+    /// without one the whole document is returned.
+    pub fn apply(&self, json: &str) -> anyhow::Result<String> {
+        let value: Value =
+            serde_json::from_str(json).map_err(|e| anyhow::anyhow!("invalid JSON: {e}"))?;
+        let result = match &self.0 {
+            Some(path) => Value::Array(path.query(&value).all().into_iter().cloned().collect()),
+            None => value,
+        };
+        Ok(serde_json::to_string_pretty(&result)?)
+    }
+}
+
+/// Parse a JSON document, optionally select parts of it with a JSONPath
+/// (RFC 9535) expression, and return the result pretty-printed.
+///
+/// See [`JsonQuery::apply`] for the shape of the result.
+pub fn query_json(json: &str, query: Option<&str>) -> anyhow::Result<String> {
+    JsonQuery::parse(query)?.apply(json)
+}
@@ -0,0 +1,115 @@
+use pulldown_cmark::{CowStr, Event, Options, Parser, Tag, TagEnd};
+
+/// URL schemes that are safe to emit in rendered links and images.
+const SAFE_URL_SCHEMES: &[&str] = &["http", "https", "mailto", "tel", "ftp"];
+
+/// Parser options for CommonMark with the GitHub Flavored Markdown extensions.
+pub(crate) fn gfm_options() -> Options {
+    Options::ENABLE_TABLES
+        | Options::ENABLE_STRIKETHROUGH
+        | Options::ENABLE_TASKLISTS
+        | Options::ENABLE_FOOTNOTES
+        | Options::ENABLE_GFM
+}
+
+/// Check whether a link target is relative or uses a known-safe scheme.
+///
+/// Browsers ignore ASCII whitespace and control characters inside a scheme, so
+/// those are removed before the check to catch inputs like `java\tscript:`.
+pub(crate) fn is_safe_url(url: &str) -> bool {
+    let url: String = url
+        .chars()
+        .filter(|c| !c.is_ascii_whitespace() && !c.is_ascii_control())
+        .collect();
+    match url.find([':', '/', '?', '#']) {
+        Some(idx) if url[idx..].starts_with(':') => SAFE_URL_SCHEMES
+            .iter()
+            .any(|scheme| url[..idx].eq_ignore_ascii_case(scheme)),
+        _ => true,
+    }
+}
+
+/// Render markdown (CommonMark + GFM) to HTML.
+///
+/// Raw HTML in the input is escaped rather than passed through, and links or
+/// images with unsafe URL schemes such as `javascript:` have their target removed.
+pub fn markdown_to_html(markdown: &str) -> String {
+    let events = Parser::new_ext(markdown, gfm_options()).map(|event| match event {
+        Event::Html(html) | Event::InlineHtml(html) => Event::Text(html),
+        Event::Start(Tag::Link {
+            link_type,
+            dest_url,
+            title,
+            id,
+        }) if !is_safe_url(&dest_url) => Event::Start(Tag::Link {
+            link_type,
+            dest_url: CowStr::Borrowed("#"),
+            title,
+            id,
+        }),
+        Event::Start(Tag::Image {
+            link_type,
+            dest_url,
+            title,
+            id,
+        }) if !is_safe_url(&dest_url) => Event::Start(Tag::Image {
+            link_type,
+            dest_url: CowStr::Borrowed("#"),
+            title,
+            id,
+        }),
+        event => event,
+    });
+
+    let mut html = String::new();
+    pulldown_cmark::html::push_html(&mut html, events);
+    html
+}
+
+/// Render markdown to plain text, dropping all markup.
+///
+/// Block elements are separated by blank lines, list items and table rows are
+/// put on their own lines, table cells are tab-separated and images are
+/// replaced by their alt text. Raw HTML is dropped.
+pub fn markdown_to_text(markdown: &str) -> String {
+    let mut text = String::new();
+
+    for event in Parser::new_ext(markdown, gfm_options()) {
+        match event {
+            Event::Text(t) | Event::Code(t) => text.push_str(&t),
+            Event::SoftBreak => text.push(' '),
+            Event::HardBreak => text.push('\n'),
+            Event::Rule => ensure_trailing_newlines(&mut text, 2),
+            Event::Start(Tag::Item) => ensure_trailing_newlines(&mut text, 1),
+            Event::End(TagEnd::TableCell) => text.push('\t'),
+            Event::End(TagEnd::TableHead | TagEnd::TableRow | TagEnd::Item) => {
+                let trimmed = text.trim_end_matches('\t').len();
+                text.truncate(trimmed);
+                ensure_trailing_newlines(&mut text, 1);
+            }
+            Event::End(
+                TagEnd::Paragraph
+                | TagEnd::Heading(_)
+                | TagEnd::CodeBlock
+                | TagEnd::BlockQuote(_)
+                | TagEnd::List(_)
+                | TagEnd::Table
+                | TagEnd::FootnoteDefinition,
+            ) => ensure_trailing_newlines(&mut text, 2),
+            _ => {}
+        }
+    }
+
+    text.trim().to_string()
+}
+
+/// Append newlines until `text` ends with at least `count` of them.
+fn ensure_trailing_newlines(text: &mut String, count: usize) {
+    if text.is_empty() {
+        return;
+    }
+    let existing = text.len() - text.trim_end_matches('\n').len();
+    for _ in existing..count {
+        text.push('\n');
+    }
+}
@@ -0,0 +1,247 @@
+use scraper::{ElementRef, Html, Node};
+
+use crate::render::is_safe_url;
+
+/// Tags kept by default, covering text formatting, lists, tables and media.
+const DEFAULT_ALLOWED_TAGS: &[&str] = &[
+    "a",
+    "abbr",
+    "article",
+    "aside",
+    "b",
+    "blockquote",
+    "br",
+    "caption",
+    "cite",
+    "code",
+    "dd",
+    "del",
+    "details",
+    "div",
+    "dl",
+    "dt",
+    "em",
+    "figcaption",
+    "figure",
+    "footer",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "header",
+    "hr",
+    "i",
+    "img",
+    "ins",
+    "kbd",
+    "li",
+    "main",
+    "mark",
+    "nav",
+    "ol",
+    "p",
+    "pre",
+    "q",
+    "s",
+    "samp",
+    "section",
+    "small",
+    "span",
+    "strong",
+    "sub",
+    "summary",
+    "sup",
+    "table",
+    "tbody",
+    "td",
+    "tfoot",
+    "th",
+    "thead",
+    "time",
+    "tr",
+    "u",
+    "ul",
+];
+
+/// Attributes kept by default on any allowed tag.
+const DEFAULT_ALLOWED_ATTRIBUTES: &[&str] = &[
+    "alt", "cite", "colspan", "datetime", "height", "href", "lang", "rowspan", "src", "start",
+    "title", "width",
+];
+
+/// Elements that are always removed together with their content, whatever the
+/// allow list says, because they run code or embed other documents.
+const ALWAYS_REMOVED_TAGS: &[&str] = &[
+    "applet", "base", "embed", "frame", "frameset", "iframe", "link", "meta", "noscript", "object",
+    "script", "style", "template",
+];
+
+/// Attributes whose values are URLs and must use a safe scheme.
+const URL_ATTRIBUTES: &[&str] = &[
+    "action",
+    "background",
+    "cite",
+    "formaction",
+    "href",
+    "longdesc",
+    "poster",
+    "src",
+];
+
+/// Elements with no closing tag.
+const VOID_TAGS: &[&str] = &[
+    "area", "br", "col", "hr", "img", "input", "source", "track", "wbr",
+];
+
+/// ARIA landmark roles that mark page chrome rather than content.
+const BOILERPLATE_ROLES: &[&str] = &[
+    "banner",
+    "complementary",
+    "contentinfo",
+    "navigation",
+    "search",
+];
+
+/// Options controlling HTML sanitisation
+#[derive(Debug, Clone)]
+pub struct SanitizeOptions {
+    /// Tags to keep. Other tags are unwrapped, keeping their content
+    pub allowed_tags: Vec<String>,
+    /// Attributes to keep on allowed tags. Event handlers are always removed
+    pub allowed_attributes: Vec<String>,
+    /// Whether to also remove navigation, sidebars, page headers and footers,
+    /// forms and hidden elements
+    pub remove_boilerplate: bool,
+}
+
+impl Default for SanitizeOptions {
+    fn default() -> Self {
+        Self {
+            allowed_tags: DEFAULT_ALLOWED_TAGS.iter().map(|t| t.to_string()).collect(),
+            allowed_attributes: DEFAULT_ALLOWED_ATTRIBUTES
+                .iter()
+                .map(|a| a.to_string())
+                .collect(),
+            remove_boilerplate: false,
+        }
+    }
+}
+
+/// Sanitise HTML for display, returning the cleaned body content.
+///
+/// Scripts, styles and embedded documents are removed with their content, event
+/// handler attributes are always dropped and URLs with unsafe schemes such as
+/// `javascript:` are removed. Tags outside the allow list are unwrapped.
+pub fn sanitize_html(html: &str, options: &SanitizeOptions) -> String {
+    let document = Html::parse_document(html);
+    let mut output = String::new();
+
+    let body = document
+        .root_element()
+        .children()
+        .filter_map(ElementRef::wrap)
+        .find(|child| child.value().name() == "body");
+    if let Some(body) = body {
+        sanitize_children(body, options, &mut output);
+    }
+
+    output.trim().to_string()
+}
+
+fn sanitize_children(element: ElementRef, options: &SanitizeOptions, output: &mut String) {
+    for child in element.children() {
+        match child.value() {
+            Node::Text(text) => output.push_str(&escape(text, false)),
+            Node::Element(_) => {
+                if let Some(child_element) = ElementRef::wrap(child) {
+                    sanitize_element(child_element, options, output);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn sanitize_element(element: ElementRef, options: &SanitizeOptions, output: &mut String) {
+    let name = element.value().name();
+    if ALWAYS_REMOVED_TAGS.contains(&name)
+        || (options.remove_boilerplate && is_boilerplate(element))
+    {
+        return;
+    }
+    if !options
+        .allowed_tags
+        .iter()
+        .any(|tag| tag.eq_ignore_ascii_case(name))
+    {
+        sanitize_children(element, options, output);
+        return;
+    }
+
+    output.push('<');
+    output.push_str(name);
+    for (attr, value) in element.value().attrs() {
+        let attr = attr.to_ascii_lowercase();
+        let allowed = options
+            .allowed_attributes
+            .iter()
+            .any(|allowed| allowed.eq_ignore_ascii_case(&attr));
+        if attr.starts_with("on") || !allowed {
+            continue;
+        }
+        if URL_ATTRIBUTES.contains(&attr.as_str()) && !is_safe_url(value) {
+            continue;
+        }
+        output.push_str(&format!(" {attr}=\"{}\"", escape(value, true)));
+    }
+    output.push('>');
+
+    if VOID_TAGS.contains(&name) {
+        return;
+    }
+    sanitize_children(element, options, output);
+    output.push_str(&format!("</{name}>"));
+}
+
+/// Whether an element is page chrome rather than content.
+///
+/// Headers and footers are only boilerplate at page level; inside an article
+/// they usually hold its title, byline or notes.
+fn is_boilerplate(element: ElementRef) -> bool {
+    let value = element.value();
+    if value.attr("hidden").is_some() || value.attr("aria-hidden") == Some("true") {
+        return true;
+    }
+    if value
+        .attr("role")
+        .is_some_and(|role| BOILERPLATE_ROLES.contains(&role))
+    {
+        return true;
+    }
+    match value.name() {
+        "nav" | "aside" | "form" => true,
+        "header" | "footer" => !element
+            .ancestors()
+            .filter_map(ElementRef::wrap)
+            .any(|ancestor| ancestor.value().name() == "article"),
+        _ => false,
+    }
+}
+
+/// Escape text for HTML output, including double quotes inside attribute values.
+fn escape(text: &str, attribute: bool) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' if attribute => escaped.push_str("&quot;"),
+            '\u{a0}' => escaped.push_str("&nbsp;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
@@ -0,0 +1,241 @@
+use scraper::{ElementRef, Html, Selector};
+use serde_json::{Map, Value};
+use url::Url;
+
+use crate::html::parse_selector;
+
+/// A field to extract from a page with a CSS selector
+#[derive(Debug, Clone)]
+pub struct FieldSpec {
+    /// Key of the field in each record
+    pub name: String,
+    pub selector: String,
+    /// Attribute to read from the matched elements instead of their text
+    pub attribute: Option<String>,
+    /// Whether to collect every match into a list rather than take the first
+    pub list: bool,
+}
+
+/// A table found in an HTML document
+#[derive(Debug, Clone, PartialEq)]
+pub struct HtmlTable {
+    pub caption: Option<String>,
+    /// Column names from the header row, or `Column 1`, `Column 2`, ... if there is none
+    pub columns: Vec<String>,
+    /// Cell text, one entry per column
+    pub rows: Vec<Vec<String>>,
+}
+
+/// Extract records from HTML with CSS selectors.
+///
+/// Each element matching `item_selector` produces one record, with the fields
+/// selected within it; without an item selector the whole document is a single
+/// record. A field is the collapsed text of its first match, or the named
+/// attribute, and `null` when nothing matches; list fields hold every match.
+/// `href` and `src` attributes are resolved against `base_url` when it is given.
+pub fn scrape(
+    html: &str,
+    base_url: Option<&str>,
+    item_selector: Option<&str>,
+    fields: &[FieldSpec],
+) -> anyhow::Result<Vec<Value>> {
+    if fields.is_empty() {
+        return Err(anyhow::anyhow!("no fields to extract"));
+    }
+    let base_url = base_url.and_then(|url| Url::parse(url).ok());
+    let selectors = fields
+        .iter()
+        .map(|field| parse_selector(&field.selector))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    let document = Html::parse_document(html);
+    let items: Vec<ElementRef> = match item_selector {
+        Some(item_selector) => document.select(&parse_selector(item_selector)?).collect(),
+        None => vec![document.root_element()],
+    };
+
+    Ok(items
+        .into_iter()
+        .map(|item| {
+            let record: Map<String, Value> = fields
+                .iter()
+                .zip(&selectors)
+                .map(|(field, selector)| {
+                    let mut values = item
+                        .select(selector)
+                        .filter_map(|element| field_value(element, field, base_url.as_ref()));
+                    let value = if field.list {
+                        Value::Array(values.map(Value::String).collect())
+                    } else {
+                        values.next().map_or(Value::Null, Value::String)
+                    };
+                    (field.name.clone(), value)
+                })
+                .collect();
+            Value::Object(record)
+        })
+        .collect())
+}
+
+/// Read a field's value from a matched element.
+fn field_value(element: ElementRef, field: &FieldSpec, base_url: Option<&Url>) -> Option<String> {
+    let Some(attribute) = field.attribute.as_deref() else {
+        return Some(element_text(element));
+    };
+    let value = element.value().attr(attribute)?.trim();
+    let resolved = matches!(attribute, "href" | "src")
+        .then(|| base_url?.join(value).ok())
+        .flatten();
+    Some(resolved.map_or_else(|| value.to_string(), |url| url.to_string()))
+}
+
+/// Find every `<table>` in an HTML document, including nested tables.
+///
+/// The header row is the first row when it consists only of `<th>` cells or is
+/// in a `<thead>`. Cells spanning several columns are repeated in each of them,
+/// and short rows are padded with empty cells.
+pub fn html_tables(html: &str) -> Vec<HtmlTable> {
+    let document = Html::parse_document(html);
+    let selector = Selector::parse("table").expect("valid selector");
+    document.select(&selector).map(parse_table).collect()
+}
+
+fn parse_table(table: ElementRef) -> HtmlTable {
+    let caption = child_elements(table)
+        .find(|child| child.value().name() == "caption")
+        .map(element_text)
+        .filter(|caption| !caption.is_empty());
+
+    // Rows are the table's own, not those of tables nested in its cells
+    let mut rows: Vec<(bool, Vec<String>)> = Vec::new();
+    for child in child_elements(table) {
+        match child.value().name() {
+            "tr" => rows.push(parse_row(child, false)),
+            section @ ("thead" | "tbody" | "tfoot") => rows.extend(
+                child_elements(child)
+                    .filter(|row| row.value().name() == "tr")
+                    .map(|row| parse_row(row, section == "thead")),
+            ),
+            _ => {}
+        }
+    }
+
+    let width = rows.iter().map(|(_, cells)| cells.len()).max().unwrap_or(0);
+    let header = match rows.first() {
+        Some((true, _)) => Some(rows.remove(0).1),
+        _ => None,
+    };
+    let mut columns: Vec<String> = Vec::with_capacity(width);
+    for i in 0..width {
+        let name = header
+            .as_ref()
+            .and_then(|header| header.get(i))
+            .filter(|name| !name.is_empty())
+            .cloned()
+            .unwrap_or_else(|| format!("Column {}", i + 1));
+        columns.push(unique_name(name, &columns));
+    }
+
+    HtmlTable {
+        caption,
+        columns,
+        rows: rows
+            .into_iter()
+            .map(|(_, mut cells)| {
+                cells.resize(width, String::new());
+                cells
+            })
+            .collect(),
+    }
+}
+
+/// Parse a row's cells, returning whether it is a header row.
+fn parse_row(row: ElementRef, in_head: bool) -> (bool, Vec<String>) {
+    let mut cells = Vec::new();
+    let mut all_headers = true;
+    for cell in child_elements(row) {
+        let name = cell.value().name();
+        if name != "td" && name != "th" {
+            continue;
+        }
+        all_headers &= name == "th";
+        let span = cell
+            .value()
+            .attr("colspan")
+            .and_then(|span| span.trim().parse::<usize>().ok())
+            .unwrap_or(1)
+            .clamp(1, 100);
+        let text = element_text(cell);
+        cells.extend(std::iter::repeat_n(text, span));
+    }
+    (in_head || (all_headers && !cells.is_empty()), cells)
+}
+
+/// Render tables as JSON: an array with one object per table, holding its
+/// caption, column names and one object per row keyed by column name.
+pub fn tables_to_json(tables: &[HtmlTable]) -> Value {
+    Value::Array(
+        tables
+            .iter()
+            .map(|table| {
+                let rows = table
+                    .rows
+                    .iter()
+                    .map(|row| {
+                        Value::Object(
+                            table
+                                .columns
+                                .iter()
+                                .cloned()
+                                .zip(row.iter().cloned().map(Value::String))
+                                .collect(),
+                        )
+                    })
+                    .collect();
+                serde_json::json!({
+                    "caption": table.caption,
+                    "columns": table.columns,
+                    "rows": Value::Array(rows),
+                })
+            })
+            .collect(),
+    )
+}
+
+/// Render tables as CSV, with a header row per table and a blank line between tables.
+pub fn tables_to_csv(tables: &[HtmlTable]) -> anyhow::Result<String> {
+    let mut sections = Vec::with_capacity(tables.len());
+    for table in tables {
+        let mut writer = csv::Writer::from_writer(Vec::new());
+        writer.write_record(&table.columns)?;
+        for row in &table.rows {
+            writer.write_record(row)?;
+        }
+        sections.push(String::from_utf8(writer.into_inner()?)?);
+    }
+    Ok(sections.join("\n"))
+}
+
+fn child_elements<'a>(element: ElementRef<'a>) -> impl Iterator<Item = ElementRef<'a>> {
+    element.children().filter_map(ElementRef::wrap)
+}
+
+/// The text of an element with whitespace collapsed.
+fn element_text(element: ElementRef) -> String {
+    element
+        .text()
+        .flat_map(str::split_whitespace)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Suffix a repeated column name with ` (2)`, ` (3)`, ... to keep keys unique.
+fn unique_name(name: String, existing: &[String]) -> String {
+    if !existing.contains(&name) {
+        return name;
+    }
+    (2..)
+        .map(|n| format!("{name} ({n})"))
+        .find(|candidate| !existing.contains(candidate))
+        .expect("an unused name exists")
+}
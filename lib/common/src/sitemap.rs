@@ -0,0 +1,190 @@
+use std::collections::HashSet;
+use std::future::Future;
+use std::io::Read;
+
+use chrono::{DateTime, FixedOffset, NaiveDate};
+use roxmltree::Document;
+use url::Url;
+
+use crate::feed::{child_text, children};
+use crate::{DEFAULT_CONCURRENCY, fetch_many};
+
+/// Maximum number of sitemap files read when following sitemap indexes.
+const MAX_SITEMAPS: usize = 100;
+
+/// Largest uncompressed size of a gzipped sitemap, the limit set by the sitemap protocol.
+const MAX_SITEMAP_SIZE: u64 = 50 * 1024 * 1024;
+
+/// A page listed in a sitemap
+#[derive(Debug, Clone, PartialEq)]
+pub struct SitemapEntry {
+    pub loc: String,
+    /// Last modification date, as given in the sitemap
+    pub lastmod: Option<String>,
+    pub priority: Option<f32>,
+}
+
+/// A parsed sitemap file: either a list of pages or an index of further sitemaps
+#[derive(Debug, Clone, PartialEq)]
+pub enum Sitemap {
+    UrlSet(Vec<SitemapEntry>),
+    Index(Vec<String>),
+}
+
+/// A sitemap file returned by the fetch function given to [`sitemap_entries`]
+#[derive(Debug, Clone)]
+pub struct FetchedSitemap {
+    /// The URL the file was served from, after following redirects
+    pub url: String,
+    pub body: Vec<u8>,
+}
+
+/// Filters applied to sitemap entries by [`sitemap_entries`]
+#[derive(Debug, Clone, Default)]
+pub struct SitemapFilter {
+    /// Keep only entries whose URL path starts with this prefix, or whose URL
+    /// starts with it if it is an absolute URL
+    pub path_prefix: Option<String>,
+    /// Keep only entries modified on or after this date (`YYYY-MM-DD` or RFC 3339).
+    /// Entries without a lastmod are dropped when this is set
+    pub modified_since: Option<String>,
+}
+
+/// Parse a sitemap or sitemap index, decompressing it first if it is gzipped.
+///
+/// Gzipped sitemaps larger than 50 MiB once decompressed are rejected.
+pub fn parse_sitemap(bytes: &[u8]) -> anyhow::Result<Sitemap> {
+    let xml = if bytes.starts_with(&[0x1f, 0x8b]) {
+        let mut xml = String::new();
+        flate2::read::GzDecoder::new(bytes)
+            .take(MAX_SITEMAP_SIZE + 1)
+            .read_to_string(&mut xml)
+            .map_err(|e| anyhow::anyhow!("invalid gzipped sitemap: {e}"))?;
+        if xml.len() as u64 > MAX_SITEMAP_SIZE {
+            return Err(anyhow::anyhow!(
+                "gzipped sitemap is larger than {} MiB uncompressed",
+                MAX_SITEMAP_SIZE / (1024 * 1024)
+            ));
+        }
+        xml
+    } else {
+        String::from_utf8_lossy(bytes).into_owned()
+    };
+
+    let document = Document::parse(&xml).map_err(|e| anyhow::anyhow!("invalid XML: {e}"))?;
+    let root = document.root_element();
+    match root.tag_name().name() {
+        "urlset" => Ok(Sitemap::UrlSet(
+            children(root, "url")
+                .filter_map(|url| {
+                    Some(SitemapEntry {
+                        loc: child_text(url, "loc")?,
+                        lastmod: child_text(url, "lastmod"),
+                        priority: child_text(url, "priority").and_then(|p| p.parse().ok()),
+                    })
+                })
+                .collect(),
+        )),
+        "sitemapindex" => Ok(Sitemap::Index(
+            children(root, "sitemap")
+                .filter_map(|sitemap| child_text(sitemap, "loc"))
+                .collect(),
+        )),
+        other => Err(anyhow::anyhow!("not a sitemap: root element is <{other}>")),
+    }
+}
+
+/// Read every page listed in the sitemap at `url`, following sitemap indexes,
+/// and apply `filter`.
+///
+/// A site root such as `https://example.com/` is looked up at `/sitemap.xml`.
+/// `fetch` returns the raw body of a URL and the URL it was served from after
+/// redirects. Entries are returned in sitemap order. Nested sitemaps are only
+/// fetched from the origin the top-level sitemap was served from, so that request
+/// headers such as credentials are not sent to other sites; the rest are skipped,
+/// as are nested sitemaps that fail to load or are redirected elsewhere. A failing
+/// top-level sitemap is an error.
+pub async fn sitemap_entries<F, Fut>(
+    url: &str,
+    filter: &SitemapFilter,
+    fetch: F,
+) -> anyhow::Result<Vec<SitemapEntry>>
+where
+    F: Fn(String) -> Fut,
+    Fut: Future<Output = Result<FetchedSitemap, String>>,
+{
+    let mut start = Url::parse(url).map_err(|e| anyhow::anyhow!("invalid URL \"{url}\": {e}"))?;
+    if start.path() == "/" && start.query().is_none() {
+        start.set_path("/sitemap.xml");
+    }
+    let modified_since = filter
+        .modified_since
+        .as_deref()
+        .map(|date| {
+            parse_date(date).ok_or_else(|| {
+                anyhow::anyhow!("invalid date \"{date}\"; expected YYYY-MM-DD or RFC 3339")
+            })
+        })
+        .transpose()?;
+
+    let fetched = fetch(start.to_string())
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to fetch {start}: {e}"))?;
+    let origin = Url::parse(&fetched.url).map_or_else(|_| start.origin(), |url| url.origin());
+    let same_origin = |url: &str| Url::parse(url).is_ok_and(|url| url.origin() == origin);
+    let mut pending = vec![parse_sitemap(&fetched.body)?];
+    let mut seen: HashSet<String> = HashSet::from([start.to_string(), fetched.url]);
+    let mut entries = Vec::new();
+
+    while !pending.is_empty() {
+        let mut nested = Vec::new();
+        for sitemap in pending {
+            match sitemap {
+                Sitemap::UrlSet(urls) => entries.extend(urls),
+                Sitemap::Index(sitemaps) => nested.extend(sitemaps.into_iter().filter(|loc| {
+                    same_origin(loc) && seen.len() < MAX_SITEMAPS && seen.insert(loc.clone())
+                })),
+            }
+        }
+        pending = fetch_many(nested, DEFAULT_CONCURRENCY, &fetch)
+            .await
+            .into_iter()
+            .filter_map(|fetched| {
+                let fetched = fetched.ok().filter(|fetched| same_origin(&fetched.url))?;
+                parse_sitemap(&fetched.body).ok()
+            })
+            .collect();
+    }
+
+    entries.retain(|entry| {
+        let prefix_matches = filter.path_prefix.as_deref().is_none_or(|prefix| {
+            if prefix.contains("://") {
+                entry.loc.starts_with(prefix)
+            } else {
+                Url::parse(&entry.loc).is_ok_and(|loc| loc.path().starts_with(prefix))
+            }
+        });
+        let recent = modified_since.is_none_or(|since| {
+            entry
+                .lastmod
+                .as_deref()
+                .and_then(parse_date)
+                .is_some_and(|lastmod| lastmod >= since)
+        });
+        prefix_matches && recent
+    });
+    Ok(entries)
+}
+
+/// Parse a W3C datetime as used in sitemaps: a date, or a date and time with
+/// an offset, with or without seconds. Dates are taken as midnight UTC.
+fn parse_date(date: &str) -> Option<DateTime<FixedOffset>> {
+    let date = date.trim();
+    DateTime::parse_from_rfc3339(date)
+        .or_else(|_| DateTime::parse_from_str(&date.replace('Z', "+00:00"), "%Y-%m-%dT%H:%M%:z"))
+        .ok()
+        .or_else(|| {
+            let day = NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()?;
+            Some(day.and_hms_opt(0, 0, 0)?.and_utc().fixed_offset())
+        })
+}
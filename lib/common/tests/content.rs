@@ -0,0 +1,101 @@
+//! Tests for content type sniffing and source language detection.
+
+use common::{is_binary, looks_binary, sniff_content_type, source_language};
+
+const PDF: &[u8] = b"%PDF-1.7\n%\xe2\xe3\xcf\xd3\n1 0 obj\n<< /Type /Catalog >>\nendobj\n";
+
+#[test]
+fn sniffs_json() {
+    assert_eq!(sniff_content_type(" {\"a\": [1, 2]}"), "application/json");
+    assert_eq!(sniff_content_type("\u{feff}[1, 2]"), "application/json");
+    // Looks like JSON but does not parse
+    assert_eq!(sniff_content_type("{not json}"), "text/plain");
+}
+
+#[test]
+fn sniffs_html() {
+    for text in [
+        "<!DOCTYPE html><p>hi</p>",
+        "<html><body>hi</body></html>",
+        "<?xml version=\"1.0\"?>\n<html><head></head></html>",
+        "<div>fragment</div>",
+    ] {
+        assert_eq!(sniff_content_type(text), "text/html", "{text}");
+    }
+}
+
+#[test]
+fn sniffs_feeds_and_xml() {
+    assert_eq!(
+        sniff_content_type("<?xml version=\"1.0\"?><rss version=\"2.0\"></rss>"),
+        "application/rss+xml"
+    );
+    assert_eq!(
+        sniff_content_type("<feed xmlns=\"http://www.w3.org/2005/Atom\"></feed>"),
+        "application/atom+xml"
+    );
+    assert_eq!(
+        sniff_content_type("<?xml version=\"1.0\"?><note><to>you</to></note>"),
+        "application/xml"
+    );
+}
+
+#[test]
+fn sniffs_plain_text() {
+    assert_eq!(sniff_content_type("just some words"), "text/plain");
+    assert_eq!(sniff_content_type(""), "text/plain");
+}
+
+#[test]
+fn pdf_is_binary() {
+    assert!(looks_binary(PDF));
+    assert!(is_binary(Some("application/pdf"), b"%PDF-1.7"));
+    assert!(is_binary(None, PDF));
+    assert!(is_binary(Some("application/octet-stream"), b"plain"));
+}
+
+#[test]
+fn text_is_not_binary() {
+    assert!(!looks_binary("<p>héllo</p>".as_bytes()));
+    assert!(!is_binary(Some("text/html; charset=utf-8"), &[0, 159, 146]));
+    assert!(!is_binary(Some("application/ld+json"), b"{}"));
+    assert!(!is_binary(None, b"{\"a\": 1}"));
+    assert!(!looks_binary(b""));
+}
+
+#[test]
+fn nul_bytes_and_invalid_utf8_are_binary() {
+    assert!(looks_binary(b"abc\0def"));
+    assert!(looks_binary(&[0x89, b'P', b'N', b'G']));
+    assert!(is_binary(
+        Some("application/x-unknown"),
+        &[0xff, 0xfe, 0x00]
+    ));
+}
+
+#[test]
+fn multibyte_character_cut_at_the_sniff_limit_is_text() {
+    let mut body = "a".repeat(8191).into_bytes();
+    body.extend_from_slice("é".as_bytes());
+    assert!(!looks_binary(&body));
+}
+
+#[test]
+fn detects_source_languages() {
+    assert_eq!(source_language("src/main.rs"), Some("rust"));
+    assert_eq!(
+        source_language("https://example.com/app.TSX?raw=1#L3"),
+        Some("tsx")
+    );
+    assert_eq!(source_language("docker/Dockerfile"), Some("dockerfile"));
+    assert_eq!(source_language("Makefile"), Some("makefile"));
+    assert_eq!(source_language("config.yml"), Some("yaml"));
+}
+
+#[test]
+fn prose_and_unknown_files_have_no_language() {
+    assert_eq!(source_language("README.md"), None);
+    assert_eq!(source_language("notes.txt"), None);
+    assert_eq!(source_language("LICENSE"), None);
+    assert_eq!(source_language("https://example.com/"), None);
+}
@@ -0,0 +1,60 @@
+//! Golden-file tests for the CSV, TSV, YAML, TOML and XML converters.
+//!
+//! Each `fixtures/convert/<name>.<ext>` input is converted and compared with
+//! `<name>.expected.md`.
+
+mod golden;
+
+use common::{csv_to_markdown, toml_to_markdown, xml_to_markdown, yaml_to_markdown};
+
+fn assert_golden(file: &str, convert: impl Fn(&str) -> anyhow::Result<String>) {
+    let input = golden::read_fixture(&format!("convert/{file}"));
+    let name = file.rsplit_once('.').unwrap().0;
+    golden::assert_golden(
+        &format!("convert/{name}.expected.md"),
+        &convert(&input).unwrap(),
+    );
+}
+
+#[test]
+fn converts_csv() {
+    assert_golden("people.csv", |text| csv_to_markdown(text, b','));
+}
+
+#[test]
+fn converts_tsv() {
+    assert_golden("issues.tsv", |text| csv_to_markdown(text, b'\t'));
+}
+
+#[test]
+fn converts_yaml() {
+    assert_golden("config.yaml", yaml_to_markdown);
+}
+
+#[test]
+fn converts_yaml_with_non_string_keys() {
+    assert_golden("keys.yaml", yaml_to_markdown);
+}
+
+#[test]
+fn converts_toml() {
+    assert_golden("package.toml", toml_to_markdown);
+}
+
+#[test]
+fn converts_xml() {
+    assert_golden("catalog.xml", xml_to_markdown);
+}
+
+#[test]
+fn reports_invalid_input() {
+    let errors = [
+        yaml_to_markdown("a: [1, 2").unwrap_err(),
+        toml_to_markdown("a = ").unwrap_err(),
+        xml_to_markdown("<a><b></a>").unwrap_err(),
+    ];
+    let prefixes = ["invalid YAML: ", "invalid TOML: ", "invalid XML: "];
+    for (err, prefix) in errors.iter().zip(prefixes) {
+        assert!(err.to_string().starts_with(prefix), "{err}");
+    }
+}
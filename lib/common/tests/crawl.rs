@@ -0,0 +1,238 @@
+//! Tests for `crawl` against an in-memory site and the static site in
+//! `tests/fixtures/site`.
+
+mod golden;
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use common::{CrawlOptions, CrawledPage, FetchedPage, crawl, html_to_markdown};
+use futures::executor::block_on;
+
+/// A small documentation site, as the markdown each page converts to.
+fn site() -> HashMap<&'static str, &'static str> {
+    HashMap::from([
+        (
+            "https://example.com/docs/",
+            "# Docs\n\n[Guide](guide.html) [Install](install/#top) [Blog](/blog/) \
+             [Other](https://other.example/docs/) ![Logo](logo.html)",
+        ),
+        (
+            "https://example.com/docs/guide.html",
+            "# Guide\n\n[Docs](./index.html) [Advanced](advanced.html) [Missing](missing.html)",
+        ),
+        (
+            "https://example.com/docs/install/",
+            "## Installing\n\n[Guide](../guide.html?)",
+        ),
+        (
+            "https://example.com/docs/advanced.html",
+            "No heading here. [Deep](deep.html)",
+        ),
+        ("https://example.com/docs/deep.html", "# Deep"),
+        ("https://example.com/docs/logo.html", "# Logo"),
+        ("https://example.com/blog/", "# Blog"),
+        ("https://other.example/", "# Other"),
+        ("https://other.example/docs/", "# Other docs"),
+        (
+            "https://example.com/docs/moves.html",
+            "# Moves\n\n[Old](old.html) [Guide](guide.html) [Away](away.html)",
+        ),
+    ])
+}
+
+/// Redirects served by the in-memory site, from URL to location.
+fn redirects() -> HashMap<&'static str, &'static str> {
+    HashMap::from([
+        ("https://example.com/docs", "https://example.com/docs/"),
+        (
+            "https://example.com/docs/old.html",
+            "https://example.com/docs/guide.html",
+        ),
+        (
+            "https://example.com/docs/away.html",
+            "https://other.example/docs/",
+        ),
+        ("https://example.com/elsewhere/", "https://other.example/"),
+    ])
+}
+
+fn run(start: &str, options: CrawlOptions) -> anyhow::Result<Vec<CrawledPage>> {
+    let (site, redirects) = (site(), redirects());
+    block_on(crawl(start, &options, |url| {
+        let url = redirects.get(url.as_str()).map_or(url, |to| to.to_string());
+        let page = site.get(url.as_str()).map(|page| page.to_string());
+        async move {
+            let markdown = page.ok_or_else(|| format!("404 for {url}"))?;
+            Ok(FetchedPage { url, markdown })
+        }
+    }))
+}
+
+fn urls(pages: &[CrawledPage]) -> Vec<&str> {
+    pages.iter().map(|page| page.url.as_str()).collect()
+}
+
+#[test]
+fn stays_in_scope_and_deduplicates() {
+    let pages = run(
+        "https://example.com/docs/index.html#intro",
+        CrawlOptions::default(),
+    )
+    .unwrap();
+    assert_eq!(
+        urls(&pages),
+        [
+            "https://example.com/docs/",
+            "https://example.com/docs/guide.html",
+            "https://example.com/docs/install/",
+            "https://example.com/docs/advanced.html",
+        ]
+    );
+}
+
+#[test]
+fn titles_come_from_headings_or_url() {
+    let pages = run("https://example.com/docs/", CrawlOptions::default()).unwrap();
+    let titles: Vec<&str> = pages.iter().map(|page| page.title.as_str()).collect();
+    assert_eq!(
+        titles,
+        [
+            "Docs",
+            "Guide",
+            "Installing",
+            "https://example.com/docs/advanced.html"
+        ]
+    );
+}
+
+#[test]
+fn respects_max_depth() {
+    let options = CrawlOptions {
+        max_depth: 0,
+        ..CrawlOptions::default()
+    };
+    let pages = run("https://example.com/docs/", options).unwrap();
+    assert_eq!(urls(&pages), ["https://example.com/docs/"]);
+
+    let options = CrawlOptions {
+        max_depth: 3,
+        ..CrawlOptions::default()
+    };
+    let pages = run("https://example.com/docs/", options).unwrap();
+    assert_eq!(
+        pages.last().unwrap().url,
+        "https://example.com/docs/deep.html"
+    );
+}
+
+#[test]
+fn respects_max_pages() {
+    let options = CrawlOptions {
+        max_pages: 2,
+        concurrency: 1,
+        ..CrawlOptions::default()
+    };
+    let pages = run("https://example.com/docs/", options).unwrap();
+    assert_eq!(
+        urls(&pages),
+        [
+            "https://example.com/docs/",
+            "https://example.com/docs/guide.html"
+        ]
+    );
+}
+
+#[test]
+fn rejects_zero_max_pages() {
+    let options = CrawlOptions {
+        max_pages: 0,
+        ..CrawlOptions::default()
+    };
+    let error = run("https://example.com/docs/", options).unwrap_err();
+    assert_eq!(error.to_string(), "max-pages must be at least 1");
+}
+
+#[test]
+fn fails_when_start_page_fails() {
+    let error = run("https://example.com/missing/", CrawlOptions::default()).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "failed to fetch https://example.com/missing/: 404 for https://example.com/missing/"
+    );
+    assert!(run("ftp://example.com/", CrawlOptions::default()).is_err());
+}
+
+#[test]
+fn follows_redirects_within_the_origin() {
+    let pages = run("https://example.com/docs", CrawlOptions::default()).unwrap();
+    assert_eq!(pages[0].url, "https://example.com/docs/");
+    // Relative links resolve against the redirected URL
+    assert_eq!(pages[1].url, "https://example.com/docs/guide.html");
+}
+
+#[test]
+fn returns_redirected_pages_once_and_skips_other_origins() {
+    let options = CrawlOptions {
+        max_depth: 1,
+        ..CrawlOptions::default()
+    };
+    let pages = run("https://example.com/docs/moves.html", options).unwrap();
+    assert_eq!(
+        urls(&pages),
+        [
+            "https://example.com/docs/moves.html",
+            "https://example.com/docs/guide.html",
+        ]
+    );
+
+    let error = run("https://example.com/elsewhere/", CrawlOptions::default()).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "failed to fetch https://example.com/elsewhere/: redirected to another origin: https://other.example/"
+    );
+}
+
+/// Serve `tests/fixtures/site` the way a static file server does: directories
+/// are redirected to a trailing slash and then served from their index.html.
+fn serve(url: &str) -> Result<FetchedPage, String> {
+    let root = golden::fixture_path("site");
+    let path = url
+        .strip_prefix("http://127.0.0.1:8765/")
+        .ok_or_else(|| format!("unexpected URL {url}"))?;
+    let mut file: PathBuf = root.join(path);
+    let mut url = url.to_string();
+    if file.is_dir() {
+        if !url.ends_with('/') {
+            url.push('/');
+        }
+        file = file.join("index.html");
+    }
+    let html = std::fs::read_to_string(&file).map_err(|e| format!("404 for {url}: {e}"))?;
+    Ok(FetchedPage {
+        url,
+        markdown: html_to_markdown(&html),
+    })
+}
+
+#[test]
+fn crawls_the_fixture_site() {
+    let pages = block_on(crawl(
+        "http://127.0.0.1:8765/docs",
+        &CrawlOptions::default(),
+        |url| async move { serve(&url) },
+    ))
+    .unwrap();
+    let found: Vec<(&str, &str)> = pages
+        .iter()
+        .map(|page| (page.url.as_str(), page.title.as_str()))
+        .collect();
+    assert_eq!(
+        found,
+        [
+            ("http://127.0.0.1:8765/docs/", "Documentation"),
+            ("http://127.0.0.1:8765/docs/guide.html", "Guide"),
+            ("http://127.0.0.1:8765/docs/api/", "API reference"),
+        ]
+    );
+}
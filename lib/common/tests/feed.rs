@@ -0,0 +1,88 @@
+//! Golden-file tests for RSS 2.0, RSS 1.0 (RDF) and Atom feed digests.
+//!
+//! Each `fixtures/feed/<name>.xml` feed is converted and compared with
+//! `<name>.expected.md`.
+
+mod golden;
+
+use common::{feed_content_type, feed_to_markdown, parse_feed, sniff_content_type};
+
+fn fixture(name: &str) -> String {
+    golden::read_fixture(&format!("feed/{name}.xml"))
+}
+
+fn assert_golden(name: &str) {
+    let feed = parse_feed(&fixture(name)).unwrap();
+    golden::assert_golden(
+        &format!("feed/{name}.expected.md"),
+        &feed_to_markdown(&feed),
+    );
+}
+
+#[test]
+fn converts_rss2() {
+    assert_golden("rss2");
+}
+
+#[test]
+fn converts_rdf() {
+    assert_golden("rdf");
+}
+
+#[test]
+fn converts_atom() {
+    assert_golden("atom");
+}
+
+#[test]
+fn recognises_feeds_by_root_element() {
+    assert_eq!(
+        feed_content_type(&fixture("rss2")),
+        Some("application/rss+xml")
+    );
+    assert_eq!(
+        feed_content_type(&fixture("rdf")),
+        Some("application/rdf+xml")
+    );
+    assert_eq!(
+        feed_content_type(&fixture("atom")),
+        Some("application/atom+xml")
+    );
+    assert_eq!(
+        feed_content_type("<!DOCTYPE feed>\n<feed xmlns=\"http://www.w3.org/2005/Atom\"/>"),
+        Some("application/atom+xml")
+    );
+    assert_eq!(feed_content_type("<catalog><rss/></catalog>"), None);
+    assert_eq!(feed_content_type("not xml"), None);
+
+    assert_eq!(sniff_content_type(&fixture("atom")), "application/atom+xml");
+    assert_eq!(
+        sniff_content_type("<?xml version=\"1.0\"?><catalog/>"),
+        "application/xml"
+    );
+}
+
+#[test]
+fn rejects_other_documents() {
+    let err = parse_feed("<catalog/>").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "unsupported feed format: root element is <catalog>"
+    );
+    let err = parse_feed("<rss version=\"2.0\"/>").unwrap_err();
+    assert_eq!(err.to_string(), "RSS feed has no channel element");
+}
+
+#[test]
+fn escapes_entry_titles_and_links() {
+    let feed = parse_feed(
+        r#"<rss version="2.0"><channel><title>Notes</title>
+  <item><title>Arrays [] and *stars*</title><link>https://example.com/a (draft)</link></item>
+</channel></rss>"#,
+    )
+    .unwrap();
+    assert_eq!(
+        feed_to_markdown(&feed),
+        "# Notes\n\n## [Arrays \\[\\] and \\*stars\\*](<https://example.com/a (draft)>)"
+    );
+}
@@ -0,0 +1,78 @@
+//! Tests for running fetches with bounded concurrency.
+
+use common::fetch_many;
+use futures::executor::block_on;
+use std::cell::Cell;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// A future that returns `Pending` a given number of times before completing.
+struct Yield(usize);
+
+impl Future for Yield {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.0 == 0 {
+            return Poll::Ready(());
+        }
+        self.0 -= 1;
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
+}
+
+fn urls(count: usize) -> Vec<String> {
+    (0..count)
+        .map(|i| format!("https://example.com/{i}"))
+        .collect()
+}
+
+/// Fetch `urls` where later URLs finish sooner, recording the most requests
+/// seen in flight at once.
+fn run(urls: Vec<String>, concurrency: usize) -> (Vec<String>, usize) {
+    let count = urls.len();
+    let in_flight = Cell::new(0);
+    let peak = Cell::new(0);
+    let results = block_on(fetch_many(urls, concurrency, |url| {
+        let (in_flight, peak) = (&in_flight, &peak);
+        async move {
+            in_flight.set(in_flight.get() + 1);
+            peak.set(peak.get().max(in_flight.get()));
+            let index: usize = url.rsplit('/').next().unwrap().parse().unwrap();
+            Yield(count - index).await;
+            in_flight.set(in_flight.get() - 1);
+            url
+        }
+    }));
+    (results, peak.get())
+}
+
+#[test]
+fn returns_results_in_input_order() {
+    let (results, _) = run(urls(6), 3);
+    assert_eq!(results, urls(6));
+}
+
+#[test]
+fn limits_requests_in_flight() {
+    for concurrency in [1, 2, 4] {
+        let (_, peak) = run(urls(8), concurrency);
+        assert_eq!(peak, concurrency);
+    }
+}
+
+#[test]
+fn zero_concurrency_runs_one_at_a_time() {
+    let (results, peak) = run(urls(3), 0);
+    assert_eq!(results, urls(3));
+    assert_eq!(peak, 1);
+}
+
+#[test]
+fn empty_input_returns_nothing() {
+    let (results, peak) = run(Vec::new(), 4);
+    assert!(results.is_empty());
+    assert_eq!(peak, 0);
+}
@@ -0,0 +1,38 @@
+//! Golden-file comparison shared by the fixture-driven tests.
+//!
+//! Run the tests with `UPDATE_GOLDEN=1` to rewrite the expected files from the
+//! current output.
+
+#![allow(dead_code)]
+
+use std::{fs, path::PathBuf};
+
+/// The path of a file under `tests/fixtures`.
+pub fn fixture_path(path: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(path)
+}
+
+/// Read a text fixture, such as `feed/rss2.xml`.
+pub fn read_fixture(path: &str) -> String {
+    fs::read_to_string(fixture_path(path)).unwrap_or_else(|e| panic!("{path}: {e}"))
+}
+
+/// Read a binary fixture, such as `pdf/report.pdf`.
+pub fn read_fixture_bytes(path: &str) -> Vec<u8> {
+    fs::read(fixture_path(path)).unwrap_or_else(|e| panic!("{path}: {e}"))
+}
+
+/// Compare `actual`, followed by a newline, with the expected file at `path`
+/// under `tests/fixtures`.
+pub fn assert_golden(path: &str, actual: &str) {
+    let expected_path = fixture_path(path);
+    let actual = format!("{actual}\n");
+    if std::env::var_os("UPDATE_GOLDEN").is_some() {
+        fs::write(&expected_path, &actual).unwrap();
+        return;
+    }
+    let expected = read_fixture(path);
+    assert_eq!(actual, expected, "output for {path} changed");
+}
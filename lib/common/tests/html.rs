@@ -0,0 +1,76 @@
+//! Golden-file tests for HTML to markdown conversion.
+//!
+//! Each `fixtures/html/<name>.html` input is converted and compared with
+//! `<name>.<variant>.expected.md`.
+
+mod golden;
+
+use common::{ConversionOptions, HeadingStyle, LinkStyle, html_to_markdown, html_to_markdown_with};
+
+fn assert_golden(name: &str, variant: &str, convert: impl Fn(&str) -> String) {
+    let input = golden::read_fixture(&format!("html/{name}.html"));
+    golden::assert_golden(
+        &format!("html/{name}.{variant}.expected.md"),
+        &convert(&input),
+    );
+}
+
+fn with(options: ConversionOptions) -> impl Fn(&str) -> String {
+    move |html| html_to_markdown_with(html, &options).unwrap()
+}
+
+#[test]
+fn default_conversion_keeps_the_original_output() {
+    assert_golden("baseline", "default", html_to_markdown);
+    assert_golden("article", "default", html_to_markdown);
+}
+
+#[test]
+fn converts_document_structure() {
+    assert_golden("article", "options", with(ConversionOptions::default()));
+}
+
+#[test]
+fn applies_style_options() {
+    let options = ConversionOptions {
+        heading_style: HeadingStyle::Setext,
+        bullet: '*',
+        keep_images: false,
+        link_style: LinkStyle::Reference,
+        max_line_width: 40,
+        preserve_raw_html: false,
+    };
+    assert_golden("article", "styled", with(options));
+}
+
+#[test]
+fn preserves_raw_html_for_unknown_elements() {
+    let options = ConversionOptions {
+        preserve_raw_html: true,
+        ..Default::default()
+    };
+    assert_golden("article", "raw", with(options));
+}
+
+#[test]
+fn escapes_markdown_syntax_in_text() {
+    assert_golden("escaping", "options", with(ConversionOptions::default()));
+    let options = ConversionOptions {
+        link_style: LinkStyle::Reference,
+        ..Default::default()
+    };
+    assert_golden("escaping", "reference", with(options));
+}
+
+#[test]
+fn rejects_invalid_bullets() {
+    let options = ConversionOptions {
+        bullet: 'x',
+        ..Default::default()
+    };
+    let err = html_to_markdown_with("<p>x</p>", &options).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid bullet character 'x', expected one of '-', '*' or '+'"
+    );
+}
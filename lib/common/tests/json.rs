@@ -0,0 +1,39 @@
+//! Tests for JSON to markdown conversion and the raw-text fallback.
+
+use common::{fenced_code_block, json_to_markdown};
+
+#[test]
+fn renders_objects_and_arrays() {
+    let markdown =
+        json_to_markdown(r#"{"name": "crate", "tags": ["wasm", 1, true, null]}"#).unwrap();
+    assert_eq!(
+        markdown,
+        "### name\n\ncrate\n\n### tags\n\n1. wasm\n\n1. 1\n\n1. true\n\n1. null\n\n\n"
+    );
+}
+
+#[test]
+fn reports_invalid_json() {
+    for json in ["", "{\"truncated\": [1, 2", "not json"] {
+        let err = json_to_markdown(json).unwrap_err().to_string();
+        assert!(err.starts_with("invalid JSON: "), "{json:?}: {err}");
+    }
+}
+
+#[test]
+fn json_null_is_still_rendered() {
+    assert_eq!(json_to_markdown("null").unwrap(), "null");
+}
+
+#[test]
+fn fences_raw_text() {
+    assert_eq!(
+        fenced_code_block("{\"a\": 1\n", "json"),
+        "```json\n{\"a\": 1\n```"
+    );
+}
+
+#[test]
+fn fence_is_longer_than_backticks_in_the_text() {
+    assert_eq!(fenced_code_block("a ```` b", ""), "`````\na ```` b\n`````");
+}
@@ -0,0 +1,100 @@
+//! Tests for the markdown outline, link extraction and sections.
+
+use common::{LinkKind, extract_links, outline, section};
+
+const DOC: &str = "\
+# Guide
+
+Intro with [a link](https://example.com \"Example\").
+
+## Install
+
+Run `cargo build`.
+
+### On Linux
+
+Use your package manager.
+
+## Usage
+
+Café ☕ details.
+
+## Install
+
+Again.
+";
+
+#[test]
+fn builds_the_heading_tree() {
+    let headings = outline(DOC);
+    let summary: Vec<(u8, &str, &str, Option<usize>)> = headings
+        .iter()
+        .map(|h| (h.level, h.text.as_str(), h.anchor.as_str(), h.parent))
+        .collect();
+    assert_eq!(
+        summary,
+        [
+            (1, "Guide", "guide", None),
+            (2, "Install", "install", Some(0)),
+            (3, "On Linux", "on-linux", Some(1)),
+            (2, "Usage", "usage", Some(0)),
+            (2, "Install", "install-1", Some(0)),
+        ]
+    );
+}
+
+#[test]
+fn offsets_are_in_characters() {
+    let headings = outline(DOC);
+    let chars: Vec<char> = DOC.chars().collect();
+    for heading in &headings {
+        let text: String = chars[heading.start..heading.end].iter().collect();
+        assert!(text.starts_with('#'), "{text:?}");
+    }
+    // The section after the multibyte text still ends at the document end
+    assert_eq!(headings.last().unwrap().end, DOC.chars().count());
+    let usage = &headings[3];
+    let text: String = chars[usage.start..usage.end].iter().collect();
+    assert_eq!(text, "## Usage\n\nCafé ☕ details.\n\n");
+}
+
+#[test]
+fn extracts_links_in_document_order() {
+    let links = extract_links(
+        "[![badge](https://img.example/b.svg)](https://ci.example \"CI\") \
+         then [ref][docs] and ![logo](logo.png)\n\n[docs]: https://docs.example \"Docs\"",
+    );
+    let summary: Vec<(LinkKind, &str, &str, &str)> = links
+        .iter()
+        .map(|l| (l.kind, l.text.as_str(), l.target.as_str(), l.title.as_str()))
+        .collect();
+    assert_eq!(
+        summary,
+        [
+            (LinkKind::Link, "badge", "https://ci.example", "CI"),
+            (LinkKind::Image, "badge", "https://img.example/b.svg", ""),
+            (LinkKind::Link, "ref", "https://docs.example", "Docs"),
+            (LinkKind::Image, "logo", "logo.png", ""),
+        ]
+    );
+}
+
+#[test]
+fn returns_a_section_by_heading_path() {
+    assert_eq!(
+        section(DOC, &["guide".into(), "install".into(), "on linux".into()]).unwrap(),
+        "### On Linux\n\nUse your package manager."
+    );
+    assert_eq!(
+        section(DOC, &["Install".into()]).unwrap(),
+        "## Install\n\nRun `cargo build`.\n\n### On Linux\n\nUse your package manager."
+    );
+}
+
+#[test]
+fn reports_missing_headings() {
+    let err = section(DOC, &["Guide".into(), "Missing".into()]).unwrap_err();
+    assert_eq!(err.to_string(), "no heading matching \"Missing\"");
+    let err = section(DOC, &[]).unwrap_err();
+    assert_eq!(err.to_string(), "heading path is empty");
+}
@@ -0,0 +1,64 @@
+//! Tests for paging through text by character position.
+
+use common::paginate;
+
+#[test]
+fn returns_text_unchanged_without_parameters() {
+    assert_eq!(paginate("héllo", None, None).unwrap(), "héllo");
+}
+
+#[test]
+fn counts_characters_not_bytes() {
+    let text = "añb€c😀d";
+    assert_eq!(
+        paginate(text, Some(1), Some(3)).unwrap(),
+        "ñb€\n\n[Showing characters 1-4 of 7. Content truncated, continue at start-index 4.]"
+    );
+    assert_eq!(
+        paginate(text, Some(4), Some(3)).unwrap(),
+        "c😀d\n\n[Showing characters 4-7 of 7. End of content.]"
+    );
+}
+
+#[test]
+fn following_the_hint_reads_everything_once() {
+    let text = "日本語のテキストを少しずつ読む";
+    let mut start = 0;
+    let mut read = String::new();
+    loop {
+        let page = paginate(text, Some(start), Some(4)).unwrap();
+        let (window, note) = page.rsplit_once("\n\n").unwrap();
+        read.push_str(window);
+        match note.split("continue at start-index ").nth(1) {
+            Some(next) => start = next.trim_end_matches(".]").parse().unwrap(),
+            None => break,
+        }
+    }
+    assert_eq!(read, text);
+}
+
+#[test]
+fn rejects_a_start_at_or_past_the_end() {
+    for start in [5, 6] {
+        let err = paginate("héllo", Some(start), None).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!("start-index {start} is past the end of the content (5 characters)")
+        );
+    }
+}
+
+#[test]
+fn rejects_zero_length() {
+    let err = paginate("héllo", Some(0), Some(0)).unwrap_err();
+    assert_eq!(err.to_string(), "max-length must be greater than zero");
+}
+
+#[test]
+fn empty_text_has_one_empty_page() {
+    assert_eq!(
+        paginate("", Some(0), Some(10)).unwrap(),
+        "\n\n[Showing characters 0-0 of 0. End of content.]"
+    );
+    assert!(paginate("", Some(1), None).is_err());
+}
@@ -0,0 +1,132 @@
+//! Golden-file tests for `pdf_to_markdown`.
+//!
+//! Each `fixtures/pdf/<name>.pdf` input is converted and compared with
+//! `<name>.expected.md`.
+
+mod golden;
+
+use common::pdf_to_markdown;
+use pdf_extract::{Document, Object, Stream, dictionary};
+
+fn fixture(name: &str) -> Vec<u8> {
+    golden::read_fixture_bytes(&format!("pdf/{name}.pdf"))
+}
+
+fn assert_golden(name: &str) {
+    golden::assert_golden(
+        &format!("pdf/{name}.expected.md"),
+        &pdf_to_markdown(&fixture(name)).unwrap(),
+    );
+}
+
+#[test]
+fn detects_headings_paragraphs_and_pages() {
+    assert_golden("report");
+}
+
+#[test]
+fn converts_plain_text() {
+    assert_golden("plain");
+}
+
+#[test]
+fn rejects_pdf_without_text() {
+    let error = pdf_to_markdown(&fixture("empty")).unwrap_err();
+    assert!(error.to_string().contains("no extractable text"), "{error}");
+}
+
+#[test]
+fn rejects_invalid_pdf() {
+    let error = pdf_to_markdown(b"<html>not a pdf</html>").unwrap_err();
+    assert!(error.to_string().starts_with("invalid PDF"), "{error}");
+}
+
+#[test]
+fn rejects_input_without_a_header_before_parsing() {
+    for bytes in [&b""[..], b"PDF-1.7", b"\x00\x01\x02%PD"] {
+        let error = pdf_to_markdown(bytes).unwrap_err();
+        assert_eq!(error.to_string(), "invalid PDF: missing %PDF- header");
+    }
+}
+
+#[test]
+fn rejects_truncated_pdf() {
+    let mut bytes = fixture("empty");
+    bytes.truncate(64);
+    let error = pdf_to_markdown(&bytes).unwrap_err();
+    assert!(error.to_string().starts_with("invalid PDF"), "{error}");
+}
+
+/// A one-page PDF showing "Hello", with `media_box` set on the page if given.
+fn one_page(media_box: Option<Vec<Object>>, content: &[u8]) -> Vec<u8> {
+    let mut doc = Document::with_version("1.5");
+    let pages_id = doc.new_object_id();
+    let font_id = doc.add_object(dictionary! {
+        "Type" => "Font",
+        "Subtype" => "Type1",
+        "BaseFont" => "Helvetica",
+    });
+    let content_id = doc.add_object(Stream::new(dictionary! {}, content.to_vec()));
+    let mut page = dictionary! {
+        "Type" => "Page",
+        "Parent" => pages_id,
+        "Contents" => content_id,
+        "Resources" => dictionary! { "Font" => dictionary! { "F1" => font_id } },
+    };
+    if let Some(media_box) = media_box {
+        page.set("MediaBox", media_box);
+    }
+    let page_id = doc.add_object(page);
+    doc.objects.insert(
+        pages_id,
+        Object::Dictionary(dictionary! {
+            "Type" => "Pages",
+            "Kids" => vec![page_id.into()],
+            "Count" => 1,
+        }),
+    );
+    let catalog_id = doc.add_object(dictionary! { "Type" => "Catalog", "Pages" => pages_id });
+    doc.trailer.set("Root", catalog_id);
+    let mut bytes = Vec::new();
+    doc.save_to(&mut bytes).unwrap();
+    bytes
+}
+
+const HELLO: &[u8] = b"BT /F1 12 Tf 72 720 Td (Hello) Tj ET";
+
+#[test]
+fn converts_a_generated_page() {
+    let media_box = vec![0.into(), 0.into(), 612.into(), 792.into()];
+    let markdown = pdf_to_markdown(&one_page(Some(media_box), HELLO)).unwrap();
+    assert_eq!(markdown, "<!-- page 1 -->\n\nHello");
+}
+
+#[test]
+fn rejects_damaged_pages_instead_of_panicking() {
+    let error = pdf_to_markdown(&one_page(None, HELLO)).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "invalid PDF: page 1 has no valid media box"
+    );
+
+    let short = vec![0.into(), 0.into(), 612.into()];
+    let error = pdf_to_markdown(&one_page(Some(short), HELLO)).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "invalid PDF: page 1 has no valid media box"
+    );
+}
+
+#[test]
+fn rejects_oversized_input() {
+    let mut bytes = b"%PDF-1.7\n".to_vec();
+    bytes.resize(50 * 1024 * 1024 + 1, b' ');
+    let error = pdf_to_markdown(&bytes).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        format!(
+            "PDF is too large to convert ({} bytes, limit 50 MiB)",
+            bytes.len()
+        )
+    );
+}
@@ -0,0 +1,60 @@
+//! Tests for JSONPath queries and their error messages.
+
+use common::{JsonQuery, query_json};
+
+const STORE: &str = r#"{"books": [{"title": "Dune", "price": 9}, {"title": "Emma", "price": 4}]}"#;
+
+#[test]
+fn returns_the_whole_document_without_a_query() {
+    let expected = "{\n  \"a\": [\n    1,\n    2\n  ]\n}";
+    assert_eq!(query_json(r#"{"a":[1,2]}"#, None).unwrap(), expected);
+    assert_eq!(query_json(r#"{"a":[1,2]}"#, Some("  ")).unwrap(), expected);
+}
+
+#[test]
+fn returns_matches_in_document_order() {
+    assert_eq!(
+        query_json(STORE, Some("$.books[*].title")).unwrap(),
+        "[\n  \"Dune\",\n  \"Emma\"\n]"
+    );
+    assert_eq!(
+        query_json(STORE, Some("$.books[?@.price < 5].title")).unwrap(),
+        "[\n  \"Emma\"\n]"
+    );
+}
+
+#[test]
+fn no_matches_is_an_empty_array() {
+    assert_eq!(query_json(STORE, Some("$.authors")).unwrap(), "[]");
+}
+
+#[test]
+fn reports_invalid_json() {
+    let err = query_json("{\"books\": [", Some("$.books"))
+        .unwrap_err()
+        .to_string();
+    assert!(err.starts_with("invalid JSON: "), "{err}");
+}
+
+#[test]
+fn reports_invalid_queries_separately() {
+    let err = query_json(STORE, Some("$.books[")).unwrap_err().to_string();
+    assert!(
+        err.starts_with("invalid JSONPath expression \"$.books[\": "),
+        "{err}"
+    );
+}
+
+#[test]
+fn parses_queries_before_any_document() {
+    let err = JsonQuery::parse(Some("$.books[")).unwrap_err().to_string();
+    assert!(
+        err.starts_with("invalid JSONPath expression \"$.books[\": "),
+        "{err}"
+    );
+    let query = JsonQuery::parse(Some("$.books[0].title")).unwrap();
+    assert_eq!(
+        query.apply(STORE).unwrap(),
+        query_json(STORE, Some("$.books[0].title")).unwrap()
+    );
+}
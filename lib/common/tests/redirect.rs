@@ -0,0 +1,110 @@
+//! Tests for how requests change when following redirects.
+
+use common::{HttpRequest, Method, redirect_request};
+
+fn post() -> HttpRequest {
+    HttpRequest {
+        method: Method::Post,
+        url: "https://api.example.com/v1/items".into(),
+        headers: vec![
+            ("Authorization".into(), "Bearer secret".into()),
+            ("cookie".into(), "session=1".into()),
+            ("Proxy-Authorization".into(), "Basic xyz".into()),
+            ("Content-Type".into(), "application/json".into()),
+            ("content-length".into(), "2".into()),
+            ("Accept".into(), "application/json".into()),
+        ],
+        body: Some(b"{}".to_vec()),
+        timeout: None,
+    }
+}
+
+fn header_names(request: &HttpRequest) -> Vec<&str> {
+    request
+        .headers
+        .iter()
+        .map(|(name, _)| name.as_str())
+        .collect()
+}
+
+#[test]
+fn keeps_everything_for_same_origin_307_and_308() {
+    for status in [307, 308] {
+        let next = redirect_request(&post(), status, "/v2/items").unwrap();
+        assert_eq!(next.method, Method::Post);
+        assert_eq!(next.url, "https://api.example.com/v2/items");
+        assert_eq!(next.body.as_deref(), Some(&b"{}"[..]));
+        assert_eq!(next.headers, post().headers);
+    }
+}
+
+#[test]
+fn drops_credentials_when_leaving_the_origin() {
+    let next = redirect_request(&post(), 307, "https://other.example.net/items").unwrap();
+    assert_eq!(next.method, Method::Post);
+    assert_eq!(
+        header_names(&next),
+        ["Content-Type", "content-length", "Accept"]
+    );
+
+    // A different scheme or port is a different origin too
+    for location in [
+        "http://api.example.com/v1/items",
+        "https://api.example.com:8443/",
+    ] {
+        let next = redirect_request(&post(), 308, location).unwrap();
+        assert!(
+            !header_names(&next).contains(&"Authorization"),
+            "{location}"
+        );
+    }
+}
+
+#[test]
+fn downgrades_to_a_bodyless_get() {
+    let mut requests = vec![(post(), 303), (post(), 301), (post(), 302)];
+    let mut put = post();
+    put.method = Method::Put;
+    requests.push((put, 303));
+
+    for (request, status) in requests {
+        let next = redirect_request(&request, status, "/done").unwrap();
+        assert_eq!(next.method, Method::Get, "{status}");
+        assert_eq!(next.body, None);
+        assert_eq!(
+            header_names(&next),
+            ["Authorization", "cookie", "Proxy-Authorization", "Accept"]
+        );
+    }
+}
+
+#[test]
+fn get_and_head_stay_unchanged_on_301_and_302() {
+    let mut get = post();
+    get.method = Method::Get;
+    get.body = None;
+    let next = redirect_request(&get, 302, "page2").unwrap();
+    assert_eq!(next.method, Method::Get);
+    assert_eq!(next.url, "https://api.example.com/v1/page2");
+    assert_eq!(next.headers, get.headers);
+
+    let mut head = get.clone();
+    head.method = Method::Head;
+    let next = redirect_request(&head, 303, "/other").unwrap();
+    assert_eq!(next.method, Method::Head);
+}
+
+#[test]
+fn cross_origin_downgrade_drops_both_kinds_of_header() {
+    let next = redirect_request(&post(), 303, "https://login.example.org/").unwrap();
+    assert_eq!(header_names(&next), ["Accept"]);
+}
+
+#[test]
+fn reports_invalid_locations() {
+    let err = redirect_request(&post(), 302, "http://[::1").unwrap_err();
+    assert!(
+        err.to_string().starts_with("invalid redirect location"),
+        "{err}"
+    );
+}
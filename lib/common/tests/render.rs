@@ -0,0 +1,79 @@
+//! Tests for rendering markdown to sanitised HTML and to plain text.
+
+use common::{markdown_to_html, markdown_to_text};
+
+#[test]
+fn escapes_raw_html() {
+    let html =
+        markdown_to_html("<script>alert(1)</script>\n\nText <img src=x onerror=alert(1)> here");
+    assert!(!html.contains("<script"), "{html}");
+    assert!(!html.contains("<img"), "{html}");
+    assert!(
+        html.contains("&lt;script&gt;alert(1)&lt;/script&gt;"),
+        "{html}"
+    );
+}
+
+#[test]
+fn neutralises_javascript_links() {
+    for markdown in [
+        "[click](javascript:alert(1))",
+        "[click](JaVaScRiPt:alert(1))",
+        "[click](&#106;avascript:alert(1))",
+        "[click](java&#9;script:alert(1))",
+        "[click](&#x6A;&#x61;vascript:alert(1))",
+        "[click](data:text/html;base64,PHNjcmlwdD4=)",
+        "[click](vbscript:msgbox)",
+    ] {
+        let html = markdown_to_html(markdown);
+        assert_eq!(html, "<p><a href=\"#\">click</a></p>\n", "{markdown}");
+    }
+}
+
+#[test]
+fn neutralises_unsafe_image_sources() {
+    let html = markdown_to_html("![x](javascript:alert(1)) ![y](DATA:image/svg+xml,<svg>)");
+    assert_eq!(
+        html,
+        "<p><img src=\"#\" alt=\"x\" /> <img src=\"#\" alt=\"y\" /></p>\n"
+    );
+}
+
+#[test]
+fn keeps_safe_and_relative_urls() {
+    for url in [
+        "https://example.com/a?b=c",
+        "mailto:someone@example.com",
+        "/docs/guide.html",
+        "../up/one",
+        "page.html#section",
+        "#top",
+        "?query=javascript:x",
+        "path/javascript:x",
+    ] {
+        let html = markdown_to_html(&format!("[link]({url})"));
+        let escaped = url.replace('&', "&amp;");
+        assert!(
+            html.contains(&format!("href=\"{escaped}\"")),
+            "{url}: {html}"
+        );
+    }
+}
+
+#[test]
+fn renders_gfm() {
+    let html = markdown_to_html("| a | b |\n| - | - |\n| 1 | 2 |\n\n~~old~~ - [x] done");
+    assert!(html.contains("<table>"), "{html}");
+    assert!(html.contains("<del>old</del>"), "{html}");
+}
+
+#[test]
+fn renders_plain_text() {
+    let text = markdown_to_text(
+        "# Title\n\nSome *emphasis* and `code`.\n\n- one\n- two\n\n| a | b |\n| - | - |\n| 1 | 2 |\n\n![alt text](img.png) <b>raw</b>",
+    );
+    assert_eq!(
+        text,
+        "Title\n\nSome emphasis and code.\n\none\ntwo\n\na\tb\n1\t2\n\nalt text raw"
+    );
+}
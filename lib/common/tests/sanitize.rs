@@ -0,0 +1,65 @@
+//! Golden-file tests for `sanitize_html`.
+//!
+//! Each `fixtures/sanitize/<name>.html` input is sanitised and compared with
+//! `<name>.expected.html`.
+
+mod golden;
+
+use common::{SanitizeOptions, sanitize_html};
+
+fn assert_golden(name: &str, options: &SanitizeOptions) {
+    let input = golden::read_fixture(&format!("sanitize/{name}.html"));
+    golden::assert_golden(
+        &format!("sanitize/{name}.expected.html"),
+        &sanitize_html(&input, options),
+    );
+}
+
+#[test]
+fn keeps_formatting() {
+    assert_golden("formatting", &SanitizeOptions::default());
+}
+
+#[test]
+fn removes_scripts_and_embeds() {
+    assert_golden("scripts", &SanitizeOptions::default());
+}
+
+#[test]
+fn removes_event_handlers() {
+    assert_golden("event_handlers", &SanitizeOptions::default());
+}
+
+#[test]
+fn removes_unsafe_urls() {
+    assert_golden("unsafe_urls", &SanitizeOptions::default());
+}
+
+#[test]
+fn unwraps_unknown_tags() {
+    assert_golden("unknown_tags", &SanitizeOptions::default());
+}
+
+#[test]
+fn keeps_only_body_of_full_document() {
+    assert_golden("full_document", &SanitizeOptions::default());
+}
+
+#[test]
+fn removes_boilerplate() {
+    let options = SanitizeOptions {
+        remove_boilerplate: true,
+        ..Default::default()
+    };
+    assert_golden("boilerplate", &options);
+}
+
+#[test]
+fn applies_custom_allow_list() {
+    let options = SanitizeOptions {
+        allowed_tags: vec!["p".to_string(), "A".to_string()],
+        allowed_attributes: vec!["href".to_string()],
+        remove_boilerplate: false,
+    };
+    assert_golden("custom_allow_list", &options);
+}
@@ -0,0 +1,114 @@
+//! Tests for selector-based record extraction and table conversion.
+
+use common::{FieldSpec, html_tables, scrape, tables_to_csv, tables_to_json};
+use serde_json::json;
+
+const PRODUCTS: &str = r#"<html><body>
+<ul>
+  <li class="product">
+    <a href="/p/1"><h2>Kettle</h2></a>
+    <span class="price"> $25 </span>
+    <span class="tag">kitchen</span><span class="tag">sale</span>
+  </li>
+  <li class="product">
+    <a href="https://shop.example/p/2"><h2>Toaster</h2></a>
+    <span class="tag">kitchen</span>
+  </li>
+</ul>
+</body></html>"#;
+
+fn field(name: &str, selector: &str, attribute: Option<&str>, list: bool) -> FieldSpec {
+    FieldSpec {
+        name: name.to_string(),
+        selector: selector.to_string(),
+        attribute: attribute.map(str::to_string),
+        list,
+    }
+}
+
+#[test]
+fn extracts_one_record_per_item() {
+    let fields = [
+        field("name", "h2", None, false),
+        field("url", "a", Some("href"), false),
+        field("price", ".price", None, false),
+        field("tags", ".tag", None, true),
+    ];
+    let records = scrape(
+        PRODUCTS,
+        Some("https://shop.example/list"),
+        Some("li.product"),
+        &fields,
+    )
+    .unwrap();
+    assert_eq!(
+        records,
+        [
+            json!({"name": "Kettle", "url": "https://shop.example/p/1", "price": "$25", "tags": ["kitchen", "sale"]}),
+            json!({"name": "Toaster", "url": "https://shop.example/p/2", "price": null, "tags": ["kitchen"]}),
+        ]
+    );
+}
+
+#[test]
+fn extracts_single_record_without_item_selector() {
+    let records = scrape(PRODUCTS, None, None, &[field("names", "h2", None, true)]).unwrap();
+    assert_eq!(records, [json!({"names": ["Kettle", "Toaster"]})]);
+}
+
+#[test]
+fn rejects_invalid_selectors() {
+    let error = scrape(PRODUCTS, None, None, &[field("x", "h2[[", None, false)]).unwrap_err();
+    assert!(
+        error.to_string().starts_with("invalid CSS selector"),
+        "{error}"
+    );
+    assert!(scrape(PRODUCTS, None, Some(">>"), &[field("x", "h2", None, false)]).is_err());
+}
+
+const TABLES: &str = r#"<html><body>
+<table>
+  <caption>Releases</caption>
+  <thead><tr><th>Version</th><th>Date</th><th>Notes</th></tr></thead>
+  <tbody>
+    <tr><td>1.1</td><td>2024-05-01</td><td>Fixes, "quoted"</td></tr>
+    <tr><td>1.0</td><td colspan="2">unreleased</td></tr>
+    <tr><td>0.9</td></tr>
+  </tbody>
+</table>
+<table>
+  <tr><td>a</td><td><table><tr><td>nested</td></tr></table></td></tr>
+</table>
+</body></html>"#;
+
+#[test]
+fn parses_tables() {
+    let tables = html_tables(TABLES);
+    assert_eq!(tables.len(), 3);
+    assert_eq!(tables[0].caption.as_deref(), Some("Releases"));
+    assert_eq!(tables[0].columns, ["Version", "Date", "Notes"]);
+    assert_eq!(
+        tables[0].rows,
+        [
+            ["1.1", "2024-05-01", "Fixes, \"quoted\""],
+            ["1.0", "unreleased", "unreleased"],
+            ["0.9", "", ""],
+        ]
+    );
+    assert_eq!(tables[1].columns, ["Column 1", "Column 2"]);
+    assert_eq!(tables[1].rows, [["a", "nested"]]);
+    assert_eq!(tables[2].rows, [["nested"]]);
+}
+
+#[test]
+fn renders_tables_as_json_and_csv() {
+    let tables = html_tables(TABLES);
+    assert_eq!(
+        tables_to_json(&tables[..1])[0]["rows"][0],
+        json!({"Version": "1.1", "Date": "2024-05-01", "Notes": "Fixes, \"quoted\""})
+    );
+    assert_eq!(
+        tables_to_csv(&tables[..2]).unwrap(),
+        "Version,Date,Notes\n1.1,2024-05-01,\"Fixes, \"\"quoted\"\"\"\n1.0,unreleased,unreleased\n0.9,,\n\nColumn 1,Column 2\na,nested\n"
+    );
+}
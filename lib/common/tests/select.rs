@@ -0,0 +1,54 @@
+//! Tests for narrowing HTML down with CSS selectors before conversion.
+
+use common::{html_to_markdown, select_html};
+
+const PAGE: &str = r#"<html><body>
+<nav>Menu</nav>
+<main id="main">
+  <article class="content">
+    <h1>Title</h1>
+    <p>Body text <span class="ad">Buy now</span></p>
+    <article class="content"><p>Nested article</p></article>
+  </article>
+</main>
+<footer>Footer</footer>
+</body></html>"#;
+
+fn markdown(include: Option<&str>, exclude: &[&str]) -> anyhow::Result<String> {
+    let exclude: Vec<String> = exclude.iter().map(|s| s.to_string()).collect();
+    select_html(PAGE, include, &exclude).map(|html| html_to_markdown(&html))
+}
+
+#[test]
+fn keeps_only_included_subtrees() {
+    assert_eq!(
+        markdown(Some("article.content"), &[]).unwrap(),
+        "# Title\n\nBody text  Buy now\n\nNested article"
+    );
+}
+
+#[test]
+fn removes_excluded_elements() {
+    assert_eq!(
+        markdown(None, &["nav", "footer", ".ad"]).unwrap(),
+        "# Title\n\nBody text\n\nNested article"
+    );
+    assert_eq!(
+        markdown(Some("#main"), &[".ad"]).unwrap(),
+        "# Title\n\nBody text\n\nNested article"
+    );
+}
+
+#[test]
+fn reports_invalid_or_unmatched_selectors() {
+    let error = markdown(Some("#missing"), &[]).unwrap_err();
+    assert_eq!(error.to_string(), "selector \"#missing\" matched nothing");
+
+    let error = markdown(None, &["div[["]).unwrap_err();
+    assert!(
+        error
+            .to_string()
+            .starts_with("invalid CSS selector \"div[[\""),
+        "{error}"
+    );
+}
@@ -0,0 +1,234 @@
+//! Tests for sitemap parsing and `sitemap_entries` against in-memory sitemaps.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::io::Write;
+
+use common::{
+    FetchedSitemap, Sitemap, SitemapEntry, SitemapFilter, parse_sitemap, sitemap_entries,
+};
+use flate2::{Compression, write::GzEncoder};
+use futures::executor::block_on;
+
+const INDEX: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<sitemapindex xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+  <sitemap><loc>https://example.com/sitemap-docs.xml</loc></sitemap>
+  <sitemap><loc>https://example.com/sitemap-blog.xml.gz</loc></sitemap>
+  <sitemap><loc>https://example.com/sitemap-missing.xml</loc></sitemap>
+  <sitemap><loc>https://example.com/sitemap.xml</loc></sitemap>
+</sitemapindex>"#;
+
+const DOCS: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+  <url><loc>https://example.com/docs/</loc><lastmod>2024-03-01</lastmod><priority>1.0</priority></url>
+  <url><loc>https://example.com/docs/guide</loc><lastmod>2023-06-15T10:00:00+00:00</lastmod></url>
+  <url><loc>https://example.com/docs/api</loc></url>
+</urlset>"#;
+
+const BLOG: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+  <url><loc>https://example.com/blog/hello</loc><lastmod>2024-05-20T08:30Z</lastmod><priority>0.5</priority></url>
+</urlset>"#;
+
+fn gzip(text: &str) -> Vec<u8> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(text.as_bytes()).unwrap();
+    encoder.finish().unwrap()
+}
+
+fn run(url: &str, filter: SitemapFilter) -> anyhow::Result<Vec<SitemapEntry>> {
+    let site: HashMap<&str, Vec<u8>> = HashMap::from([
+        ("https://example.com/sitemap.xml", INDEX.as_bytes().to_vec()),
+        (
+            "https://example.com/sitemap-docs.xml",
+            DOCS.as_bytes().to_vec(),
+        ),
+        ("https://example.com/sitemap-blog.xml.gz", gzip(BLOG)),
+    ]);
+    block_on(sitemap_entries(url, &filter, |url| {
+        let body = site.get(url.as_str()).cloned();
+        async move {
+            let body = body.ok_or_else(|| format!("404 for {url}"))?;
+            Ok(FetchedSitemap { url, body })
+        }
+    }))
+}
+
+fn locs(entries: &[SitemapEntry]) -> Vec<&str> {
+    entries.iter().map(|entry| entry.loc.as_str()).collect()
+}
+
+#[test]
+fn parses_urlset() {
+    let Sitemap::UrlSet(entries) = parse_sitemap(DOCS.as_bytes()).unwrap() else {
+        panic!("expected a urlset");
+    };
+    assert_eq!(
+        entries[0],
+        SitemapEntry {
+            loc: "https://example.com/docs/".to_string(),
+            lastmod: Some("2024-03-01".to_string()),
+            priority: Some(1.0),
+        }
+    );
+    assert_eq!(entries[2].lastmod, None);
+}
+
+#[test]
+fn parses_gzipped_index() {
+    let sitemap = parse_sitemap(&gzip(INDEX)).unwrap();
+    assert!(matches!(sitemap, Sitemap::Index(sitemaps) if sitemaps.len() == 4));
+}
+
+#[test]
+fn rejects_other_xml() {
+    let error = parse_sitemap(b"<rss><channel/></rss>").unwrap_err();
+    assert_eq!(error.to_string(), "not a sitemap: root element is <rss>");
+}
+
+#[test]
+fn follows_index_from_site_root() {
+    let entries = run("https://example.com/", SitemapFilter::default()).unwrap();
+    assert_eq!(
+        locs(&entries),
+        [
+            "https://example.com/docs/",
+            "https://example.com/docs/guide",
+            "https://example.com/docs/api",
+            "https://example.com/blog/hello",
+        ]
+    );
+}
+
+#[test]
+fn filters_by_path_prefix() {
+    let filter = SitemapFilter {
+        path_prefix: Some("/blog/".to_string()),
+        ..SitemapFilter::default()
+    };
+    let entries = run("https://example.com/sitemap.xml", filter).unwrap();
+    assert_eq!(locs(&entries), ["https://example.com/blog/hello"]);
+}
+
+#[test]
+fn filters_by_lastmod() {
+    let filter = SitemapFilter {
+        modified_since: Some("2024-01-01".to_string()),
+        ..SitemapFilter::default()
+    };
+    let entries = run("https://example.com/", filter).unwrap();
+    assert_eq!(
+        locs(&entries),
+        [
+            "https://example.com/docs/",
+            "https://example.com/blog/hello"
+        ]
+    );
+
+    let filter = SitemapFilter {
+        modified_since: Some("last week".to_string()),
+        ..SitemapFilter::default()
+    };
+    assert!(run("https://example.com/", filter).is_err());
+}
+
+#[test]
+fn fails_when_top_level_sitemap_fails() {
+    let error = run(
+        "https://example.com/sitemap-missing.xml",
+        SitemapFilter::default(),
+    )
+    .unwrap_err();
+    assert!(error.to_string().starts_with("failed to fetch"), "{error}");
+}
+
+#[test]
+fn rejects_oversized_gzipped_sitemap() {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::best());
+    let block = vec![b' '; 1024 * 1024];
+    for _ in 0..50 {
+        encoder.write_all(&block).unwrap();
+    }
+    encoder.write_all(b"<urlset/>").unwrap();
+    let error = parse_sitemap(&encoder.finish().unwrap()).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "gzipped sitemap is larger than 50 MiB uncompressed"
+    );
+}
+
+#[test]
+fn fetches_nested_sitemaps_only_from_the_same_origin() {
+    const INDEX: &str = r#"<sitemapindex>
+  <sitemap><loc>https://example.com/sitemap-docs.xml</loc></sitemap>
+  <sitemap><loc>https://other.example/sitemap.xml</loc></sitemap>
+  <sitemap><loc>http://example.com/sitemap-docs.xml</loc></sitemap>
+</sitemapindex>"#;
+    let requested = RefCell::new(Vec::new());
+    let entries = block_on(sitemap_entries(
+        "https://example.com/sitemap.xml",
+        &SitemapFilter::default(),
+        |url| {
+            requested.borrow_mut().push(url.clone());
+            let body = match url.as_str() {
+                "https://example.com/sitemap.xml" => Ok(INDEX.as_bytes().to_vec()),
+                "https://example.com/sitemap-docs.xml" => Ok(DOCS.as_bytes().to_vec()),
+                _ => Err(format!("unexpected request for {url}")),
+            };
+            async move { Ok(FetchedSitemap { url, body: body? }) }
+        },
+    ))
+    .unwrap();
+    assert_eq!(entries.len(), 3);
+    assert_eq!(
+        requested.into_inner(),
+        [
+            "https://example.com/sitemap.xml",
+            "https://example.com/sitemap-docs.xml"
+        ]
+    );
+}
+
+#[test]
+fn follows_indexes_on_the_origin_the_sitemap_redirected_to() {
+    const INDEX: &str = r#"<sitemapindex>
+  <sitemap><loc>https://www.example.com/sitemap-docs.xml</loc></sitemap>
+  <sitemap><loc>https://www.example.com/sitemap-moved.xml</loc></sitemap>
+</sitemapindex>"#;
+    let redirects = HashMap::from([
+        (
+            "http://example.com/sitemap.xml",
+            "https://www.example.com/sitemap.xml",
+        ),
+        (
+            "https://www.example.com/sitemap-moved.xml",
+            "https://other.example/sitemap.xml",
+        ),
+    ]);
+    let site = HashMap::from([
+        ("https://www.example.com/sitemap.xml", INDEX),
+        ("https://www.example.com/sitemap-docs.xml", DOCS),
+        ("https://other.example/sitemap.xml", BLOG),
+    ]);
+    let entries = block_on(sitemap_entries(
+        "http://example.com/",
+        &SitemapFilter::default(),
+        |url| {
+            let url = redirects.get(url.as_str()).map_or(url, |to| to.to_string());
+            let body = site.get(url.as_str()).map(|body| body.as_bytes().to_vec());
+            async move {
+                let body = body.ok_or_else(|| format!("404 for {url}"))?;
+                Ok(FetchedSitemap { url, body })
+            }
+        },
+    ))
+    .unwrap();
+    assert_eq!(
+        locs(&entries),
+        [
+            "https://example.com/docs/",
+            "https://example.com/docs/guide",
+            "https://example.com/docs/api",
+        ]
+    );
+}
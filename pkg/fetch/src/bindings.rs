@@ -15,6 +15,158 @@ impl ::core::fmt::Debug for Header {
             .finish()
     }
 }
+/// HTTP request method
+#[repr(u8)]
+#[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+pub enum Method {
+    Get,
+    Head,
+    Post,
+    Put,
+    Patch,
+    Delete,
+    Options,
+}
+impl ::core::fmt::Debug for Method {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        match self {
+            Method::Get => f.debug_tuple("Method::Get").finish(),
+            Method::Head => f.debug_tuple("Method::Head").finish(),
+            Method::Post => f.debug_tuple("Method::Post").finish(),
+            Method::Put => f.debug_tuple("Method::Put").finish(),
+            Method::Patch => f.debug_tuple("Method::Patch").finish(),
+            Method::Delete => f.debug_tuple("Method::Delete").finish(),
+            Method::Options => f.debug_tuple("Method::Options").finish(),
+        }
+    }
+}
+impl Method {
+    #[doc(hidden)]
+    pub unsafe fn _lift(val: u8) -> Method {
+        if !cfg!(debug_assertions) {
+            return ::core::mem::transmute(val);
+        }
+        match val {
+            0 => Method::Get,
+            1 => Method::Head,
+            2 => Method::Post,
+            3 => Method::Put,
+            4 => Method::Patch,
+            5 => Method::Delete,
+            6 => Method::Options,
+            _ => panic!("invalid enum discriminant"),
+        }
+    }
+}
+/// A request body, given either as text or as raw bytes
+#[derive(Clone)]
+pub enum RequestBody {
+    Text(_rt::String),
+    Bytes(_rt::Vec<u8>),
+}
+impl ::core::fmt::Debug for RequestBody {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        match self {
+            RequestBody::Text(e) => f.debug_tuple("RequestBody::Text").field(e).finish(),
+            RequestBody::Bytes(e) => f.debug_tuple("RequestBody::Bytes").field(e).finish(),
+        }
+    }
+}
+/// A full HTTP request
+#[derive(Clone)]
+pub struct HttpRequest {
+    /// The request method
+    pub method: Method,
+    /// The URL to send the request to
+    pub url: _rt::String,
+    /// Request headers, e.g. content-type for the body
+    pub headers: _rt::Vec<Header>,
+    /// Optional request body
+    pub body: Option<RequestBody>,
+    /// Timeout in milliseconds, applied separately to connecting, waiting for
+    /// the first response byte and waiting between body chunks
+    pub timeout_ms: Option<u32>,
+}
+impl ::core::fmt::Debug for HttpRequest {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct("HttpRequest")
+            .field("method", &self.method)
+            .field("url", &self.url)
+            .field("headers", &self.headers)
+            .field("body", &self.body)
+            .field("timeout-ms", &self.timeout_ms)
+            .finish()
+    }
+}
+/// A response body, as text when it is valid UTF-8 and not binary, and as raw
+/// bytes otherwise, so no data is lost
+#[derive(Clone)]
+pub enum ResponseBody {
+    Text(_rt::String),
+    Bytes(_rt::Vec<u8>),
+}
+impl ::core::fmt::Debug for ResponseBody {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        match self {
+            ResponseBody::Text(e) => f.debug_tuple("ResponseBody::Text").field(e).finish(),
+            ResponseBody::Bytes(e) => f.debug_tuple("ResponseBody::Bytes").field(e).finish(),
+        }
+    }
+}
+/// The response to an HTTP request
+#[derive(Clone)]
+pub struct HttpResponse {
+    /// HTTP status code
+    pub status: u16,
+    /// Response headers
+    pub headers: _rt::Vec<Header>,
+    /// The response body
+    pub body: ResponseBody,
+    /// The URL that produced this response, after following redirects
+    pub final_url: _rt::String,
+}
+impl ::core::fmt::Debug for HttpResponse {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct("HttpResponse")
+            .field("status", &self.status)
+            .field("headers", &self.headers)
+            .field("body", &self.body)
+            .field("final-url", &self.final_url)
+            .finish()
+    }
+}
+/// A response body as raw bytes
+#[derive(Clone)]
+pub struct BinaryResponse {
+    /// The response content type, if the server sent one
+    pub content_type: Option<_rt::String>,
+    /// The raw response body
+    pub body: _rt::Vec<u8>,
+}
+impl ::core::fmt::Debug for BinaryResponse {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct("BinaryResponse")
+            .field("content-type", &self.content_type)
+            .field("body", &self.body)
+            .finish()
+    }
+}
+/// A response body encoded as base64, for hosts that pass results as JSON
+#[derive(Clone)]
+pub struct Base64Response {
+    /// The response content type, if the server sent one
+    pub content_type: Option<_rt::String>,
+    /// The response body encoded as standard base64
+    pub body: _rt::String,
+}
+impl ::core::fmt::Debug for Base64Response {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct("Base64Response")
+            .field("content-type", &self.content_type)
+            .field("body", &self.body)
+            .finish()
+    }
+}
 #[doc(hidden)]
 #[allow(non_snake_case)]
 pub unsafe fn _export_fetch_cabi<T: Guest>(
@@ -22,6 +174,10 @@ pub unsafe fn _export_fetch_cabi<T: Guest>(
     arg1: usize,
     arg2: *mut u8,
     arg3: usize,
+    arg4: i32,
+    arg5: i32,
+    arg6: i32,
+    arg7: i32,
 ) -> *mut u8 {
     #[cfg(target_arch = "wasm32")]
     _rt::run_ctors_once();
@@ -59,7 +215,26 @@ pub unsafe fn _export_fetch_cabi<T: Guest>(
         len7 * (4 * ::core::mem::size_of::<*const u8>()),
         ::core::mem::size_of::<*const u8>(),
     );
-    let result8 = T::fetch(_rt::string_lift(bytes0), result7);
+    let result8 = T::fetch(
+        _rt::string_lift(bytes0),
+        result7,
+        match arg4 {
+            0 => None,
+            1 => {
+                let e = arg5 as u32;
+                Some(e)
+            }
+            _ => _rt::invalid_enum_discriminant(),
+        },
+        match arg6 {
+            0 => None,
+            1 => {
+                let e = arg7 as u32;
+                Some(e)
+            }
+            _ => _rt::invalid_enum_discriminant(),
+        },
+    );
     let ptr9 = (&raw mut _RET_AREA.0).cast::<u8>();
     match result8 {
         Ok(e) => {
@@ -116,37 +291,972 @@ pub unsafe fn __post_return_fetch<T: Guest>(arg0: *mut u8) {
         }
     }
 }
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_fetch_many_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+    arg2: *mut u8,
+    arg3: usize,
+    arg4: i32,
+    arg5: i32,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let base3 = arg0;
+    let len3 = arg1;
+    let mut result3 = _rt::Vec::with_capacity(len3);
+    for i in 0..len3 {
+        let base = base3.add(i * (2 * ::core::mem::size_of::<*const u8>()));
+        let e3 = {
+            let l0 = *base.add(0).cast::<*mut u8>();
+            let l1 = *base
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            let len2 = l1;
+            let bytes2 = _rt::Vec::from_raw_parts(l0.cast(), len2, len2);
+            _rt::string_lift(bytes2)
+        };
+        result3.push(e3);
+    }
+    _rt::cabi_dealloc(
+        base3,
+        len3 * (2 * ::core::mem::size_of::<*const u8>()),
+        ::core::mem::size_of::<*const u8>(),
+    );
+    let base10 = arg2;
+    let len10 = arg3;
+    let mut result10 = _rt::Vec::with_capacity(len10);
+    for i in 0..len10 {
+        let base = base10.add(i * (4 * ::core::mem::size_of::<*const u8>()));
+        let e10 = {
+            let l4 = *base.add(0).cast::<*mut u8>();
+            let l5 = *base
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            let len6 = l5;
+            let bytes6 = _rt::Vec::from_raw_parts(l4.cast(), len6, len6);
+            let l7 = *base
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>();
+            let l8 = *base
+                .add(3 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            let len9 = l8;
+            let bytes9 = _rt::Vec::from_raw_parts(l7.cast(), len9, len9);
+            Header {
+                name: _rt::string_lift(bytes6),
+                value: _rt::string_lift(bytes9),
+            }
+        };
+        result10.push(e10);
+    }
+    _rt::cabi_dealloc(
+        base10,
+        len10 * (4 * ::core::mem::size_of::<*const u8>()),
+        ::core::mem::size_of::<*const u8>(),
+    );
+    let result11 = T::fetch_many(
+        result3,
+        result10,
+        match arg4 {
+            0 => None,
+            1 => {
+                let e = arg5 as u32;
+                Some(e)
+            }
+            _ => _rt::invalid_enum_discriminant(),
+        },
+    );
+    let ptr12 = (&raw mut _RET_AREA.0).cast::<u8>();
+    let vec15 = result11;
+    let len15 = vec15.len();
+    let layout15 = _rt::alloc::Layout::from_size_align_unchecked(
+        vec15.len() * (3 * ::core::mem::size_of::<*const u8>()),
+        ::core::mem::size_of::<*const u8>(),
+    );
+    let result15 = if layout15.size() != 0 {
+        let ptr = _rt::alloc::alloc(layout15).cast::<u8>();
+        if ptr.is_null() {
+            _rt::alloc::handle_alloc_error(layout15);
+        }
+        ptr
+    } else {
+        ::core::ptr::null_mut()
+    };
+    for (i, e) in vec15.into_iter().enumerate() {
+        let base = result15.add(i * (3 * ::core::mem::size_of::<*const u8>()));
+        {
+            match e {
+                Ok(e) => {
+                    *base.add(0).cast::<u8>() = (0i32) as u8;
+                    let vec13 = (e.into_bytes()).into_boxed_slice();
+                    let ptr13 = vec13.as_ptr().cast::<u8>();
+                    let len13 = vec13.len();
+                    ::core::mem::forget(vec13);
+                    *base
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len13;
+                    *base
+                        .add(::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr13.cast_mut();
+                }
+                Err(e) => {
+                    *base.add(0).cast::<u8>() = (1i32) as u8;
+                    let vec14 = (e.into_bytes()).into_boxed_slice();
+                    let ptr14 = vec14.as_ptr().cast::<u8>();
+                    let len14 = vec14.len();
+                    ::core::mem::forget(vec14);
+                    *base
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len14;
+                    *base
+                        .add(::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr14.cast_mut();
+                }
+            };
+        }
+    }
+    *ptr12
+        .add(::core::mem::size_of::<*const u8>())
+        .cast::<usize>() = len15;
+    *ptr12.add(0).cast::<*mut u8>() = result15;
+    ptr12
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_fetch_many<T: Guest>(arg0: *mut u8) {
+    let l0 = *arg0.add(0).cast::<*mut u8>();
+    let l1 = *arg0
+        .add(::core::mem::size_of::<*const u8>())
+        .cast::<usize>();
+    let base7 = l0;
+    let len7 = l1;
+    for i in 0..len7 {
+        let base = base7.add(i * (3 * ::core::mem::size_of::<*const u8>()));
+        {
+            let l2 = i32::from(*base.add(0).cast::<u8>());
+            match l2 {
+                0 => {
+                    let l3 = *base
+                        .add(::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l4 = *base
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l3, l4, 1);
+                }
+                _ => {
+                    let l5 = *base
+                        .add(::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l6 = *base
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l5, l6, 1);
+                }
+            }
+        }
+    }
+    _rt::cabi_dealloc(
+        base7,
+        len7 * (3 * ::core::mem::size_of::<*const u8>()),
+        ::core::mem::size_of::<*const u8>(),
+    );
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_fetch_json_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+    arg2: *mut u8,
+    arg3: usize,
+    arg4: i32,
+    arg5: *mut u8,
+    arg6: usize,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let base7 = arg2;
+    let len7 = arg3;
+    let mut result7 = _rt::Vec::with_capacity(len7);
+    for i in 0..len7 {
+        let base = base7.add(i * (4 * ::core::mem::size_of::<*const u8>()));
+        let e7 = {
+            let l1 = *base.add(0).cast::<*mut u8>();
+            let l2 = *base
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            let len3 = l2;
+            let bytes3 = _rt::Vec::from_raw_parts(l1.cast(), len3, len3);
+            let l4 = *base
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>();
+            let l5 = *base
+                .add(3 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            let len6 = l5;
+            let bytes6 = _rt::Vec::from_raw_parts(l4.cast(), len6, len6);
+            Header {
+                name: _rt::string_lift(bytes3),
+                value: _rt::string_lift(bytes6),
+            }
+        };
+        result7.push(e7);
+    }
+    _rt::cabi_dealloc(
+        base7,
+        len7 * (4 * ::core::mem::size_of::<*const u8>()),
+        ::core::mem::size_of::<*const u8>(),
+    );
+    let result9 = T::fetch_json(
+        _rt::string_lift(bytes0),
+        result7,
+        match arg4 {
+            0 => None,
+            1 => {
+                let e = {
+                    let len8 = arg6;
+                    let bytes8 = _rt::Vec::from_raw_parts(arg5.cast(), len8, len8);
+                    _rt::string_lift(bytes8)
+                };
+                Some(e)
+            }
+            _ => _rt::invalid_enum_discriminant(),
+        },
+    );
+    let ptr10 = (&raw mut _RET_AREA.0).cast::<u8>();
+    match result9 {
+        Ok(e) => {
+            *ptr10.add(0).cast::<u8>() = (0i32) as u8;
+            let vec11 = (e.into_bytes()).into_boxed_slice();
+            let ptr11 = vec11.as_ptr().cast::<u8>();
+            let len11 = vec11.len();
+            ::core::mem::forget(vec11);
+            *ptr10
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>() = len11;
+            *ptr10
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>() = ptr11.cast_mut();
+        }
+        Err(e) => {
+            *ptr10.add(0).cast::<u8>() = (1i32) as u8;
+            let vec12 = (e.into_bytes()).into_boxed_slice();
+            let ptr12 = vec12.as_ptr().cast::<u8>();
+            let len12 = vec12.len();
+            ::core::mem::forget(vec12);
+            *ptr10
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>() = len12;
+            *ptr10
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>() = ptr12.cast_mut();
+        }
+    };
+    ptr10
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_fetch_json<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>();
+            let l2 = *arg0
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+        _ => {
+            let l3 = *arg0
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>();
+            let l4 = *arg0
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            _rt::cabi_dealloc(l3, l4, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_fetch_bytes_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+    arg2: *mut u8,
+    arg3: usize,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let base7 = arg2;
+    let len7 = arg3;
+    let mut result7 = _rt::Vec::with_capacity(len7);
+    for i in 0..len7 {
+        let base = base7.add(i * (4 * ::core::mem::size_of::<*const u8>()));
+        let e7 = {
+            let l1 = *base.add(0).cast::<*mut u8>();
+            let l2 = *base
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            let len3 = l2;
+            let bytes3 = _rt::Vec::from_raw_parts(l1.cast(), len3, len3);
+            let l4 = *base
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>();
+            let l5 = *base
+                .add(3 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            let len6 = l5;
+            let bytes6 = _rt::Vec::from_raw_parts(l4.cast(), len6, len6);
+            Header {
+                name: _rt::string_lift(bytes3),
+                value: _rt::string_lift(bytes6),
+            }
+        };
+        result7.push(e7);
+    }
+    _rt::cabi_dealloc(
+        base7,
+        len7 * (4 * ::core::mem::size_of::<*const u8>()),
+        ::core::mem::size_of::<*const u8>(),
+    );
+    let result8 = T::fetch_bytes(_rt::string_lift(bytes0), result7);
+    let ptr9 = (&raw mut _RET_AREA.0).cast::<u8>();
+    match result8 {
+        Ok(e) => {
+            *ptr9.add(0).cast::<u8>() = (0i32) as u8;
+            let BinaryResponse {
+                content_type: content_type10,
+                body: body10,
+            } = e;
+            match content_type10 {
+                Some(e) => {
+                    *ptr9.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
+                    let vec11 = (e.into_bytes()).into_boxed_slice();
+                    let ptr11 = vec11.as_ptr().cast::<u8>();
+                    let len11 = vec11.len();
+                    ::core::mem::forget(vec11);
+                    *ptr9
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len11;
+                    *ptr9
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr11.cast_mut();
+                }
+                None => {
+                    *ptr9.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
+                }
+            };
+            let vec12 = (body10).into_boxed_slice();
+            let ptr12 = vec12.as_ptr().cast::<u8>();
+            let len12 = vec12.len();
+            ::core::mem::forget(vec12);
+            *ptr9
+                .add(5 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>() = len12;
+            *ptr9
+                .add(4 * ::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>() = ptr12.cast_mut();
+        }
+        Err(e) => {
+            *ptr9.add(0).cast::<u8>() = (1i32) as u8;
+            let vec13 = (e.into_bytes()).into_boxed_slice();
+            let ptr13 = vec13.as_ptr().cast::<u8>();
+            let len13 = vec13.len();
+            ::core::mem::forget(vec13);
+            *ptr9
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>() = len13;
+            *ptr9
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>() = ptr13.cast_mut();
+        }
+    };
+    ptr9
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_fetch_bytes<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = i32::from(*arg0.add(::core::mem::size_of::<*const u8>()).cast::<u8>());
+            match l1 {
+                0 => {}
+                _ => {
+                    let l2 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l3 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l2, l3, 1);
+                }
+            }
+            let l4 = *arg0
+                .add(4 * ::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>();
+            let l5 = *arg0
+                .add(5 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            let base6 = l4;
+            let len6 = l5;
+            _rt::cabi_dealloc(base6, len6 * 1, 1);
+        }
+        _ => {
+            let l7 = *arg0
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>();
+            let l8 = *arg0
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            _rt::cabi_dealloc(l7, l8, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_fetch_base64_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+    arg2: *mut u8,
+    arg3: usize,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let base7 = arg2;
+    let len7 = arg3;
+    let mut result7 = _rt::Vec::with_capacity(len7);
+    for i in 0..len7 {
+        let base = base7.add(i * (4 * ::core::mem::size_of::<*const u8>()));
+        let e7 = {
+            let l1 = *base.add(0).cast::<*mut u8>();
+            let l2 = *base
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            let len3 = l2;
+            let bytes3 = _rt::Vec::from_raw_parts(l1.cast(), len3, len3);
+            let l4 = *base
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>();
+            let l5 = *base
+                .add(3 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            let len6 = l5;
+            let bytes6 = _rt::Vec::from_raw_parts(l4.cast(), len6, len6);
+            Header {
+                name: _rt::string_lift(bytes3),
+                value: _rt::string_lift(bytes6),
+            }
+        };
+        result7.push(e7);
+    }
+    _rt::cabi_dealloc(
+        base7,
+        len7 * (4 * ::core::mem::size_of::<*const u8>()),
+        ::core::mem::size_of::<*const u8>(),
+    );
+    let result8 = T::fetch_base64(_rt::string_lift(bytes0), result7);
+    let ptr9 = (&raw mut _RET_AREA.0).cast::<u8>();
+    match result8 {
+        Ok(e) => {
+            *ptr9.add(0).cast::<u8>() = (0i32) as u8;
+            let Base64Response {
+                content_type: content_type10,
+                body: body10,
+            } = e;
+            match content_type10 {
+                Some(e) => {
+                    *ptr9.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32) as u8;
+                    let vec11 = (e.into_bytes()).into_boxed_slice();
+                    let ptr11 = vec11.as_ptr().cast::<u8>();
+                    let len11 = vec11.len();
+                    ::core::mem::forget(vec11);
+                    *ptr9
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len11;
+                    *ptr9
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr11.cast_mut();
+                }
+                None => {
+                    *ptr9.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32) as u8;
+                }
+            };
+            let vec12 = (body10.into_bytes()).into_boxed_slice();
+            let ptr12 = vec12.as_ptr().cast::<u8>();
+            let len12 = vec12.len();
+            ::core::mem::forget(vec12);
+            *ptr9
+                .add(5 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>() = len12;
+            *ptr9
+                .add(4 * ::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>() = ptr12.cast_mut();
+        }
+        Err(e) => {
+            *ptr9.add(0).cast::<u8>() = (1i32) as u8;
+            let vec13 = (e.into_bytes()).into_boxed_slice();
+            let ptr13 = vec13.as_ptr().cast::<u8>();
+            let len13 = vec13.len();
+            ::core::mem::forget(vec13);
+            *ptr9
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>() = len13;
+            *ptr9
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>() = ptr13.cast_mut();
+        }
+    };
+    ptr9
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_fetch_base64<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = i32::from(*arg0.add(::core::mem::size_of::<*const u8>()).cast::<u8>());
+            match l1 {
+                0 => {}
+                _ => {
+                    let l2 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l3 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l2, l3, 1);
+                }
+            }
+            let l4 = *arg0
+                .add(4 * ::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>();
+            let l5 = *arg0
+                .add(5 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            _rt::cabi_dealloc(l4, l5, 1);
+        }
+        _ => {
+            let l6 = *arg0
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>();
+            let l7 = *arg0
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            _rt::cabi_dealloc(l6, l7, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_request_cabi<T: Guest>(
+    arg0: i32,
+    arg1: *mut u8,
+    arg2: usize,
+    arg3: *mut u8,
+    arg4: usize,
+    arg5: i32,
+    arg6: i32,
+    arg7: *mut u8,
+    arg8: usize,
+    arg9: i32,
+    arg10: i32,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg2;
+    let bytes0 = _rt::Vec::from_raw_parts(arg1.cast(), len0, len0);
+    let base7 = arg3;
+    let len7 = arg4;
+    let mut result7 = _rt::Vec::with_capacity(len7);
+    for i in 0..len7 {
+        let base = base7.add(i * (4 * ::core::mem::size_of::<*const u8>()));
+        let e7 = {
+            let l1 = *base.add(0).cast::<*mut u8>();
+            let l2 = *base
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            let len3 = l2;
+            let bytes3 = _rt::Vec::from_raw_parts(l1.cast(), len3, len3);
+            let l4 = *base
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>();
+            let l5 = *base
+                .add(3 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            let len6 = l5;
+            let bytes6 = _rt::Vec::from_raw_parts(l4.cast(), len6, len6);
+            Header {
+                name: _rt::string_lift(bytes3),
+                value: _rt::string_lift(bytes6),
+            }
+        };
+        result7.push(e7);
+    }
+    _rt::cabi_dealloc(
+        base7,
+        len7 * (4 * ::core::mem::size_of::<*const u8>()),
+        ::core::mem::size_of::<*const u8>(),
+    );
+    let result11 = T::request(HttpRequest {
+        method: Method::_lift(arg0 as u8),
+        url: _rt::string_lift(bytes0),
+        headers: result7,
+        body: match arg5 {
+            0 => None,
+            1 => {
+                let e = {
+                    let v10 = match arg6 {
+                        0 => {
+                            let e10 = {
+                                let len8 = arg8;
+                                let bytes8 = _rt::Vec::from_raw_parts(arg7.cast(), len8, len8);
+                                _rt::string_lift(bytes8)
+                            };
+                            RequestBody::Text(e10)
+                        }
+                        n => {
+                            debug_assert_eq!(n, 1, "invalid enum discriminant");
+                            let e10 = {
+                                let len9 = arg8;
+                                _rt::Vec::from_raw_parts(arg7.cast(), len9, len9)
+                            };
+                            RequestBody::Bytes(e10)
+                        }
+                    };
+                    v10
+                };
+                Some(e)
+            }
+            _ => _rt::invalid_enum_discriminant(),
+        },
+        timeout_ms: match arg9 {
+            0 => None,
+            1 => {
+                let e = arg10 as u32;
+                Some(e)
+            }
+            _ => _rt::invalid_enum_discriminant(),
+        },
+    });
+    let ptr12 = (&raw mut _RET_AREA.0).cast::<u8>();
+    match result11 {
+        Ok(e) => {
+            *ptr12.add(0).cast::<u8>() = (0i32) as u8;
+            let HttpResponse {
+                status: status13,
+                headers: headers13,
+                body: body13,
+                final_url: final_url13,
+            } = e;
+            *ptr12.add(::core::mem::size_of::<*const u8>()).cast::<u16>() =
+                (_rt::as_i32(status13)) as u16;
+            let vec17 = headers13;
+            let len17 = vec17.len();
+            let layout17 = _rt::alloc::Layout::from_size_align_unchecked(
+                vec17.len() * (4 * ::core::mem::size_of::<*const u8>()),
+                ::core::mem::size_of::<*const u8>(),
+            );
+            let result17 = if layout17.size() != 0 {
+                let ptr = _rt::alloc::alloc(layout17).cast::<u8>();
+                if ptr.is_null() {
+                    _rt::alloc::handle_alloc_error(layout17);
+                }
+                ptr
+            } else {
+                ::core::ptr::null_mut()
+            };
+            for (i, e) in vec17.into_iter().enumerate() {
+                let base = result17.add(i * (4 * ::core::mem::size_of::<*const u8>()));
+                {
+                    let Header {
+                        name: name14,
+                        value: value14,
+                    } = e;
+                    let vec15 = (name14.into_bytes()).into_boxed_slice();
+                    let ptr15 = vec15.as_ptr().cast::<u8>();
+                    let len15 = vec15.len();
+                    ::core::mem::forget(vec15);
+                    *base
+                        .add(::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len15;
+                    *base.add(0).cast::<*mut u8>() = ptr15.cast_mut();
+                    let vec16 = (value14.into_bytes()).into_boxed_slice();
+                    let ptr16 = vec16.as_ptr().cast::<u8>();
+                    let len16 = vec16.len();
+                    ::core::mem::forget(vec16);
+                    *base
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len16;
+                    *base
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr16.cast_mut();
+                }
+            }
+            *ptr12
+                .add(3 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>() = len17;
+            *ptr12
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>() = result17;
+            match body13 {
+                ResponseBody::Text(e) => {
+                    *ptr12
+                        .add(4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<u8>() = (0i32) as u8;
+                    let vec18 = (e.into_bytes()).into_boxed_slice();
+                    let ptr18 = vec18.as_ptr().cast::<u8>();
+                    let len18 = vec18.len();
+                    ::core::mem::forget(vec18);
+                    *ptr12
+                        .add(6 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len18;
+                    *ptr12
+                        .add(5 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr18.cast_mut();
+                }
+                ResponseBody::Bytes(e) => {
+                    *ptr12
+                        .add(4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<u8>() = (1i32) as u8;
+                    let vec19 = (e).into_boxed_slice();
+                    let ptr19 = vec19.as_ptr().cast::<u8>();
+                    let len19 = vec19.len();
+                    ::core::mem::forget(vec19);
+                    *ptr12
+                        .add(6 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len19;
+                    *ptr12
+                        .add(5 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr19.cast_mut();
+                }
+            }
+            let vec20 = (final_url13.into_bytes()).into_boxed_slice();
+            let ptr20 = vec20.as_ptr().cast::<u8>();
+            let len20 = vec20.len();
+            ::core::mem::forget(vec20);
+            *ptr12
+                .add(8 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>() = len20;
+            *ptr12
+                .add(7 * ::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>() = ptr20.cast_mut();
+        }
+        Err(e) => {
+            *ptr12.add(0).cast::<u8>() = (1i32) as u8;
+            let vec21 = (e.into_bytes()).into_boxed_slice();
+            let ptr21 = vec21.as_ptr().cast::<u8>();
+            let len21 = vec21.len();
+            ::core::mem::forget(vec21);
+            *ptr12
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>() = len21;
+            *ptr12
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>() = ptr21.cast_mut();
+        }
+    };
+    ptr12
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_request<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>();
+            let l2 = *arg0
+                .add(3 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            let base7 = l1;
+            let len7 = l2;
+            for i in 0..len7 {
+                let base = base7.add(i * (4 * ::core::mem::size_of::<*const u8>()));
+                {
+                    let l3 = *base.add(0).cast::<*mut u8>();
+                    let l4 = *base
+                        .add(::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l3, l4, 1);
+                    let l5 = *base
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l6 = *base
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l5, l6, 1);
+                }
+            }
+            _rt::cabi_dealloc(
+                base7,
+                len7 * (4 * ::core::mem::size_of::<*const u8>()),
+                ::core::mem::size_of::<*const u8>(),
+            );
+            let l8 = i32::from(
+                *arg0
+                    .add(4 * ::core::mem::size_of::<*const u8>())
+                    .cast::<u8>(),
+            );
+            match l8 {
+                0 => {
+                    let l9 = *arg0
+                        .add(5 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l10 = *arg0
+                        .add(6 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l9, l10, 1);
+                }
+                _ => {
+                    let l11 = *arg0
+                        .add(5 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l12 = *arg0
+                        .add(6 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base13 = l11;
+                    let len13 = l12;
+                    _rt::cabi_dealloc(base13, len13 * 1, 1);
+                }
+            }
+            let l14 = *arg0
+                .add(7 * ::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>();
+            let l15 = *arg0
+                .add(8 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            _rt::cabi_dealloc(l14, l15, 1);
+        }
+        _ => {
+            let l16 = *arg0
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>();
+            let l17 = *arg0
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            _rt::cabi_dealloc(l16, l17, 1);
+        }
+    }
+}
 pub trait Guest {
-    /// Fetch data from a URL with optional headers and return the pure response body as a String
-    fn fetch(url: _rt::String, headers: _rt::Vec<Header>) -> Result<_rt::String, _rt::String>;
+    /// Fetch data from a URL with optional headers and return the pure response body as a String.
+    /// Binary responses such as images or archives are reported as an error;
+    /// use fetch-bytes or fetch-base64 for those.
+    ///
+    /// Large bodies can be read in pages by passing start-index and max-length, both in
+    /// characters. The page is then followed by a note with the total length and the
+    /// start-index to continue from.
+    fn fetch(
+        url: _rt::String,
+        headers: _rt::Vec<Header>,
+        start_index: Option<u32>,
+        max_length: Option<u32>,
+    ) -> Result<_rt::String, _rt::String>;
+    /// Fetch several URLs with the same headers, like calling fetch for each one.
+    ///
+    /// At most `concurrency` requests run at once, 4 if not given. Results are returned
+    /// in the order of `urls`, each with its own error, so one failing URL does not fail
+    /// the batch.
+    fn fetch_many(
+        urls: _rt::Vec<_rt::String>,
+        headers: _rt::Vec<Header>,
+        concurrency: Option<u32>,
+    ) -> _rt::Vec<Result<_rt::String, _rt::String>>;
+    /// Fetch a JSON document and return it pretty-printed.
+    ///
+    /// An optional JSONPath (RFC 9535) query such as `$.items[*].name` selects parts of the
+    /// document; the result is then a JSON array of every match. Invalid JSON and invalid
+    /// query expressions are reported as separate errors.
+    fn fetch_json(
+        url: _rt::String,
+        headers: _rt::Vec<Header>,
+        query: Option<_rt::String>,
+    ) -> Result<_rt::String, _rt::String>;
+    /// Fetch data from a URL with optional headers and return the raw response body
+    fn fetch_bytes(
+        url: _rt::String,
+        headers: _rt::Vec<Header>,
+    ) -> Result<BinaryResponse, _rt::String>;
+    /// Fetch data from a URL with optional headers and return the response body as base64
+    fn fetch_base64(
+        url: _rt::String,
+        headers: _rt::Vec<Header>,
+    ) -> Result<Base64Response, _rt::String>;
+    /// Send an HTTP request with any method, headers and body, following redirects.
+    /// The response is returned whatever its status code; only transport failures are errors.
+    fn request(request: HttpRequest) -> Result<HttpResponse, _rt::String>;
 }
 #[doc(hidden)]
 macro_rules! __export_world_fetch_cabi {
     ($ty:ident with_types_in $($path_to_types:tt)*) => {
         const _ : () = { #[unsafe (export_name = "fetch")] unsafe extern "C" fn
-        export_fetch(arg0 : * mut u8, arg1 : usize, arg2 : * mut u8, arg3 : usize,) -> *
-        mut u8 { unsafe { $($path_to_types)*:: _export_fetch_cabi::<$ty > (arg0, arg1,
-        arg2, arg3) } } #[unsafe (export_name = "cabi_post_fetch")] unsafe extern "C" fn
-        _post_return_fetch(arg0 : * mut u8,) { unsafe { $($path_to_types)*::
-        __post_return_fetch::<$ty > (arg0) } } };
+        export_fetch(arg0 : * mut u8, arg1 : usize, arg2 : * mut u8, arg3 : usize, arg4 :
+        i32, arg5 : i32, arg6 : i32, arg7 : i32,) -> * mut u8 { unsafe {
+        $($path_to_types)*:: _export_fetch_cabi::<$ty > (arg0, arg1, arg2, arg3, arg4,
+        arg5, arg6, arg7) } } #[unsafe (export_name = "cabi_post_fetch")] unsafe extern
+        "C" fn _post_return_fetch(arg0 : * mut u8,) { unsafe { $($path_to_types)*::
+        __post_return_fetch::<$ty > (arg0) } } #[unsafe (export_name = "fetch-many")]
+        unsafe extern "C" fn export_fetch_many(arg0 : * mut u8, arg1 : usize, arg2 : *
+        mut u8, arg3 : usize, arg4 : i32, arg5 : i32,) -> * mut u8 { unsafe {
+        $($path_to_types)*:: _export_fetch_many_cabi::<$ty > (arg0, arg1, arg2, arg3,
+        arg4, arg5) } } #[unsafe (export_name = "cabi_post_fetch-many")] unsafe extern
+        "C" fn _post_return_fetch_many(arg0 : * mut u8,) { unsafe { $($path_to_types)*::
+        __post_return_fetch_many::<$ty > (arg0) } } #[unsafe (export_name =
+        "fetch-json")] unsafe extern "C" fn export_fetch_json(arg0 : * mut u8, arg1 :
+        usize, arg2 : * mut u8, arg3 : usize, arg4 : i32, arg5 : * mut u8, arg6 : usize,)
+        -> * mut u8 { unsafe { $($path_to_types)*:: _export_fetch_json_cabi::<$ty >
+        (arg0, arg1, arg2, arg3, arg4, arg5, arg6) } } #[unsafe (export_name =
+        "cabi_post_fetch-json")] unsafe extern "C" fn _post_return_fetch_json(arg0 : *
+        mut u8,) { unsafe { $($path_to_types)*:: __post_return_fetch_json::<$ty > (arg0)
+        } } #[unsafe (export_name = "fetch-bytes")] unsafe extern "C" fn
+        export_fetch_bytes(arg0 : * mut u8, arg1 : usize, arg2 : * mut u8, arg3 : usize,)
+        -> * mut u8 { unsafe { $($path_to_types)*:: _export_fetch_bytes_cabi::<$ty >
+        (arg0, arg1, arg2, arg3) } } #[unsafe (export_name = "cabi_post_fetch-bytes")]
+        unsafe extern "C" fn _post_return_fetch_bytes(arg0 : * mut u8,) { unsafe {
+        $($path_to_types)*:: __post_return_fetch_bytes::<$ty > (arg0) } } #[unsafe
+        (export_name = "fetch-base64")] unsafe extern "C" fn export_fetch_base64(arg0 : *
+        mut u8, arg1 : usize, arg2 : * mut u8, arg3 : usize,) -> * mut u8 { unsafe {
+        $($path_to_types)*:: _export_fetch_base64_cabi::<$ty > (arg0, arg1, arg2, arg3) }
+        } #[unsafe (export_name = "cabi_post_fetch-base64")] unsafe extern "C" fn
+        _post_return_fetch_base64(arg0 : * mut u8,) { unsafe { $($path_to_types)*::
+        __post_return_fetch_base64::<$ty > (arg0) } } #[unsafe (export_name = "request")]
+        unsafe extern "C" fn export_request(arg0 : i32, arg1 : * mut u8, arg2 : usize,
+        arg3 : * mut u8, arg4 : usize, arg5 : i32, arg6 : i32, arg7 : * mut u8, arg8 :
+        usize, arg9 : i32, arg10 : i32,) -> * mut u8 { unsafe { $($path_to_types)*::
+        _export_request_cabi::<$ty > (arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7,
+        arg8, arg9, arg10) } } #[unsafe (export_name = "cabi_post_request")] unsafe
+        extern "C" fn _post_return_request(arg0 : * mut u8,) { unsafe {
+        $($path_to_types)*:: __post_return_request::<$ty > (arg0) } } };
     };
 }
 #[doc(hidden)]
 pub(crate) use __export_world_fetch_cabi;
 #[cfg_attr(target_pointer_width = "64", repr(align(8)))]
 #[cfg_attr(target_pointer_width = "32", repr(align(4)))]
-struct _RetArea([::core::mem::MaybeUninit<u8>; 3 * ::core::mem::size_of::<*const u8>()]);
+struct _RetArea([::core::mem::MaybeUninit<u8>; 9 * ::core::mem::size_of::<*const u8>()]);
 static mut _RET_AREA: _RetArea =
-    _RetArea([::core::mem::MaybeUninit::uninit(); 3 * ::core::mem::size_of::<*const u8>()]);
+    _RetArea([::core::mem::MaybeUninit::uninit(); 9 * ::core::mem::size_of::<*const u8>()]);
 #[rustfmt::skip]
 mod _rt {
     #![allow(dead_code, clippy::all)]
     pub use alloc_crate::string::String;
+    pub use alloc_crate::vec::Vec;
     #[cfg(target_arch = "wasm32")]
     pub fn run_ctors_once() {
         wit_bindgen_rt::run_ctors_once();
     }
-    pub use alloc_crate::vec::Vec;
     pub unsafe fn string_lift(bytes: Vec<u8>) -> String {
         if cfg!(debug_assertions) {
             String::from_utf8(bytes).unwrap()
@@ -161,8 +1271,74 @@ mod _rt {
         let layout = alloc::Layout::from_size_align_unchecked(size, align);
         alloc::dealloc(ptr, layout);
     }
-    extern crate alloc as alloc_crate;
+    pub unsafe fn invalid_enum_discriminant<T>() -> T {
+        if cfg!(debug_assertions) {
+            panic!("invalid enum discriminant")
+        } else {
+            unsafe { core::hint::unreachable_unchecked() }
+        }
+    }
     pub use alloc_crate::alloc;
+    pub fn as_i32<T: AsI32>(t: T) -> i32 {
+        t.as_i32()
+    }
+    pub trait AsI32 {
+        fn as_i32(self) -> i32;
+    }
+    impl<'a, T: Copy + AsI32> AsI32 for &'a T {
+        fn as_i32(self) -> i32 {
+            (*self).as_i32()
+        }
+    }
+    impl AsI32 for i32 {
+        #[inline]
+        fn as_i32(self) -> i32 {
+            self as i32
+        }
+    }
+    impl AsI32 for u32 {
+        #[inline]
+        fn as_i32(self) -> i32 {
+            self as i32
+        }
+    }
+    impl AsI32 for i16 {
+        #[inline]
+        fn as_i32(self) -> i32 {
+            self as i32
+        }
+    }
+    impl AsI32 for u16 {
+        #[inline]
+        fn as_i32(self) -> i32 {
+            self as i32
+        }
+    }
+    impl AsI32 for i8 {
+        #[inline]
+        fn as_i32(self) -> i32 {
+            self as i32
+        }
+    }
+    impl AsI32 for u8 {
+        #[inline]
+        fn as_i32(self) -> i32 {
+            self as i32
+        }
+    }
+    impl AsI32 for char {
+        #[inline]
+        fn as_i32(self) -> i32 {
+            self as i32
+        }
+    }
+    impl AsI32 for usize {
+        #[inline]
+        fn as_i32(self) -> i32 {
+            self as i32
+        }
+    }
+    extern crate alloc as alloc_crate;
 }
 /// Generates `#[unsafe(no_mangle)]` functions to export the specified type as
 /// the root implementation of all generated traits.
@@ -197,12 +1373,26 @@ pub(crate) use __export_fetch_impl as export;
 #[unsafe(link_section = "component-type:wit-bindgen:0.41.0:component:fetch:fetch:encoded world")]
 #[doc(hidden)]
 #[allow(clippy::octal_escapes)]
-pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 221] = *b"\
-\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07b\x01A\x02\x01A\x06\x01\
-r\x02\x04names\x05values\x03\0\x06header\x03\0\0\x01p\x01\x01j\x01s\x01s\x01@\x02\
-\x03urls\x07headers\x02\0\x03\x04\0\x05fetch\x01\x04\x04\0\x15component:fetch/fe\
-tch\x04\0\x0b\x0b\x01\0\x05fetch\x03\0\0\0G\x09producers\x01\x0cprocessed-by\x02\
-\x0dwit-component\x070.227.1\x10wit-bindgen-rust\x060.41.0";
+pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 805] = *b"\
+\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\xa9\x05\x01A\x02\x01\
+A'\x01r\x02\x04names\x05values\x03\0\x06header\x03\0\0\x01m\x07\x03get\x04head\x04\
+post\x03put\x05patch\x06delete\x07options\x03\0\x06method\x03\0\x02\x01p}\x01q\x02\
+\x04text\x01s\0\x05bytes\x01\x04\0\x03\0\x0crequest-body\x03\0\x05\x01p\x01\x01k\
+\x06\x01ky\x01r\x05\x06method\x03\x03urls\x07headers\x07\x04body\x08\x0atimeout-\
+ms\x09\x03\0\x0chttp-request\x03\0\x0a\x01q\x02\x04text\x01s\0\x05bytes\x01\x04\0\
+\x03\0\x0dresponse-body\x03\0\x0c\x01r\x04\x06status{\x07headers\x07\x04body\x0d\
+\x09final-urls\x03\0\x0dhttp-response\x03\0\x0e\x01ks\x01r\x02\x0ccontent-type\x10\
+\x04body\x04\x03\0\x0fbinary-response\x03\0\x11\x01r\x02\x0ccontent-type\x10\x04\
+bodys\x03\0\x0fbase64-response\x03\0\x13\x01j\x01s\x01s\x01@\x04\x03urls\x07head\
+ers\x07\x0bstart-index\x09\x0amax-length\x09\0\x15\x04\0\x05fetch\x01\x16\x01ps\x01\
+p\x15\x01@\x03\x04urls\x17\x07headers\x07\x0bconcurrency\x09\0\x18\x04\0\x0afetc\
+h-many\x01\x19\x01@\x03\x03urls\x07headers\x07\x05query\x10\0\x15\x04\0\x0afetch\
+-json\x01\x1a\x01j\x01\x12\x01s\x01@\x02\x03urls\x07headers\x07\0\x1b\x04\0\x0bf\
+etch-bytes\x01\x1c\x01j\x01\x14\x01s\x01@\x02\x03urls\x07headers\x07\0\x1d\x04\0\
+\x0cfetch-base64\x01\x1e\x01j\x01\x0f\x01s\x01@\x01\x07request\x0b\0\x1f\x04\0\x07\
+request\x01\x20\x04\0\x15component:fetch/fetch\x04\0\x0b\x0b\x01\0\x05fetch\x03\0\
+\0\0G\x09producers\x01\x0cprocessed-by\x02\x0dwit-component\x070.227.1\x10wit-bi\
+ndgen-rust\x060.41.0";
 #[inline(never)]
 #[doc(hidden)]
 pub fn __link_custom_section_describing_imports() {
@@ -1,5 +1,8 @@
+use base64::Engine;
 use common::get;
+use spin_sdk::http::Response;
 use std::collections::HashMap;
+use std::time::Duration;
 
 #[allow(warnings)]
 mod bindings;
@@ -9,14 +12,152 @@ use bindings::Guest;
 struct Component;
 
 impl Guest for Component {
-    fn fetch(url: String, headers: Vec<bindings::Header>) -> Result<String, String> {
+    fn fetch(
+        url: String,
+        headers: Vec<bindings::Header>,
+        start_index: Option<u32>,
+        max_length: Option<u32>,
+    ) -> Result<String, String> {
+        spin_executor::run(async move {
+            let headers: HashMap<String, String> =
+                headers.into_iter().map(|h| (h.name, h.value)).collect();
+            let body = fetch_text(&url, &headers).await?;
+            common::paginate(&body, start_index, max_length).map_err(|e| e.to_string())
+        })
+    }
+
+    fn fetch_many(
+        urls: Vec<String>,
+        headers: Vec<bindings::Header>,
+        concurrency: Option<u32>,
+    ) -> Vec<Result<String, String>> {
+        spin_executor::run(async move {
+            let headers: HashMap<String, String> =
+                headers.into_iter().map(|h| (h.name, h.value)).collect();
+            let concurrency = concurrency.map_or(common::DEFAULT_CONCURRENCY, |n| n as usize);
+            common::fetch_many(urls, concurrency, |url| {
+                let headers = &headers;
+                async move { fetch_text(&url, headers).await }
+            })
+            .await
+        })
+    }
+
+    fn fetch_json(
+        url: String,
+        headers: Vec<bindings::Header>,
+        query: Option<String>,
+    ) -> Result<String, String> {
+        spin_executor::run(async move {
+            let query = common::JsonQuery::parse(query.as_deref()).map_err(|e| e.to_string())?;
+            let headers: HashMap<String, String> =
+                headers.into_iter().map(|h| (h.name, h.value)).collect();
+            let response = get(&url, &headers).await.map_err(|e| e.to_string())?;
+            let body = String::from_utf8_lossy(response.body());
+            query.apply(&body).map_err(|e| e.to_string())
+        })
+    }
+
+    fn fetch_bytes(
+        url: String,
+        headers: Vec<bindings::Header>,
+    ) -> Result<bindings::BinaryResponse, String> {
         spin_executor::run(async move {
             let headers: HashMap<String, String> =
                 headers.into_iter().map(|h| (h.name, h.value)).collect();
             let response = get(&url, &headers).await.map_err(|e| e.to_string())?;
-            Ok(String::from_utf8_lossy(response.body()).to_string())
+            Ok(bindings::BinaryResponse {
+                content_type: content_type(&response),
+                body: response.into_body(),
+            })
+        })
+    }
+
+    fn fetch_base64(
+        url: String,
+        headers: Vec<bindings::Header>,
+    ) -> Result<bindings::Base64Response, String> {
+        let response = Self::fetch_bytes(url, headers)?;
+        Ok(bindings::Base64Response {
+            content_type: response.content_type,
+            body: base64::engine::general_purpose::STANDARD.encode(response.body),
+        })
+    }
+
+    fn request(request: bindings::HttpRequest) -> Result<bindings::HttpResponse, String> {
+        spin_executor::run(async move {
+            let request = common::HttpRequest {
+                method: match request.method {
+                    bindings::Method::Get => common::Method::Get,
+                    bindings::Method::Head => common::Method::Head,
+                    bindings::Method::Post => common::Method::Post,
+                    bindings::Method::Put => common::Method::Put,
+                    bindings::Method::Patch => common::Method::Patch,
+                    bindings::Method::Delete => common::Method::Delete,
+                    bindings::Method::Options => common::Method::Options,
+                },
+                url: request.url,
+                headers: request
+                    .headers
+                    .into_iter()
+                    .map(|h| (h.name, h.value))
+                    .collect(),
+                body: request.body.map(|body| match body {
+                    bindings::RequestBody::Text(text) => text.into_bytes(),
+                    bindings::RequestBody::Bytes(bytes) => bytes,
+                }),
+                timeout: request
+                    .timeout_ms
+                    .map(|ms| Duration::from_millis(ms.into())),
+            };
+            let response = common::request(request).await.map_err(|e| e.to_string())?;
+            let content_type = response
+                .headers
+                .iter()
+                .find(|(name, _)| name.eq_ignore_ascii_case("content-type"))
+                .map(|(_, value)| value.as_str());
+            let body = if common::is_binary(content_type, &response.body) {
+                bindings::ResponseBody::Bytes(response.body)
+            } else {
+                match String::from_utf8(response.body) {
+                    Ok(text) => bindings::ResponseBody::Text(text),
+                    Err(e) => bindings::ResponseBody::Bytes(e.into_bytes()),
+                }
+            };
+
+            Ok(bindings::HttpResponse {
+                status: response.status,
+                headers: response
+                    .headers
+                    .into_iter()
+                    .map(|(name, value)| bindings::Header { name, value })
+                    .collect(),
+                body,
+                final_url: response.final_url,
+            })
         })
     }
 }
 
+/// Fetch a URL and return its body as text, rejecting binary responses.
+async fn fetch_text(url: &str, headers: &HashMap<String, String>) -> Result<String, String> {
+    let response = get(url, headers).await.map_err(|e| e.to_string())?;
+    let content_type = content_type(&response);
+    if common::is_binary(content_type.as_deref(), response.body()) {
+        return Err(format!(
+            "Response is binary ({}, {} bytes); use fetch-bytes or fetch-base64 instead",
+            content_type.as_deref().unwrap_or("unknown content type"),
+            response.body().len()
+        ));
+    }
+    Ok(String::from_utf8_lossy(response.body()).into_owned())
+}
+
+fn content_type(response: &Response) -> Option<String> {
+    response
+        .header("content-type")
+        .and_then(|value| value.as_str())
+        .map(str::to_string)
+}
+
 bindings::export!(Component with_types_in bindings);
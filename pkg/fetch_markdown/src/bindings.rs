@@ -15,6 +15,47 @@ impl ::core::fmt::Debug for Header {
             .finish()
     }
 }
+/// A page found by crawl-as-markdown
+#[derive(Clone)]
+pub struct CrawledPage {
+    /// Normalised URL of the page
+    pub url: _rt::String,
+    /// The page's first heading, or its URL if it has none
+    pub title: _rt::String,
+    /// The page converted to markdown
+    pub markdown: _rt::String,
+}
+impl ::core::fmt::Debug for CrawledPage {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct("CrawledPage")
+            .field("url", &self.url)
+            .field("title", &self.title)
+            .field("markdown", &self.markdown)
+            .finish()
+    }
+}
+/// A page listed in a sitemap
+#[derive(Clone)]
+pub struct SitemapEntry {
+    /// URL of the page
+    pub loc: _rt::String,
+    /// Last modification date, as given in the sitemap
+    pub lastmod: Option<_rt::String>,
+    /// Priority relative to other pages of the site, from 0.0 to 1.0
+    pub priority: Option<f32>,
+    /// The page converted to markdown, for the first fetch-count entries
+    pub markdown: Option<Result<_rt::String, _rt::String>>,
+}
+impl ::core::fmt::Debug for SitemapEntry {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct("SitemapEntry")
+            .field("loc", &self.loc)
+            .field("lastmod", &self.lastmod)
+            .field("priority", &self.priority)
+            .field("markdown", &self.markdown)
+            .finish()
+    }
+}
 #[doc(hidden)]
 #[allow(non_snake_case)]
 pub unsafe fn _export_fetch_as_markdown_cabi<T: Guest>(
@@ -22,6 +63,15 @@ pub unsafe fn _export_fetch_as_markdown_cabi<T: Guest>(
     arg1: usize,
     arg2: *mut u8,
     arg3: usize,
+    arg4: i32,
+    arg5: i32,
+    arg6: i32,
+    arg7: i32,
+    arg8: i32,
+    arg9: *mut u8,
+    arg10: usize,
+    arg11: *mut u8,
+    arg12: usize,
 ) -> *mut u8 {
     #[cfg(target_arch = "wasm32")]
     _rt::run_ctors_once();
@@ -59,41 +109,440 @@ pub unsafe fn _export_fetch_as_markdown_cabi<T: Guest>(
         len7 * (4 * ::core::mem::size_of::<*const u8>()),
         ::core::mem::size_of::<*const u8>(),
     );
-    let result8 = T::fetch_as_markdown(_rt::string_lift(bytes0), result7);
+    let base12 = arg11;
+    let len12 = arg12;
+    let mut result12 = _rt::Vec::with_capacity(len12);
+    for i in 0..len12 {
+        let base = base12.add(i * (2 * ::core::mem::size_of::<*const u8>()));
+        let e12 = {
+            let l9 = *base.add(0).cast::<*mut u8>();
+            let l10 = *base
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            let len11 = l10;
+            let bytes11 = _rt::Vec::from_raw_parts(l9.cast(), len11, len11);
+            _rt::string_lift(bytes11)
+        };
+        result12.push(e12);
+    }
+    _rt::cabi_dealloc(
+        base12,
+        len12 * (2 * ::core::mem::size_of::<*const u8>()),
+        ::core::mem::size_of::<*const u8>(),
+    );
+    let result13 = T::fetch_as_markdown(
+        _rt::string_lift(bytes0),
+        result7,
+        match arg4 {
+            0 => None,
+            1 => {
+                let e = arg5 as u32;
+                Some(e)
+            }
+            _ => _rt::invalid_enum_discriminant(),
+        },
+        match arg6 {
+            0 => None,
+            1 => {
+                let e = arg7 as u32;
+                Some(e)
+            }
+            _ => _rt::invalid_enum_discriminant(),
+        },
+        match arg8 {
+            0 => None,
+            1 => {
+                let e = {
+                    let len8 = arg10;
+                    let bytes8 = _rt::Vec::from_raw_parts(arg9.cast(), len8, len8);
+                    _rt::string_lift(bytes8)
+                };
+                Some(e)
+            }
+            _ => _rt::invalid_enum_discriminant(),
+        },
+        result12,
+    );
+    let ptr14 = (&raw mut _RET_AREA.0).cast::<u8>();
+    match result13 {
+        Ok(e) => {
+            *ptr14.add(0).cast::<u8>() = (0i32) as u8;
+            let vec15 = (e.into_bytes()).into_boxed_slice();
+            let ptr15 = vec15.as_ptr().cast::<u8>();
+            let len15 = vec15.len();
+            ::core::mem::forget(vec15);
+            *ptr14
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>() = len15;
+            *ptr14
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>() = ptr15.cast_mut();
+        }
+        Err(e) => {
+            *ptr14.add(0).cast::<u8>() = (1i32) as u8;
+            let vec16 = (e.into_bytes()).into_boxed_slice();
+            let ptr16 = vec16.as_ptr().cast::<u8>();
+            let len16 = vec16.len();
+            ::core::mem::forget(vec16);
+            *ptr14
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>() = len16;
+            *ptr14
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>() = ptr16.cast_mut();
+        }
+    };
+    ptr14
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_fetch_as_markdown<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>();
+            let l2 = *arg0
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+        _ => {
+            let l3 = *arg0
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>();
+            let l4 = *arg0
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            _rt::cabi_dealloc(l3, l4, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_fetch_many_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+    arg2: *mut u8,
+    arg3: usize,
+    arg4: i32,
+    arg5: i32,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let base3 = arg0;
+    let len3 = arg1;
+    let mut result3 = _rt::Vec::with_capacity(len3);
+    for i in 0..len3 {
+        let base = base3.add(i * (2 * ::core::mem::size_of::<*const u8>()));
+        let e3 = {
+            let l0 = *base.add(0).cast::<*mut u8>();
+            let l1 = *base
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            let len2 = l1;
+            let bytes2 = _rt::Vec::from_raw_parts(l0.cast(), len2, len2);
+            _rt::string_lift(bytes2)
+        };
+        result3.push(e3);
+    }
+    _rt::cabi_dealloc(
+        base3,
+        len3 * (2 * ::core::mem::size_of::<*const u8>()),
+        ::core::mem::size_of::<*const u8>(),
+    );
+    let base10 = arg2;
+    let len10 = arg3;
+    let mut result10 = _rt::Vec::with_capacity(len10);
+    for i in 0..len10 {
+        let base = base10.add(i * (4 * ::core::mem::size_of::<*const u8>()));
+        let e10 = {
+            let l4 = *base.add(0).cast::<*mut u8>();
+            let l5 = *base
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            let len6 = l5;
+            let bytes6 = _rt::Vec::from_raw_parts(l4.cast(), len6, len6);
+            let l7 = *base
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>();
+            let l8 = *base
+                .add(3 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            let len9 = l8;
+            let bytes9 = _rt::Vec::from_raw_parts(l7.cast(), len9, len9);
+            Header {
+                name: _rt::string_lift(bytes6),
+                value: _rt::string_lift(bytes9),
+            }
+        };
+        result10.push(e10);
+    }
+    _rt::cabi_dealloc(
+        base10,
+        len10 * (4 * ::core::mem::size_of::<*const u8>()),
+        ::core::mem::size_of::<*const u8>(),
+    );
+    let result11 = T::fetch_many(
+        result3,
+        result10,
+        match arg4 {
+            0 => None,
+            1 => {
+                let e = arg5 as u32;
+                Some(e)
+            }
+            _ => _rt::invalid_enum_discriminant(),
+        },
+    );
+    let ptr12 = (&raw mut _RET_AREA.0).cast::<u8>();
+    let vec15 = result11;
+    let len15 = vec15.len();
+    let layout15 = _rt::alloc::Layout::from_size_align_unchecked(
+        vec15.len() * (3 * ::core::mem::size_of::<*const u8>()),
+        ::core::mem::size_of::<*const u8>(),
+    );
+    let result15 = if layout15.size() != 0 {
+        let ptr = _rt::alloc::alloc(layout15).cast::<u8>();
+        if ptr.is_null() {
+            _rt::alloc::handle_alloc_error(layout15);
+        }
+        ptr
+    } else {
+        ::core::ptr::null_mut()
+    };
+    for (i, e) in vec15.into_iter().enumerate() {
+        let base = result15.add(i * (3 * ::core::mem::size_of::<*const u8>()));
+        {
+            match e {
+                Ok(e) => {
+                    *base.add(0).cast::<u8>() = (0i32) as u8;
+                    let vec13 = (e.into_bytes()).into_boxed_slice();
+                    let ptr13 = vec13.as_ptr().cast::<u8>();
+                    let len13 = vec13.len();
+                    ::core::mem::forget(vec13);
+                    *base
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len13;
+                    *base
+                        .add(::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr13.cast_mut();
+                }
+                Err(e) => {
+                    *base.add(0).cast::<u8>() = (1i32) as u8;
+                    let vec14 = (e.into_bytes()).into_boxed_slice();
+                    let ptr14 = vec14.as_ptr().cast::<u8>();
+                    let len14 = vec14.len();
+                    ::core::mem::forget(vec14);
+                    *base
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len14;
+                    *base
+                        .add(::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr14.cast_mut();
+                }
+            };
+        }
+    }
+    *ptr12
+        .add(::core::mem::size_of::<*const u8>())
+        .cast::<usize>() = len15;
+    *ptr12.add(0).cast::<*mut u8>() = result15;
+    ptr12
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_fetch_many<T: Guest>(arg0: *mut u8) {
+    let l0 = *arg0.add(0).cast::<*mut u8>();
+    let l1 = *arg0
+        .add(::core::mem::size_of::<*const u8>())
+        .cast::<usize>();
+    let base7 = l0;
+    let len7 = l1;
+    for i in 0..len7 {
+        let base = base7.add(i * (3 * ::core::mem::size_of::<*const u8>()));
+        {
+            let l2 = i32::from(*base.add(0).cast::<u8>());
+            match l2 {
+                0 => {
+                    let l3 = *base
+                        .add(::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l4 = *base
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l3, l4, 1);
+                }
+                _ => {
+                    let l5 = *base
+                        .add(::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l6 = *base
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l5, l6, 1);
+                }
+            }
+        }
+    }
+    _rt::cabi_dealloc(
+        base7,
+        len7 * (3 * ::core::mem::size_of::<*const u8>()),
+        ::core::mem::size_of::<*const u8>(),
+    );
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_crawl_as_markdown_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+    arg2: *mut u8,
+    arg3: usize,
+    arg4: i32,
+    arg5: i32,
+    arg6: i32,
+    arg7: i32,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let base7 = arg2;
+    let len7 = arg3;
+    let mut result7 = _rt::Vec::with_capacity(len7);
+    for i in 0..len7 {
+        let base = base7.add(i * (4 * ::core::mem::size_of::<*const u8>()));
+        let e7 = {
+            let l1 = *base.add(0).cast::<*mut u8>();
+            let l2 = *base
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            let len3 = l2;
+            let bytes3 = _rt::Vec::from_raw_parts(l1.cast(), len3, len3);
+            let l4 = *base
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>();
+            let l5 = *base
+                .add(3 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            let len6 = l5;
+            let bytes6 = _rt::Vec::from_raw_parts(l4.cast(), len6, len6);
+            Header {
+                name: _rt::string_lift(bytes3),
+                value: _rt::string_lift(bytes6),
+            }
+        };
+        result7.push(e7);
+    }
+    _rt::cabi_dealloc(
+        base7,
+        len7 * (4 * ::core::mem::size_of::<*const u8>()),
+        ::core::mem::size_of::<*const u8>(),
+    );
+    let result8 = T::crawl_as_markdown(
+        _rt::string_lift(bytes0),
+        result7,
+        match arg4 {
+            0 => None,
+            1 => {
+                let e = arg5 as u32;
+                Some(e)
+            }
+            _ => _rt::invalid_enum_discriminant(),
+        },
+        match arg6 {
+            0 => None,
+            1 => {
+                let e = arg7 as u32;
+                Some(e)
+            }
+            _ => _rt::invalid_enum_discriminant(),
+        },
+    );
     let ptr9 = (&raw mut _RET_AREA.0).cast::<u8>();
     match result8 {
         Ok(e) => {
             *ptr9.add(0).cast::<u8>() = (0i32) as u8;
-            let vec10 = (e.into_bytes()).into_boxed_slice();
-            let ptr10 = vec10.as_ptr().cast::<u8>();
-            let len10 = vec10.len();
-            ::core::mem::forget(vec10);
+            let vec14 = e;
+            let len14 = vec14.len();
+            let layout14 = _rt::alloc::Layout::from_size_align_unchecked(
+                vec14.len() * (6 * ::core::mem::size_of::<*const u8>()),
+                ::core::mem::size_of::<*const u8>(),
+            );
+            let result14 = if layout14.size() != 0 {
+                let ptr = _rt::alloc::alloc(layout14).cast::<u8>();
+                if ptr.is_null() {
+                    _rt::alloc::handle_alloc_error(layout14);
+                }
+                ptr
+            } else {
+                ::core::ptr::null_mut()
+            };
+            for (i, e) in vec14.into_iter().enumerate() {
+                let base = result14.add(i * (6 * ::core::mem::size_of::<*const u8>()));
+                {
+                    let CrawledPage {
+                        url: url10,
+                        title: title10,
+                        markdown: markdown10,
+                    } = e;
+                    let vec11 = (url10.into_bytes()).into_boxed_slice();
+                    let ptr11 = vec11.as_ptr().cast::<u8>();
+                    let len11 = vec11.len();
+                    ::core::mem::forget(vec11);
+                    *base
+                        .add(::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len11;
+                    *base.add(0).cast::<*mut u8>() = ptr11.cast_mut();
+                    let vec12 = (title10.into_bytes()).into_boxed_slice();
+                    let ptr12 = vec12.as_ptr().cast::<u8>();
+                    let len12 = vec12.len();
+                    ::core::mem::forget(vec12);
+                    *base
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len12;
+                    *base
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr12.cast_mut();
+                    let vec13 = (markdown10.into_bytes()).into_boxed_slice();
+                    let ptr13 = vec13.as_ptr().cast::<u8>();
+                    let len13 = vec13.len();
+                    ::core::mem::forget(vec13);
+                    *base
+                        .add(5 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len13;
+                    *base
+                        .add(4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr13.cast_mut();
+                }
+            }
             *ptr9
                 .add(2 * ::core::mem::size_of::<*const u8>())
-                .cast::<usize>() = len10;
+                .cast::<usize>() = len14;
             *ptr9
                 .add(::core::mem::size_of::<*const u8>())
-                .cast::<*mut u8>() = ptr10.cast_mut();
+                .cast::<*mut u8>() = result14;
         }
         Err(e) => {
             *ptr9.add(0).cast::<u8>() = (1i32) as u8;
-            let vec11 = (e.into_bytes()).into_boxed_slice();
-            let ptr11 = vec11.as_ptr().cast::<u8>();
-            let len11 = vec11.len();
-            ::core::mem::forget(vec11);
+            let vec15 = (e.into_bytes()).into_boxed_slice();
+            let ptr15 = vec15.as_ptr().cast::<u8>();
+            let len15 = vec15.len();
+            ::core::mem::forget(vec15);
             *ptr9
                 .add(2 * ::core::mem::size_of::<*const u8>())
-                .cast::<usize>() = len11;
+                .cast::<usize>() = len15;
             *ptr9
                 .add(::core::mem::size_of::<*const u8>())
-                .cast::<*mut u8>() = ptr11.cast_mut();
+                .cast::<*mut u8>() = ptr15.cast_mut();
         }
     };
     ptr9
 }
 #[doc(hidden)]
 #[allow(non_snake_case)]
-pub unsafe fn __post_return_fetch_as_markdown<T: Guest>(arg0: *mut u8) {
+pub unsafe fn __post_return_crawl_as_markdown<T: Guest>(arg0: *mut u8) {
     let l0 = i32::from(*arg0.add(0).cast::<u8>());
     match l0 {
         0 => {
@@ -103,36 +552,477 @@ pub unsafe fn __post_return_fetch_as_markdown<T: Guest>(arg0: *mut u8) {
             let l2 = *arg0
                 .add(2 * ::core::mem::size_of::<*const u8>())
                 .cast::<usize>();
-            _rt::cabi_dealloc(l1, l2, 1);
+            let base9 = l1;
+            let len9 = l2;
+            for i in 0..len9 {
+                let base = base9.add(i * (6 * ::core::mem::size_of::<*const u8>()));
+                {
+                    let l3 = *base.add(0).cast::<*mut u8>();
+                    let l4 = *base
+                        .add(::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l3, l4, 1);
+                    let l5 = *base
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l6 = *base
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l5, l6, 1);
+                    let l7 = *base
+                        .add(4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l8 = *base
+                        .add(5 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l7, l8, 1);
+                }
+            }
+            _rt::cabi_dealloc(
+                base9,
+                len9 * (6 * ::core::mem::size_of::<*const u8>()),
+                ::core::mem::size_of::<*const u8>(),
+            );
         }
         _ => {
-            let l3 = *arg0
+            let l10 = *arg0
                 .add(::core::mem::size_of::<*const u8>())
                 .cast::<*mut u8>();
-            let l4 = *arg0
+            let l11 = *arg0
                 .add(2 * ::core::mem::size_of::<*const u8>())
                 .cast::<usize>();
-            _rt::cabi_dealloc(l3, l4, 1);
+            _rt::cabi_dealloc(l10, l11, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_sitemap_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+    arg2: *mut u8,
+    arg3: usize,
+    arg4: i32,
+    arg5: *mut u8,
+    arg6: usize,
+    arg7: i32,
+    arg8: *mut u8,
+    arg9: usize,
+    arg10: i32,
+    arg11: i32,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let base7 = arg2;
+    let len7 = arg3;
+    let mut result7 = _rt::Vec::with_capacity(len7);
+    for i in 0..len7 {
+        let base = base7.add(i * (4 * ::core::mem::size_of::<*const u8>()));
+        let e7 = {
+            let l1 = *base.add(0).cast::<*mut u8>();
+            let l2 = *base
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            let len3 = l2;
+            let bytes3 = _rt::Vec::from_raw_parts(l1.cast(), len3, len3);
+            let l4 = *base
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>();
+            let l5 = *base
+                .add(3 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            let len6 = l5;
+            let bytes6 = _rt::Vec::from_raw_parts(l4.cast(), len6, len6);
+            Header {
+                name: _rt::string_lift(bytes3),
+                value: _rt::string_lift(bytes6),
+            }
+        };
+        result7.push(e7);
+    }
+    _rt::cabi_dealloc(
+        base7,
+        len7 * (4 * ::core::mem::size_of::<*const u8>()),
+        ::core::mem::size_of::<*const u8>(),
+    );
+    let result10 = T::sitemap(
+        _rt::string_lift(bytes0),
+        result7,
+        match arg4 {
+            0 => None,
+            1 => {
+                let e = {
+                    let len8 = arg6;
+                    let bytes8 = _rt::Vec::from_raw_parts(arg5.cast(), len8, len8);
+                    _rt::string_lift(bytes8)
+                };
+                Some(e)
+            }
+            _ => _rt::invalid_enum_discriminant(),
+        },
+        match arg7 {
+            0 => None,
+            1 => {
+                let e = {
+                    let len9 = arg9;
+                    let bytes9 = _rt::Vec::from_raw_parts(arg8.cast(), len9, len9);
+                    _rt::string_lift(bytes9)
+                };
+                Some(e)
+            }
+            _ => _rt::invalid_enum_discriminant(),
+        },
+        match arg10 {
+            0 => None,
+            1 => {
+                let e = arg11 as u32;
+                Some(e)
+            }
+            _ => _rt::invalid_enum_discriminant(),
+        },
+    );
+    let ptr11 = (&raw mut _RET_AREA.0).cast::<u8>();
+    match result10 {
+        Ok(e) => {
+            *ptr11.add(0).cast::<u8>() = (0i32) as u8;
+            let vec17 = e;
+            let len17 = vec17.len();
+            let layout17 = _rt::alloc::Layout::from_size_align_unchecked(
+                vec17.len() * (8 + 9 * ::core::mem::size_of::<*const u8>()),
+                ::core::mem::size_of::<*const u8>(),
+            );
+            let result17 = if layout17.size() != 0 {
+                let ptr = _rt::alloc::alloc(layout17).cast::<u8>();
+                if ptr.is_null() {
+                    _rt::alloc::handle_alloc_error(layout17);
+                }
+                ptr
+            } else {
+                ::core::ptr::null_mut()
+            };
+            for (i, e) in vec17.into_iter().enumerate() {
+                let base = result17.add(i * (8 + 9 * ::core::mem::size_of::<*const u8>()));
+                {
+                    let SitemapEntry {
+                        loc: loc12,
+                        lastmod: lastmod12,
+                        priority: priority12,
+                        markdown: markdown12,
+                    } = e;
+                    let vec13 = (loc12.into_bytes()).into_boxed_slice();
+                    let ptr13 = vec13.as_ptr().cast::<u8>();
+                    let len13 = vec13.len();
+                    ::core::mem::forget(vec13);
+                    *base
+                        .add(::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len13;
+                    *base.add(0).cast::<*mut u8>() = ptr13.cast_mut();
+                    match lastmod12 {
+                        Some(e) => {
+                            *base
+                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (1i32) as u8;
+                            let vec14 = (e.into_bytes()).into_boxed_slice();
+                            let ptr14 = vec14.as_ptr().cast::<u8>();
+                            let len14 = vec14.len();
+                            ::core::mem::forget(vec14);
+                            *base
+                                .add(4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len14;
+                            *base
+                                .add(3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>() = ptr14.cast_mut();
+                        }
+                        None => {
+                            *base
+                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                    match priority12 {
+                        Some(e) => {
+                            *base
+                                .add(5 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (1i32) as u8;
+                            *base
+                                .add(4 + 5 * ::core::mem::size_of::<*const u8>())
+                                .cast::<f32>() = _rt::as_f32(e);
+                        }
+                        None => {
+                            *base
+                                .add(5 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                    match markdown12 {
+                        Some(e) => {
+                            *base
+                                .add(8 + 5 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (1i32) as u8;
+                            match e {
+                                Ok(e) => {
+                                    *base
+                                        .add(8 + 6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (0i32) as u8;
+                                    let vec15 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr15 = vec15.as_ptr().cast::<u8>();
+                                    let len15 = vec15.len();
+                                    ::core::mem::forget(vec15);
+                                    *base
+                                        .add(8 + 8 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len15;
+                                    *base
+                                        .add(8 + 7 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>() = ptr15.cast_mut();
+                                }
+                                Err(e) => {
+                                    *base
+                                        .add(8 + 6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (1i32) as u8;
+                                    let vec16 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr16 = vec16.as_ptr().cast::<u8>();
+                                    let len16 = vec16.len();
+                                    ::core::mem::forget(vec16);
+                                    *base
+                                        .add(8 + 8 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len16;
+                                    *base
+                                        .add(8 + 7 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>() = ptr16.cast_mut();
+                                }
+                            };
+                        }
+                        None => {
+                            *base
+                                .add(8 + 5 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                }
+            }
+            *ptr11
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>() = len17;
+            *ptr11
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>() = result17;
+        }
+        Err(e) => {
+            *ptr11.add(0).cast::<u8>() = (1i32) as u8;
+            let vec18 = (e.into_bytes()).into_boxed_slice();
+            let ptr18 = vec18.as_ptr().cast::<u8>();
+            let len18 = vec18.len();
+            ::core::mem::forget(vec18);
+            *ptr11
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>() = len18;
+            *ptr11
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>() = ptr18.cast_mut();
+        }
+    };
+    ptr11
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_sitemap<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>();
+            let l2 = *arg0
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            let base14 = l1;
+            let len14 = l2;
+            for i in 0..len14 {
+                let base = base14.add(i * (8 + 9 * ::core::mem::size_of::<*const u8>()));
+                {
+                    let l3 = *base.add(0).cast::<*mut u8>();
+                    let l4 = *base
+                        .add(::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l3, l4, 1);
+                    let l5 = i32::from(
+                        *base
+                            .add(2 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    match l5 {
+                        0 => {}
+                        _ => {
+                            let l6 = *base
+                                .add(3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l7 = *base
+                                .add(4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            _rt::cabi_dealloc(l6, l7, 1);
+                        }
+                    }
+                    let l8 = i32::from(
+                        *base
+                            .add(8 + 5 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    match l8 {
+                        0 => {}
+                        _ => {
+                            let l9 = i32::from(
+                                *base
+                                    .add(8 + 6 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<u8>(),
+                            );
+                            match l9 {
+                                0 => {
+                                    let l10 = *base
+                                        .add(8 + 7 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>();
+                                    let l11 = *base
+                                        .add(8 + 8 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l10, l11, 1);
+                                }
+                                _ => {
+                                    let l12 = *base
+                                        .add(8 + 7 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>();
+                                    let l13 = *base
+                                        .add(8 + 8 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l12, l13, 1);
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            _rt::cabi_dealloc(
+                base14,
+                len14 * (8 + 9 * ::core::mem::size_of::<*const u8>()),
+                ::core::mem::size_of::<*const u8>(),
+            );
+        }
+        _ => {
+            let l15 = *arg0
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>();
+            let l16 = *arg0
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            _rt::cabi_dealloc(l15, l16, 1);
         }
     }
 }
 pub trait Guest {
-    /// Fetch a URL and convert the response to markdown
+    /// Fetch a URL and convert the response to markdown.
+    ///
+    /// Redirects are followed, and credential headers such as Authorization and Cookie are
+    /// dropped when a redirect leads to another origin.
+    ///
+    /// HTML, JSON, CSV, YAML, TOML, XML and feeds are converted, markdown and plain text are
+    /// passed through, and source files are wrapped in a code block. PDF text is extracted
+    /// with a separator between pages and headings where the font sizes show them; PDFs over
+    /// 50 MiB or with damaged pages are refused. Responses without a content type are
+    /// sniffed. Other binary responses are reported as an unsupported content type.
+    ///
+    /// Long documents can be read in pages by passing start-index and max-length, both in
+    /// characters of the converted markdown. The page is then followed by a note with the
+    /// total length and the start-index to continue from.
+    ///
+    /// For HTML pages, include-selector limits the conversion to the elements matching a
+    /// CSS selector such as `article.content` or `#main`, and elements matching any of
+    /// exclude-selectors are removed. An invalid selector, or an include-selector that
+    /// matches nothing, is an error.
     fn fetch_as_markdown(
         url: _rt::String,
         headers: _rt::Vec<Header>,
+        start_index: Option<u32>,
+        max_length: Option<u32>,
+        include_selector: Option<_rt::String>,
+        exclude_selectors: _rt::Vec<_rt::String>,
     ) -> Result<_rt::String, _rt::String>;
+    /// Fetch several URLs with the same headers and convert each to markdown, like calling
+    /// fetch-as-markdown for each one.
+    ///
+    /// At most `concurrency` requests run at once, 4 if not given. Results are returned
+    /// in the order of `urls`, each with its own error, so one failing URL does not fail
+    /// the batch.
+    fn fetch_many(
+        urls: _rt::Vec<_rt::String>,
+        headers: _rt::Vec<Header>,
+        concurrency: Option<u32>,
+    ) -> _rt::Vec<Result<_rt::String, _rt::String>>;
+    /// Crawl a site breadth-first from a URL, converting each page to markdown as
+    /// fetch-as-markdown does.
+    ///
+    /// Links in the converted pages are followed when they stay on the same origin and
+    /// under the directory of the start URL. Each URL is fetched once after removing
+    /// fragments and trailing index.html. Redirects are followed only within the start
+    /// URL's origin, and a redirected page is listed under its final URL. The crawl stops
+    /// after max-depth links from the start page (2 if not given) or max-pages pages (20
+    /// if not given, and at least 1). Pages that fail to fetch are skipped, but a failing
+    /// start page is an error.
+    fn crawl_as_markdown(
+        url: _rt::String,
+        headers: _rt::Vec<Header>,
+        max_depth: Option<u32>,
+        max_pages: Option<u32>,
+    ) -> Result<_rt::Vec<CrawledPage>, _rt::String>;
+    /// List the pages of a site from its sitemap, following sitemap index files and
+    /// reading gzipped sitemaps of up to 50 MiB uncompressed. Sitemaps are fetched following
+    /// redirects, and index files are only followed to sitemaps on the same origin as the
+    /// URL the first sitemap was finally fetched from.
+    ///
+    /// A site root such as https://example.com/ is looked up at /sitemap.xml. Entries can be
+    /// limited to URLs under path-prefix (a path, or an absolute URL prefix) and to those
+    /// modified on or after modified-since (YYYY-MM-DD or RFC 3339). The first fetch-count
+    /// matching entries, none if not given, are also fetched and converted to markdown.
+    fn sitemap(
+        url: _rt::String,
+        headers: _rt::Vec<Header>,
+        path_prefix: Option<_rt::String>,
+        modified_since: Option<_rt::String>,
+        fetch_count: Option<u32>,
+    ) -> Result<_rt::Vec<SitemapEntry>, _rt::String>;
 }
 #[doc(hidden)]
 macro_rules! __export_world_fetch_markdown_cabi {
     ($ty:ident with_types_in $($path_to_types:tt)*) => {
         const _ : () = { #[unsafe (export_name = "fetch-as-markdown")] unsafe extern "C"
         fn export_fetch_as_markdown(arg0 : * mut u8, arg1 : usize, arg2 : * mut u8, arg3
-        : usize,) -> * mut u8 { unsafe { $($path_to_types)*::
-        _export_fetch_as_markdown_cabi::<$ty > (arg0, arg1, arg2, arg3) } } #[unsafe
+        : usize, arg4 : i32, arg5 : i32, arg6 : i32, arg7 : i32, arg8 : i32, arg9 : * mut
+        u8, arg10 : usize, arg11 : * mut u8, arg12 : usize,) -> * mut u8 { unsafe {
+        $($path_to_types)*:: _export_fetch_as_markdown_cabi::<$ty > (arg0, arg1, arg2,
+        arg3, arg4, arg5, arg6, arg7, arg8, arg9, arg10, arg11, arg12) } } #[unsafe
         (export_name = "cabi_post_fetch-as-markdown")] unsafe extern "C" fn
         _post_return_fetch_as_markdown(arg0 : * mut u8,) { unsafe { $($path_to_types)*::
-        __post_return_fetch_as_markdown::<$ty > (arg0) } } };
+        __post_return_fetch_as_markdown::<$ty > (arg0) } } #[unsafe (export_name =
+        "fetch-many")] unsafe extern "C" fn export_fetch_many(arg0 : * mut u8, arg1 :
+        usize, arg2 : * mut u8, arg3 : usize, arg4 : i32, arg5 : i32,) -> * mut u8 {
+        unsafe { $($path_to_types)*:: _export_fetch_many_cabi::<$ty > (arg0, arg1, arg2,
+        arg3, arg4, arg5) } } #[unsafe (export_name = "cabi_post_fetch-many")] unsafe
+        extern "C" fn _post_return_fetch_many(arg0 : * mut u8,) { unsafe {
+        $($path_to_types)*:: __post_return_fetch_many::<$ty > (arg0) } } #[unsafe
+        (export_name = "crawl-as-markdown")] unsafe extern "C" fn
+        export_crawl_as_markdown(arg0 : * mut u8, arg1 : usize, arg2 : * mut u8, arg3 :
+        usize, arg4 : i32, arg5 : i32, arg6 : i32, arg7 : i32,) -> * mut u8 { unsafe {
+        $($path_to_types)*:: _export_crawl_as_markdown_cabi::<$ty > (arg0, arg1, arg2,
+        arg3, arg4, arg5, arg6, arg7) } } #[unsafe (export_name =
+        "cabi_post_crawl-as-markdown")] unsafe extern "C" fn
+        _post_return_crawl_as_markdown(arg0 : * mut u8,) { unsafe { $($path_to_types)*::
+        __post_return_crawl_as_markdown::<$ty > (arg0) } } #[unsafe (export_name =
+        "sitemap")] unsafe extern "C" fn export_sitemap(arg0 : * mut u8, arg1 : usize,
+        arg2 : * mut u8, arg3 : usize, arg4 : i32, arg5 : * mut u8, arg6 : usize, arg7 :
+        i32, arg8 : * mut u8, arg9 : usize, arg10 : i32, arg11 : i32,) -> * mut u8 {
+        unsafe { $($path_to_types)*:: _export_sitemap_cabi::<$ty > (arg0, arg1, arg2,
+        arg3, arg4, arg5, arg6, arg7, arg8, arg9, arg10, arg11) } } #[unsafe (export_name
+        = "cabi_post_sitemap")] unsafe extern "C" fn _post_return_sitemap(arg0 : * mut
+        u8,) { unsafe { $($path_to_types)*:: __post_return_sitemap::<$ty > (arg0) } } };
     };
 }
 #[doc(hidden)]
@@ -165,8 +1055,32 @@ mod _rt {
         let layout = alloc::Layout::from_size_align_unchecked(size, align);
         alloc::dealloc(ptr, layout);
     }
-    extern crate alloc as alloc_crate;
+    pub unsafe fn invalid_enum_discriminant<T>() -> T {
+        if cfg!(debug_assertions) {
+            panic!("invalid enum discriminant")
+        } else {
+            unsafe { core::hint::unreachable_unchecked() }
+        }
+    }
     pub use alloc_crate::alloc;
+    pub fn as_f32<T: AsF32>(t: T) -> f32 {
+        t.as_f32()
+    }
+    pub trait AsF32 {
+        fn as_f32(self) -> f32;
+    }
+    impl<'a, T: Copy + AsF32> AsF32 for &'a T {
+        fn as_f32(self) -> f32 {
+            (*self).as_f32()
+        }
+    }
+    impl AsF32 for f32 {
+        #[inline]
+        fn as_f32(self) -> f32 {
+            self as f32
+        }
+    }
+    extern crate alloc as alloc_crate;
 }
 /// Generates `#[unsafe(no_mangle)]` functions to export the specified type as
 /// the root implementation of all generated traits.
@@ -201,13 +1115,21 @@ pub(crate) use __export_fetch_markdown_impl as export;
 #[unsafe(link_section = "component-type:wit-bindgen:0.41.0:component:fetch-markdown:fetch-markdown:encoded world")]
 #[doc(hidden)]
 #[allow(clippy::octal_escapes)]
-pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 261] = *b"\
-\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\x80\x01\x01A\x02\x01\
-A\x06\x01r\x02\x04names\x05values\x03\0\x06header\x03\0\0\x01p\x01\x01j\x01s\x01\
-s\x01@\x02\x03urls\x07headers\x02\0\x03\x04\0\x11fetch-as-markdown\x01\x04\x04\0\
-'component:fetch-markdown/fetch-markdown\x04\0\x0b\x14\x01\0\x0efetch-markdown\x03\
-\0\0\0G\x09producers\x01\x0cprocessed-by\x02\x0dwit-component\x070.227.1\x10wit-\
-bindgen-rust\x060.41.0";
+pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 642] = *b"\
+\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\xfd\x03\x01A\x02\x01\
+A\x1a\x01r\x02\x04names\x05values\x03\0\x06header\x03\0\0\x01r\x03\x03urls\x05ti\
+tles\x08markdowns\x03\0\x0ccrawled-page\x03\0\x02\x01ks\x01kv\x01j\x01s\x01s\x01\
+k\x06\x01r\x04\x03locs\x07lastmod\x04\x08priority\x05\x08markdown\x07\x03\0\x0ds\
+itemap-entry\x03\0\x08\x01p\x01\x01ky\x01ps\x01@\x06\x03urls\x07headers\x0a\x0bs\
+tart-index\x0b\x0amax-length\x0b\x10include-selector\x04\x11exclude-selectors\x0c\
+\0\x06\x04\0\x11fetch-as-markdown\x01\x0d\x01p\x06\x01@\x03\x04urls\x0c\x07heade\
+rs\x0a\x0bconcurrency\x0b\0\x0e\x04\0\x0afetch-many\x01\x0f\x01p\x03\x01j\x01\x10\
+\x01s\x01@\x04\x03urls\x07headers\x0a\x09max-depth\x0b\x09max-pages\x0b\0\x11\x04\
+\0\x11crawl-as-markdown\x01\x12\x01p\x09\x01j\x01\x13\x01s\x01@\x05\x03urls\x07h\
+eaders\x0a\x0bpath-prefix\x04\x0emodified-since\x04\x0bfetch-count\x0b\0\x14\x04\
+\0\x07sitemap\x01\x15\x04\0'component:fetch-markdown/fetch-markdown\x04\0\x0b\x14\
+\x01\0\x0efetch-markdown\x03\0\0\0G\x09producers\x01\x0cprocessed-by\x02\x0dwit-\
+component\x070.227.1\x10wit-bindgen-rust\x060.41.0";
 #[inline(never)]
 #[doc(hidden)]
 pub fn __link_custom_section_describing_imports() {
@@ -1,4 +1,4 @@
-use common::get;
+use common::{FetchedPage, HttpRequest, HttpResponse, Method};
 use std::collections::HashMap;
 
 #[allow(warnings)]
@@ -9,31 +9,284 @@ use bindings::Guest;
 struct Component;
 
 impl Guest for Component {
-    fn fetch_as_markdown(url: String, headers: Vec<bindings::Header>) -> Result<String, String> {
+    fn fetch_as_markdown(
+        url: String,
+        headers: Vec<bindings::Header>,
+        start_index: Option<u32>,
+        max_length: Option<u32>,
+        include_selector: Option<String>,
+        exclude_selectors: Vec<String>,
+    ) -> Result<String, String> {
         spin_executor::run(async move {
             let headers: HashMap<String, String> =
                 headers.into_iter().map(|h| (h.name, h.value)).collect();
-            let response = get(&url, &headers)
-                .await
-                .map_err(|e| format!("fetch error: {e}"))?;
-            let content = String::from_utf8_lossy(response.body()).into_owned();
-
-            // Check content type to determine conversion method
-            let content_type = response
-                .headers()
-                .find(|(name, _)| name.to_lowercase() == "content-type")
-                .map(|(_, value)| value.as_bytes())
-                .and_then(|bytes| std::str::from_utf8(bytes).ok())
-                .map(|s| s.to_lowercase())
-                .unwrap_or_default();
-
-            if content_type.contains("application/json") {
-                Ok(common::json_to_markdown(&content))
-            } else {
-                Ok(common::html_to_markdown(&content))
-            }
+            let selection = Selection {
+                include: include_selector,
+                exclude: exclude_selectors,
+            };
+            let markdown = fetch_markdown(&url, &headers, &selection).await?;
+            common::paginate(&markdown, start_index, max_length).map_err(|e| e.to_string())
+        })
+    }
+
+    fn fetch_many(
+        urls: Vec<String>,
+        headers: Vec<bindings::Header>,
+        concurrency: Option<u32>,
+    ) -> Vec<Result<String, String>> {
+        spin_executor::run(async move {
+            let headers: HashMap<String, String> =
+                headers.into_iter().map(|h| (h.name, h.value)).collect();
+            let concurrency = concurrency.map_or(common::DEFAULT_CONCURRENCY, |n| n as usize);
+            common::fetch_many(urls, concurrency, |url| {
+                let headers = &headers;
+                async move { fetch_markdown(&url, headers, &Selection::default()).await }
+            })
+            .await
+        })
+    }
+
+    fn crawl_as_markdown(
+        url: String,
+        headers: Vec<bindings::Header>,
+        max_depth: Option<u32>,
+        max_pages: Option<u32>,
+    ) -> Result<Vec<bindings::CrawledPage>, String> {
+        spin_executor::run(async move {
+            let headers: HashMap<String, String> =
+                headers.into_iter().map(|h| (h.name, h.value)).collect();
+            let defaults = common::CrawlOptions::default();
+            let options = common::CrawlOptions {
+                max_depth: max_depth.map_or(defaults.max_depth, |n| n as usize),
+                max_pages: max_pages.map_or(defaults.max_pages, |n| n as usize),
+                ..defaults
+            };
+            let pages = common::crawl(&url, &options, |url| {
+                let headers = &headers;
+                async move { fetch_page(&url, headers, &Selection::default()).await }
+            })
+            .await
+            .map_err(|e| e.to_string())?;
+
+            Ok(pages
+                .into_iter()
+                .map(|page| bindings::CrawledPage {
+                    url: page.url,
+                    title: page.title,
+                    markdown: page.markdown,
+                })
+                .collect())
+        })
+    }
+
+    fn sitemap(
+        url: String,
+        headers: Vec<bindings::Header>,
+        path_prefix: Option<String>,
+        modified_since: Option<String>,
+        fetch_count: Option<u32>,
+    ) -> Result<Vec<bindings::SitemapEntry>, String> {
+        spin_executor::run(async move {
+            let headers: HashMap<String, String> =
+                headers.into_iter().map(|h| (h.name, h.value)).collect();
+            let filter = common::SitemapFilter {
+                path_prefix,
+                modified_since,
+            };
+            let entries = common::sitemap_entries(&url, &filter, |url| {
+                let headers = &headers;
+                async move {
+                    let response = get_following_redirects(&url, headers).await?;
+                    Ok(common::FetchedSitemap {
+                        url: response.final_url,
+                        body: response.body,
+                    })
+                }
+            })
+            .await
+            .map_err(|e| e.to_string())?;
+
+            let fetch_count = fetch_count.map_or(0, |n| n as usize).min(entries.len());
+            let urls = entries[..fetch_count]
+                .iter()
+                .map(|entry| entry.loc.clone())
+                .collect();
+            let mut pages = common::fetch_many(urls, common::DEFAULT_CONCURRENCY, |url| {
+                let headers = &headers;
+                async move { fetch_markdown(&url, headers, &Selection::default()).await }
+            })
+            .await
+            .into_iter();
+
+            Ok(entries
+                .into_iter()
+                .map(|entry| bindings::SitemapEntry {
+                    loc: entry.loc,
+                    lastmod: entry.lastmod,
+                    priority: entry.priority,
+                    markdown: pages.next(),
+                })
+                .collect())
         })
     }
 }
 
+/// The parts of an HTML page to convert, given as CSS selectors
+#[derive(Default)]
+struct Selection {
+    include: Option<String>,
+    exclude: Vec<String>,
+}
+
+impl Selection {
+    fn is_empty(&self) -> bool {
+        self.include.is_none() && self.exclude.is_empty()
+    }
+}
+
+/// Fetch a URL and convert the response, or the selected parts of an HTML page, to markdown.
+async fn fetch_markdown(
+    url: &str,
+    headers: &HashMap<String, String>,
+    selection: &Selection,
+) -> Result<String, String> {
+    fetch_page(url, headers, selection)
+        .await
+        .map(|page| page.markdown)
+}
+
+/// Fetch a URL, following redirects, and convert the response to markdown as
+/// [`fetch_markdown`] does, keeping the URL the page was finally served from.
+async fn fetch_page(
+    url: &str,
+    headers: &HashMap<String, String>,
+    selection: &Selection,
+) -> Result<FetchedPage, String> {
+    let response = get_following_redirects(url, headers)
+        .await
+        .map_err(|e| format!("fetch error: {e}"))?;
+    let markdown = response_to_markdown(&response, selection)?;
+    Ok(FetchedPage {
+        url: response.final_url,
+        markdown,
+    })
+}
+
+/// Send a GET request, following redirects, and fail unless the final response
+/// is successful.
+async fn get_following_redirects(
+    url: &str,
+    headers: &HashMap<String, String>,
+) -> Result<HttpResponse, String> {
+    let response = common::request(HttpRequest {
+        method: Method::Get,
+        url: url.to_string(),
+        headers: headers
+            .iter()
+            .map(|(name, value)| (name.clone(), value.clone()))
+            .collect(),
+        body: None,
+        timeout: None,
+    })
+    .await
+    .map_err(|e| e.to_string())?;
+    if !(200..300).contains(&response.status) {
+        return Err(format!(
+            "Request failed with status code: {}",
+            response.status
+        ));
+    }
+    Ok(response)
+}
+
+/// Convert a successful response, or the selected parts of an HTML page, to markdown.
+fn response_to_markdown(response: &HttpResponse, selection: &Selection) -> Result<String, String> {
+    let url = response.final_url.as_str();
+    let content_type = response
+        .headers
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case("content-type"))
+        .map(|(_, value)| common::mime_type(value))
+        .filter(|mime| !mime.is_empty());
+    // PDFs are also recognised by their signature, since servers often send them
+    // without a content type or as application/octet-stream
+    if content_type.as_deref() == Some("application/pdf") || response.body.starts_with(b"%PDF-") {
+        if !selection.is_empty() {
+            return Err(
+                "CSS selectors can only be applied to HTML, but the response is a PDF".into(),
+            );
+        }
+        return common::pdf_to_markdown(&response.body).map_err(|e| e.to_string());
+    }
+    if common::is_binary(content_type.as_deref(), &response.body) {
+        return Err(format!(
+            "unsupported content type: {} ({} bytes)",
+            content_type.as_deref().unwrap_or("unknown binary data"),
+            response.body.len()
+        ));
+    }
+    let content = String::from_utf8_lossy(&response.body);
+    let content_type =
+        content_type.unwrap_or_else(|| common::sniff_content_type(&content).to_string());
+
+    if !selection.is_empty() {
+        if !matches!(content_type.as_str(), "text/html" | "application/xhtml+xml") {
+            return Err(format!(
+                "CSS selectors can only be applied to HTML, but the response is {content_type}"
+            ));
+        }
+        let html = common::select_html(&content, selection.include.as_deref(), &selection.exclude)
+            .map_err(|e| e.to_string())?;
+        return Ok(common::html_to_markdown(&html));
+    }
+
+    Ok(content_to_markdown(url, &content_type, &content))
+}
+
+/// Convert a response body to markdown based on its mime type.
+///
+/// Structured formats that fail to parse, such as truncated JSON, are still worth
+/// showing verbatim, so they fall back to a fenced code block of the raw text.
+/// Types with no dedicated conversion are sniffed from the content.
+fn content_to_markdown(url: &str, mime: &str, content: &str) -> String {
+    let (converted, language) = match mime {
+        "application/json" => (common::json_to_markdown(content), "json"),
+        m if m.ends_with("+json") => (common::json_to_markdown(content), "json"),
+        "text/csv" => (common::csv_to_markdown(content, b','), "csv"),
+        "text/tab-separated-values" => (common::csv_to_markdown(content, b'\t'), "tsv"),
+        "application/yaml" | "application/x-yaml" | "text/yaml" | "text/x-yaml" => {
+            (common::yaml_to_markdown(content), "yaml")
+        }
+        "application/toml" => (common::toml_to_markdown(content), "toml"),
+        "text/html" | "application/xhtml+xml" => return common::html_to_markdown(content),
+        "application/rss+xml" | "application/atom+xml" | "application/rdf+xml" => (
+            common::parse_feed(content).map(|feed| common::feed_to_markdown(&feed)),
+            "xml",
+        ),
+        m if m == "application/xml" || m == "text/xml" || m.ends_with("+xml") => {
+            // Feeds are commonly served with a generic XML type
+            match common::feed_content_type(content) {
+                Some(feed) => return content_to_markdown(url, feed, content),
+                None => (common::xml_to_markdown(content), "xml"),
+            }
+        }
+        "text/markdown" | "text/x-markdown" => return content.to_string(),
+        m if common::is_text_content_type(m) => return plain_text(url, content),
+        _ => match common::sniff_content_type(content) {
+            "text/plain" => return plain_text(url, content),
+            sniffed => return content_to_markdown(url, sniffed, content),
+        },
+    };
+    converted.unwrap_or_else(|_| common::fenced_code_block(content, language))
+}
+
+/// Pass plain text through unchanged, except for source files, which are put in
+/// a code block for the language given by the URL's file extension.
+fn plain_text(url: &str, content: &str) -> String {
+    match common::source_language(url) {
+        Some(language) => common::fenced_code_block(content, language),
+        None => content.to_string(),
+    }
+}
+
 bindings::export!(Component with_types_in bindings);
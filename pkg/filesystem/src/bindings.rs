@@ -1,6 +1,153 @@
 // Generated by `wit-bindgen` 0.41.0. DO NOT EDIT!
 // Options used:
 //   * runtime_path: "wit_bindgen_rt"
+/// The type of a file system entry.
+#[repr(u8)]
+#[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+pub enum FileKind {
+    File,
+    Directory,
+    Symlink,
+}
+impl ::core::fmt::Debug for FileKind {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        match self {
+            FileKind::File => f.debug_tuple("FileKind::File").finish(),
+            FileKind::Directory => f.debug_tuple("FileKind::Directory").finish(),
+            FileKind::Symlink => f.debug_tuple("FileKind::Symlink").finish(),
+        }
+    }
+}
+impl FileKind {
+    #[doc(hidden)]
+    pub unsafe fn _lift(val: u8) -> FileKind {
+        if !cfg!(debug_assertions) {
+            return ::core::mem::transmute(val);
+        }
+        match val {
+            0 => FileKind::File,
+            1 => FileKind::Directory,
+            2 => FileKind::Symlink,
+            _ => panic!("invalid enum discriminant"),
+        }
+    }
+}
+/// Metadata about a file, directory or symbolic link. Symbolic links are described
+/// themselves rather than the file they point to.
+#[derive(Clone)]
+pub struct FileInfo {
+    /// Absolute path of the entry.
+    pub path: _rt::String,
+    pub kind: FileKind,
+    /// Size in bytes.
+    pub size: u64,
+    /// RFC 3339 timestamps in UTC, absent where the platform does not record them.
+    pub created: Option<_rt::String>,
+    pub modified: Option<_rt::String>,
+    pub accessed: Option<_rt::String>,
+    pub readonly: bool,
+    /// Target of a symbolic link, as stored in the link.
+    pub symlink_target: Option<_rt::String>,
+}
+impl ::core::fmt::Debug for FileInfo {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct("FileInfo")
+            .field("path", &self.path)
+            .field("kind", &self.kind)
+            .field("size", &self.size)
+            .field("created", &self.created)
+            .field("modified", &self.modified)
+            .field("accessed", &self.accessed)
+            .field("readonly", &self.readonly)
+            .field("symlink-target", &self.symlink_target)
+            .finish()
+    }
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct LineWindow {
+    pub offset: u32,
+    pub limit: Option<u32>,
+}
+impl ::core::fmt::Debug for LineWindow {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct("LineWindow")
+            .field("offset", &self.offset)
+            .field("limit", &self.limit)
+            .finish()
+    }
+}
+/// Lines to read with `read-file-range`.
+#[derive(Clone, Copy)]
+pub enum LineRange {
+    /// Skip `offset` lines, then read up to `limit` lines, or all the rest.
+    Lines(LineWindow),
+    /// The first lines of the file.
+    Head(u32),
+    /// The last lines of the file.
+    Tail(u32),
+}
+impl ::core::fmt::Debug for LineRange {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        match self {
+            LineRange::Lines(e) => f.debug_tuple("LineRange::Lines").field(e).finish(),
+            LineRange::Head(e) => f.debug_tuple("LineRange::Head").field(e).finish(),
+            LineRange::Tail(e) => f.debug_tuple("LineRange::Tail").field(e).finish(),
+        }
+    }
+}
+/// Bytes read by `read-bytes`.
+#[derive(Clone)]
+pub struct FileBytes {
+    /// The bytes read, base64 encoded.
+    pub data: _rt::String,
+    /// Size of the whole file in bytes.
+    pub total_size: u64,
+}
+impl ::core::fmt::Debug for FileBytes {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct("FileBytes")
+            .field("data", &self.data)
+            .field("total-size", &self.total_size)
+            .finish()
+    }
+}
+/// A line found by `grep`.
+#[derive(Clone)]
+pub struct GrepMatch {
+    pub path: _rt::String,
+    /// 1-based line number.
+    pub line_number: u32,
+    pub line: _rt::String,
+    pub context_before: _rt::Vec<_rt::String>,
+    pub context_after: _rt::Vec<_rt::String>,
+}
+impl ::core::fmt::Debug for GrepMatch {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct("GrepMatch")
+            .field("path", &self.path)
+            .field("line-number", &self.line_number)
+            .field("line", &self.line)
+            .field("context-before", &self.context_before)
+            .field("context-after", &self.context_after)
+            .finish()
+    }
+}
+/// A replacement made by `edit-file`.
+#[derive(Clone)]
+pub struct Edit {
+    /// Text to replace, which must occur exactly once in the file.
+    pub old_text: _rt::String,
+    pub new_text: _rt::String,
+}
+impl ::core::fmt::Debug for Edit {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct("Edit")
+            .field("old-text", &self.old_text)
+            .field("new-text", &self.new_text)
+            .finish()
+    }
+}
 #[doc(hidden)]
 #[allow(non_snake_case)]
 pub unsafe fn _export_list_directory_cabi<T: Guest>(arg0: *mut u8, arg1: usize) -> *mut u8 {
@@ -171,19 +318,44 @@ pub unsafe fn __post_return_read_file<T: Guest>(arg0: *mut u8) {
 }
 #[doc(hidden)]
 #[allow(non_snake_case)]
-pub unsafe fn _export_search_file_cabi<T: Guest>(
+pub unsafe fn _export_read_file_range_cabi<T: Guest>(
     arg0: *mut u8,
     arg1: usize,
-    arg2: *mut u8,
-    arg3: usize,
+    arg2: i32,
+    arg3: i32,
+    arg4: i32,
+    arg5: i32,
 ) -> *mut u8 {
     #[cfg(target_arch = "wasm32")]
     _rt::run_ctors_once();
     let len0 = arg1;
     let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
-    let len1 = arg3;
-    let bytes1 = _rt::Vec::from_raw_parts(arg2.cast(), len1, len1);
-    let result2 = T::search_file(_rt::string_lift(bytes0), _rt::string_lift(bytes1));
+    let v1 = match arg2 {
+        0 => {
+            let e1 = LineWindow {
+                offset: arg3 as u32,
+                limit: match arg4 {
+                    0 => None,
+                    1 => {
+                        let e = arg5 as u32;
+                        Some(e)
+                    }
+                    _ => _rt::invalid_enum_discriminant(),
+                },
+            };
+            LineRange::Lines(e1)
+        }
+        1 => {
+            let e1 = arg3 as u32;
+            LineRange::Head(e1)
+        }
+        n => {
+            debug_assert_eq!(n, 2, "invalid enum discriminant");
+            let e1 = arg3 as u32;
+            LineRange::Tail(e1)
+        }
+    };
+    let result2 = T::read_file_range(_rt::string_lift(bytes0), v1);
     let ptr3 = (&raw mut _RET_AREA.0).cast::<u8>();
     match result2 {
         Ok(e) => {
@@ -217,7 +389,7 @@ pub unsafe fn _export_search_file_cabi<T: Guest>(
 }
 #[doc(hidden)]
 #[allow(non_snake_case)]
-pub unsafe fn __post_return_search_file<T: Guest>(arg0: *mut u8) {
+pub unsafe fn __post_return_read_file_range<T: Guest>(arg0: *mut u8) {
     let l0 = i32::from(*arg0.add(0).cast::<u8>());
     match l0 {
         0 => {
@@ -242,46 +414,192 @@ pub unsafe fn __post_return_search_file<T: Guest>(arg0: *mut u8) {
 }
 #[doc(hidden)]
 #[allow(non_snake_case)]
-pub unsafe fn _export_get_file_info_cabi<T: Guest>(arg0: *mut u8, arg1: usize) -> *mut u8 {
+pub unsafe fn _export_read_bytes_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+    arg2: i64,
+    arg3: i32,
+) -> *mut u8 {
     #[cfg(target_arch = "wasm32")]
     _rt::run_ctors_once();
     let len0 = arg1;
     let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
-    let result1 = T::get_file_info(_rt::string_lift(bytes0));
+    let result1 = T::read_bytes(_rt::string_lift(bytes0), arg2 as u64, arg3 as u32);
     let ptr2 = (&raw mut _RET_AREA.0).cast::<u8>();
     match result1 {
         Ok(e) => {
             *ptr2.add(0).cast::<u8>() = (0i32) as u8;
-            let vec3 = (e.into_bytes()).into_boxed_slice();
-            let ptr3 = vec3.as_ptr().cast::<u8>();
-            let len3 = vec3.len();
-            ::core::mem::forget(vec3);
+            let FileBytes {
+                data: data3,
+                total_size: total_size3,
+            } = e;
+            let vec4 = (data3.into_bytes()).into_boxed_slice();
+            let ptr4 = vec4.as_ptr().cast::<u8>();
+            let len4 = vec4.len();
+            ::core::mem::forget(vec4);
             *ptr2
-                .add(2 * ::core::mem::size_of::<*const u8>())
-                .cast::<usize>() = len3;
+                .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>() = len4;
+            *ptr2.add(8).cast::<*mut u8>() = ptr4.cast_mut();
             *ptr2
-                .add(::core::mem::size_of::<*const u8>())
-                .cast::<*mut u8>() = ptr3.cast_mut();
+                .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                .cast::<i64>() = _rt::as_i64(total_size3);
         }
         Err(e) => {
             *ptr2.add(0).cast::<u8>() = (1i32) as u8;
-            let vec4 = (e.into_bytes()).into_boxed_slice();
-            let ptr4 = vec4.as_ptr().cast::<u8>();
-            let len4 = vec4.len();
-            ::core::mem::forget(vec4);
+            let vec5 = (e.into_bytes()).into_boxed_slice();
+            let ptr5 = vec5.as_ptr().cast::<u8>();
+            let len5 = vec5.len();
+            ::core::mem::forget(vec5);
             *ptr2
+                .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>() = len5;
+            *ptr2.add(8).cast::<*mut u8>() = ptr5.cast_mut();
+        }
+    };
+    ptr2
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_read_bytes<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(8).cast::<*mut u8>();
+            let l2 = *arg0
+                .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+        _ => {
+            let l3 = *arg0.add(8).cast::<*mut u8>();
+            let l4 = *arg0
+                .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            _rt::cabi_dealloc(l3, l4, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_search_file_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+    arg2: *mut u8,
+    arg3: usize,
+    arg4: *mut u8,
+    arg5: usize,
+    arg6: i32,
+    arg7: i32,
+    arg8: i32,
+    arg9: i32,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let len1 = arg3;
+    let bytes1 = _rt::Vec::from_raw_parts(arg2.cast(), len1, len1);
+    let base5 = arg4;
+    let len5 = arg5;
+    let mut result5 = _rt::Vec::with_capacity(len5);
+    for i in 0..len5 {
+        let base = base5.add(i * (2 * ::core::mem::size_of::<*const u8>()));
+        let e5 = {
+            let l2 = *base.add(0).cast::<*mut u8>();
+            let l3 = *base
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            let len4 = l3;
+            let bytes4 = _rt::Vec::from_raw_parts(l2.cast(), len4, len4);
+            _rt::string_lift(bytes4)
+        };
+        result5.push(e5);
+    }
+    _rt::cabi_dealloc(
+        base5,
+        len5 * (2 * ::core::mem::size_of::<*const u8>()),
+        ::core::mem::size_of::<*const u8>(),
+    );
+    let result6 = T::search_file(
+        _rt::string_lift(bytes0),
+        _rt::string_lift(bytes1),
+        result5,
+        match arg6 {
+            0 => None,
+            1 => {
+                let e = arg7 as u32;
+                Some(e)
+            }
+            _ => _rt::invalid_enum_discriminant(),
+        },
+        match arg8 {
+            0 => None,
+            1 => {
+                let e = arg9 as u32;
+                Some(e)
+            }
+            _ => _rt::invalid_enum_discriminant(),
+        },
+    );
+    let ptr7 = (&raw mut _RET_AREA.0).cast::<u8>();
+    match result6 {
+        Ok(e) => {
+            *ptr7.add(0).cast::<u8>() = (0i32) as u8;
+            let vec9 = e;
+            let len9 = vec9.len();
+            let layout9 = _rt::alloc::Layout::from_size_align_unchecked(
+                vec9.len() * (2 * ::core::mem::size_of::<*const u8>()),
+                ::core::mem::size_of::<*const u8>(),
+            );
+            let result9 = if layout9.size() != 0 {
+                let ptr = _rt::alloc::alloc(layout9).cast::<u8>();
+                if ptr.is_null() {
+                    _rt::alloc::handle_alloc_error(layout9);
+                }
+                ptr
+            } else {
+                ::core::ptr::null_mut()
+            };
+            for (i, e) in vec9.into_iter().enumerate() {
+                let base = result9.add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                {
+                    let vec8 = (e.into_bytes()).into_boxed_slice();
+                    let ptr8 = vec8.as_ptr().cast::<u8>();
+                    let len8 = vec8.len();
+                    ::core::mem::forget(vec8);
+                    *base
+                        .add(::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len8;
+                    *base.add(0).cast::<*mut u8>() = ptr8.cast_mut();
+                }
+            }
+            *ptr7
                 .add(2 * ::core::mem::size_of::<*const u8>())
-                .cast::<usize>() = len4;
-            *ptr2
+                .cast::<usize>() = len9;
+            *ptr7
                 .add(::core::mem::size_of::<*const u8>())
-                .cast::<*mut u8>() = ptr4.cast_mut();
+                .cast::<*mut u8>() = result9;
+        }
+        Err(e) => {
+            *ptr7.add(0).cast::<u8>() = (1i32) as u8;
+            let vec10 = (e.into_bytes()).into_boxed_slice();
+            let ptr10 = vec10.as_ptr().cast::<u8>();
+            let len10 = vec10.len();
+            ::core::mem::forget(vec10);
+            *ptr7
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>() = len10;
+            *ptr7
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>() = ptr10.cast_mut();
         }
     };
-    ptr2
+    ptr7
 }
 #[doc(hidden)]
 #[allow(non_snake_case)]
-pub unsafe fn __post_return_get_file_info<T: Guest>(arg0: *mut u8) {
+pub unsafe fn __post_return_search_file<T: Guest>(arg0: *mut u8) {
     let l0 = i32::from(*arg0.add(0).cast::<u8>());
     match l0 {
         0 => {
@@ -291,91 +609,1552 @@ pub unsafe fn __post_return_get_file_info<T: Guest>(arg0: *mut u8) {
             let l2 = *arg0
                 .add(2 * ::core::mem::size_of::<*const u8>())
                 .cast::<usize>();
-            _rt::cabi_dealloc(l1, l2, 1);
+            let base5 = l1;
+            let len5 = l2;
+            for i in 0..len5 {
+                let base = base5.add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                {
+                    let l3 = *base.add(0).cast::<*mut u8>();
+                    let l4 = *base
+                        .add(::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l3, l4, 1);
+                }
+            }
+            _rt::cabi_dealloc(
+                base5,
+                len5 * (2 * ::core::mem::size_of::<*const u8>()),
+                ::core::mem::size_of::<*const u8>(),
+            );
         }
         _ => {
-            let l3 = *arg0
+            let l6 = *arg0
                 .add(::core::mem::size_of::<*const u8>())
                 .cast::<*mut u8>();
-            let l4 = *arg0
+            let l7 = *arg0
                 .add(2 * ::core::mem::size_of::<*const u8>())
                 .cast::<usize>();
-            _rt::cabi_dealloc(l3, l4, 1);
+            _rt::cabi_dealloc(l6, l7, 1);
         }
     }
 }
-pub trait Guest {
-    /// Get a detailed listing of all files and directories in a specified path.
-    /// Results clearly distinguish between files and directories with [FILE] and [DIR] \
-    /// prefixes. This tool is essential for understanding directory structure and \
-    /// finding specific files within a directory. Only works within allowed directories.
-    fn list_directory(path: _rt::String) -> Result<_rt::Vec<_rt::String>, _rt::String>;
-    /// Read the complete contents of a file from the file system.
-    fn read_file(path: _rt::String) -> Result<_rt::String, _rt::String>;
-    /// Recursively search for files and directories matching a pattern.
-    fn search_file(path: _rt::String, pattern: _rt::String) -> Result<_rt::String, _rt::String>;
-    /// Retrieve detailed metadata about a file or directory.
-    fn get_file_info(path: _rt::String) -> Result<_rt::String, _rt::String>;
-}
 #[doc(hidden)]
-macro_rules! __export_world_fs_cabi {
-    ($ty:ident with_types_in $($path_to_types:tt)*) => {
-        const _ : () = { #[unsafe (export_name = "list-directory")] unsafe extern "C" fn
-        export_list_directory(arg0 : * mut u8, arg1 : usize,) -> * mut u8 { unsafe {
-        $($path_to_types)*:: _export_list_directory_cabi::<$ty > (arg0, arg1) } }
-        #[unsafe (export_name = "cabi_post_list-directory")] unsafe extern "C" fn
-        _post_return_list_directory(arg0 : * mut u8,) { unsafe { $($path_to_types)*::
-        __post_return_list_directory::<$ty > (arg0) } } #[unsafe (export_name =
-        "read-file")] unsafe extern "C" fn export_read_file(arg0 : * mut u8, arg1 :
-        usize,) -> * mut u8 { unsafe { $($path_to_types)*:: _export_read_file_cabi::<$ty
-        > (arg0, arg1) } } #[unsafe (export_name = "cabi_post_read-file")] unsafe extern
-        "C" fn _post_return_read_file(arg0 : * mut u8,) { unsafe { $($path_to_types)*::
-        __post_return_read_file::<$ty > (arg0) } } #[unsafe (export_name =
-        "search-file")] unsafe extern "C" fn export_search_file(arg0 : * mut u8, arg1 :
-        usize, arg2 : * mut u8, arg3 : usize,) -> * mut u8 { unsafe {
-        $($path_to_types)*:: _export_search_file_cabi::<$ty > (arg0, arg1, arg2, arg3) }
-        } #[unsafe (export_name = "cabi_post_search-file")] unsafe extern "C" fn
-        _post_return_search_file(arg0 : * mut u8,) { unsafe { $($path_to_types)*::
-        __post_return_search_file::<$ty > (arg0) } } #[unsafe (export_name =
-        "get-file-info")] unsafe extern "C" fn export_get_file_info(arg0 : * mut u8, arg1
-        : usize,) -> * mut u8 { unsafe { $($path_to_types)*::
-        _export_get_file_info_cabi::<$ty > (arg0, arg1) } } #[unsafe (export_name =
-        "cabi_post_get-file-info")] unsafe extern "C" fn _post_return_get_file_info(arg0
-        : * mut u8,) { unsafe { $($path_to_types)*:: __post_return_get_file_info::<$ty >
-        (arg0) } } };
-    };
-}
-#[doc(hidden)]
-pub(crate) use __export_world_fs_cabi;
-#[cfg_attr(target_pointer_width = "64", repr(align(8)))]
-#[cfg_attr(target_pointer_width = "32", repr(align(4)))]
-struct _RetArea([::core::mem::MaybeUninit<u8>; 3 * ::core::mem::size_of::<*const u8>()]);
-static mut _RET_AREA: _RetArea =
-    _RetArea([::core::mem::MaybeUninit::uninit(); 3 * ::core::mem::size_of::<*const u8>()]);
-#[rustfmt::skip]
-mod _rt {
-    #![allow(dead_code, clippy::all)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_grep_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+    arg2: *mut u8,
+    arg3: usize,
+    arg4: *mut u8,
+    arg5: usize,
+    arg6: i32,
+    arg7: i32,
+    arg8: i32,
+    arg9: i32,
+) -> *mut u8 {
     #[cfg(target_arch = "wasm32")]
-    pub fn run_ctors_once() {
-        wit_bindgen_rt::run_ctors_once();
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let len1 = arg3;
+    let bytes1 = _rt::Vec::from_raw_parts(arg2.cast(), len1, len1);
+    let base5 = arg4;
+    let len5 = arg5;
+    let mut result5 = _rt::Vec::with_capacity(len5);
+    for i in 0..len5 {
+        let base = base5.add(i * (2 * ::core::mem::size_of::<*const u8>()));
+        let e5 = {
+            let l2 = *base.add(0).cast::<*mut u8>();
+            let l3 = *base
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            let len4 = l3;
+            let bytes4 = _rt::Vec::from_raw_parts(l2.cast(), len4, len4);
+            _rt::string_lift(bytes4)
+        };
+        result5.push(e5);
     }
-    pub use alloc_crate::vec::Vec;
-    pub unsafe fn string_lift(bytes: Vec<u8>) -> String {
-        if cfg!(debug_assertions) {
-            String::from_utf8(bytes).unwrap()
-        } else {
-            String::from_utf8_unchecked(bytes)
+    _rt::cabi_dealloc(
+        base5,
+        len5 * (2 * ::core::mem::size_of::<*const u8>()),
+        ::core::mem::size_of::<*const u8>(),
+    );
+    let result6 = T::grep(
+        _rt::string_lift(bytes0),
+        _rt::string_lift(bytes1),
+        result5,
+        _rt::bool_lift(arg6 as u8),
+        arg7 as u32,
+        match arg8 {
+            0 => None,
+            1 => {
+                let e = arg9 as u32;
+                Some(e)
+            }
+            _ => _rt::invalid_enum_discriminant(),
+        },
+    );
+    let ptr7 = (&raw mut _RET_AREA.0).cast::<u8>();
+    match result6 {
+        Ok(e) => {
+            *ptr7.add(0).cast::<u8>() = (0i32) as u8;
+            let vec15 = e;
+            let len15 = vec15.len();
+            let layout15 = _rt::alloc::Layout::from_size_align_unchecked(
+                vec15.len() * (9 * ::core::mem::size_of::<*const u8>()),
+                ::core::mem::size_of::<*const u8>(),
+            );
+            let result15 = if layout15.size() != 0 {
+                let ptr = _rt::alloc::alloc(layout15).cast::<u8>();
+                if ptr.is_null() {
+                    _rt::alloc::handle_alloc_error(layout15);
+                }
+                ptr
+            } else {
+                ::core::ptr::null_mut()
+            };
+            for (i, e) in vec15.into_iter().enumerate() {
+                let base = result15.add(i * (9 * ::core::mem::size_of::<*const u8>()));
+                {
+                    let GrepMatch {
+                        path: path8,
+                        line_number: line_number8,
+                        line: line8,
+                        context_before: context_before8,
+                        context_after: context_after8,
+                    } = e;
+                    let vec9 = (path8.into_bytes()).into_boxed_slice();
+                    let ptr9 = vec9.as_ptr().cast::<u8>();
+                    let len9 = vec9.len();
+                    ::core::mem::forget(vec9);
+                    *base
+                        .add(::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len9;
+                    *base.add(0).cast::<*mut u8>() = ptr9.cast_mut();
+                    *base
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(line_number8);
+                    let vec10 = (line8.into_bytes()).into_boxed_slice();
+                    let ptr10 = vec10.as_ptr().cast::<u8>();
+                    let len10 = vec10.len();
+                    ::core::mem::forget(vec10);
+                    *base
+                        .add(4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len10;
+                    *base
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr10.cast_mut();
+                    let vec12 = context_before8;
+                    let len12 = vec12.len();
+                    let layout12 = _rt::alloc::Layout::from_size_align_unchecked(
+                        vec12.len() * (2 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result12 = if layout12.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout12).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout12);
+                        }
+                        ptr
+                    } else {
+                        ::core::ptr::null_mut()
+                    };
+                    for (i, e) in vec12.into_iter().enumerate() {
+                        let base = result12.add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                        {
+                            let vec11 = (e.into_bytes()).into_boxed_slice();
+                            let ptr11 = vec11.as_ptr().cast::<u8>();
+                            let len11 = vec11.len();
+                            ::core::mem::forget(vec11);
+                            *base
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len11;
+                            *base.add(0).cast::<*mut u8>() = ptr11.cast_mut();
+                        }
+                    }
+                    *base
+                        .add(6 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len12;
+                    *base
+                        .add(5 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = result12;
+                    let vec14 = context_after8;
+                    let len14 = vec14.len();
+                    let layout14 = _rt::alloc::Layout::from_size_align_unchecked(
+                        vec14.len() * (2 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result14 = if layout14.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout14).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout14);
+                        }
+                        ptr
+                    } else {
+                        ::core::ptr::null_mut()
+                    };
+                    for (i, e) in vec14.into_iter().enumerate() {
+                        let base = result14.add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                        {
+                            let vec13 = (e.into_bytes()).into_boxed_slice();
+                            let ptr13 = vec13.as_ptr().cast::<u8>();
+                            let len13 = vec13.len();
+                            ::core::mem::forget(vec13);
+                            *base
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len13;
+                            *base.add(0).cast::<*mut u8>() = ptr13.cast_mut();
+                        }
+                    }
+                    *base
+                        .add(8 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len14;
+                    *base
+                        .add(7 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = result14;
+                }
+            }
+            *ptr7
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>() = len15;
+            *ptr7
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>() = result15;
         }
-    }
-    pub use alloc_crate::alloc;
-    pub unsafe fn cabi_dealloc(ptr: *mut u8, size: usize, align: usize) {
-        if size == 0 {
-            return;
+        Err(e) => {
+            *ptr7.add(0).cast::<u8>() = (1i32) as u8;
+            let vec16 = (e.into_bytes()).into_boxed_slice();
+            let ptr16 = vec16.as_ptr().cast::<u8>();
+            let len16 = vec16.len();
+            ::core::mem::forget(vec16);
+            *ptr7
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>() = len16;
+            *ptr7
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>() = ptr16.cast_mut();
         }
-        let layout = alloc::Layout::from_size_align_unchecked(size, align);
-        alloc::dealloc(ptr, layout);
-    }
-    pub use alloc_crate::string::String;
+    };
+    ptr7
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_grep<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>();
+            let l2 = *arg0
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            let base17 = l1;
+            let len17 = l2;
+            for i in 0..len17 {
+                let base = base17.add(i * (9 * ::core::mem::size_of::<*const u8>()));
+                {
+                    let l3 = *base.add(0).cast::<*mut u8>();
+                    let l4 = *base
+                        .add(::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l3, l4, 1);
+                    let l5 = *base
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l6 = *base
+                        .add(4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l5, l6, 1);
+                    let l7 = *base
+                        .add(5 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l8 = *base
+                        .add(6 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base11 = l7;
+                    let len11 = l8;
+                    for i in 0..len11 {
+                        let base = base11.add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                        {
+                            let l9 = *base.add(0).cast::<*mut u8>();
+                            let l10 = *base
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            _rt::cabi_dealloc(l9, l10, 1);
+                        }
+                    }
+                    _rt::cabi_dealloc(
+                        base11,
+                        len11 * (2 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let l12 = *base
+                        .add(7 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l13 = *base
+                        .add(8 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base16 = l12;
+                    let len16 = l13;
+                    for i in 0..len16 {
+                        let base = base16.add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                        {
+                            let l14 = *base.add(0).cast::<*mut u8>();
+                            let l15 = *base
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            _rt::cabi_dealloc(l14, l15, 1);
+                        }
+                    }
+                    _rt::cabi_dealloc(
+                        base16,
+                        len16 * (2 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                }
+            }
+            _rt::cabi_dealloc(
+                base17,
+                len17 * (9 * ::core::mem::size_of::<*const u8>()),
+                ::core::mem::size_of::<*const u8>(),
+            );
+        }
+        _ => {
+            let l18 = *arg0
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>();
+            let l19 = *arg0
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            _rt::cabi_dealloc(l18, l19, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_get_file_info_cabi<T: Guest>(arg0: *mut u8, arg1: usize) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let result1 = T::get_file_info(_rt::string_lift(bytes0));
+    let ptr2 = (&raw mut _RET_AREA.0).cast::<u8>();
+    match result1 {
+        Ok(e) => {
+            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+            let FileInfo {
+                path: path3,
+                kind: kind3,
+                size: size3,
+                created: created3,
+                modified: modified3,
+                accessed: accessed3,
+                readonly: readonly3,
+                symlink_target: symlink_target3,
+            } = e;
+            let vec4 = (path3.into_bytes()).into_boxed_slice();
+            let ptr4 = vec4.as_ptr().cast::<u8>();
+            let len4 = vec4.len();
+            ::core::mem::forget(vec4);
+            *ptr2
+                .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>() = len4;
+            *ptr2.add(8).cast::<*mut u8>() = ptr4.cast_mut();
+            *ptr2
+                .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                .cast::<u8>() = (kind3.clone() as i32) as u8;
+            *ptr2
+                .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                .cast::<i64>() = _rt::as_i64(size3);
+            match created3 {
+                Some(e) => {
+                    *ptr2
+                        .add(24 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<u8>() = (1i32) as u8;
+                    let vec5 = (e.into_bytes()).into_boxed_slice();
+                    let ptr5 = vec5.as_ptr().cast::<u8>();
+                    let len5 = vec5.len();
+                    ::core::mem::forget(vec5);
+                    *ptr2
+                        .add(24 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len5;
+                    *ptr2
+                        .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr5.cast_mut();
+                }
+                None => {
+                    *ptr2
+                        .add(24 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<u8>() = (0i32) as u8;
+                }
+            };
+            match modified3 {
+                Some(e) => {
+                    *ptr2
+                        .add(24 + 5 * ::core::mem::size_of::<*const u8>())
+                        .cast::<u8>() = (1i32) as u8;
+                    let vec6 = (e.into_bytes()).into_boxed_slice();
+                    let ptr6 = vec6.as_ptr().cast::<u8>();
+                    let len6 = vec6.len();
+                    ::core::mem::forget(vec6);
+                    *ptr2
+                        .add(24 + 7 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len6;
+                    *ptr2
+                        .add(24 + 6 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr6.cast_mut();
+                }
+                None => {
+                    *ptr2
+                        .add(24 + 5 * ::core::mem::size_of::<*const u8>())
+                        .cast::<u8>() = (0i32) as u8;
+                }
+            };
+            match accessed3 {
+                Some(e) => {
+                    *ptr2
+                        .add(24 + 8 * ::core::mem::size_of::<*const u8>())
+                        .cast::<u8>() = (1i32) as u8;
+                    let vec7 = (e.into_bytes()).into_boxed_slice();
+                    let ptr7 = vec7.as_ptr().cast::<u8>();
+                    let len7 = vec7.len();
+                    ::core::mem::forget(vec7);
+                    *ptr2
+                        .add(24 + 10 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len7;
+                    *ptr2
+                        .add(24 + 9 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr7.cast_mut();
+                }
+                None => {
+                    *ptr2
+                        .add(24 + 8 * ::core::mem::size_of::<*const u8>())
+                        .cast::<u8>() = (0i32) as u8;
+                }
+            };
+            *ptr2
+                .add(24 + 11 * ::core::mem::size_of::<*const u8>())
+                .cast::<u8>() = (match readonly3 {
+                true => 1,
+                false => 0,
+            }) as u8;
+            match symlink_target3 {
+                Some(e) => {
+                    *ptr2
+                        .add(24 + 12 * ::core::mem::size_of::<*const u8>())
+                        .cast::<u8>() = (1i32) as u8;
+                    let vec8 = (e.into_bytes()).into_boxed_slice();
+                    let ptr8 = vec8.as_ptr().cast::<u8>();
+                    let len8 = vec8.len();
+                    ::core::mem::forget(vec8);
+                    *ptr2
+                        .add(24 + 14 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len8;
+                    *ptr2
+                        .add(24 + 13 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr8.cast_mut();
+                }
+                None => {
+                    *ptr2
+                        .add(24 + 12 * ::core::mem::size_of::<*const u8>())
+                        .cast::<u8>() = (0i32) as u8;
+                }
+            };
+        }
+        Err(e) => {
+            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+            let vec9 = (e.into_bytes()).into_boxed_slice();
+            let ptr9 = vec9.as_ptr().cast::<u8>();
+            let len9 = vec9.len();
+            ::core::mem::forget(vec9);
+            *ptr2
+                .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>() = len9;
+            *ptr2.add(8).cast::<*mut u8>() = ptr9.cast_mut();
+        }
+    };
+    ptr2
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_get_file_info<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(8).cast::<*mut u8>();
+            let l2 = *arg0
+                .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+            let l3 = i32::from(
+                *arg0
+                    .add(24 + 2 * ::core::mem::size_of::<*const u8>())
+                    .cast::<u8>(),
+            );
+            match l3 {
+                0 => {}
+                _ => {
+                    let l4 = *arg0
+                        .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l5 = *arg0
+                        .add(24 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l4, l5, 1);
+                }
+            }
+            let l6 = i32::from(
+                *arg0
+                    .add(24 + 5 * ::core::mem::size_of::<*const u8>())
+                    .cast::<u8>(),
+            );
+            match l6 {
+                0 => {}
+                _ => {
+                    let l7 = *arg0
+                        .add(24 + 6 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l8 = *arg0
+                        .add(24 + 7 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l7, l8, 1);
+                }
+            }
+            let l9 = i32::from(
+                *arg0
+                    .add(24 + 8 * ::core::mem::size_of::<*const u8>())
+                    .cast::<u8>(),
+            );
+            match l9 {
+                0 => {}
+                _ => {
+                    let l10 = *arg0
+                        .add(24 + 9 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l11 = *arg0
+                        .add(24 + 10 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l10, l11, 1);
+                }
+            }
+            let l12 = i32::from(
+                *arg0
+                    .add(24 + 12 * ::core::mem::size_of::<*const u8>())
+                    .cast::<u8>(),
+            );
+            match l12 {
+                0 => {}
+                _ => {
+                    let l13 = *arg0
+                        .add(24 + 13 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l14 = *arg0
+                        .add(24 + 14 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l13, l14, 1);
+                }
+            }
+        }
+        _ => {
+            let l15 = *arg0.add(8).cast::<*mut u8>();
+            let l16 = *arg0
+                .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            _rt::cabi_dealloc(l15, l16, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_list_directory_detailed_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let result1 = T::list_directory_detailed(_rt::string_lift(bytes0));
+    let ptr2 = (&raw mut _RET_AREA.0).cast::<u8>();
+    match result1 {
+        Ok(e) => {
+            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+            let vec9 = e;
+            let len9 = vec9.len();
+            let layout9 = _rt::alloc::Layout::from_size_align_unchecked(
+                vec9.len() * (24 + 14 * ::core::mem::size_of::<*const u8>()),
+                8,
+            );
+            let result9 = if layout9.size() != 0 {
+                let ptr = _rt::alloc::alloc(layout9).cast::<u8>();
+                if ptr.is_null() {
+                    _rt::alloc::handle_alloc_error(layout9);
+                }
+                ptr
+            } else {
+                ::core::ptr::null_mut()
+            };
+            for (i, e) in vec9.into_iter().enumerate() {
+                let base = result9.add(i * (24 + 14 * ::core::mem::size_of::<*const u8>()));
+                {
+                    let FileInfo {
+                        path: path3,
+                        kind: kind3,
+                        size: size3,
+                        created: created3,
+                        modified: modified3,
+                        accessed: accessed3,
+                        readonly: readonly3,
+                        symlink_target: symlink_target3,
+                    } = e;
+                    let vec4 = (path3.into_bytes()).into_boxed_slice();
+                    let ptr4 = vec4.as_ptr().cast::<u8>();
+                    let len4 = vec4.len();
+                    ::core::mem::forget(vec4);
+                    *base
+                        .add(::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len4;
+                    *base.add(0).cast::<*mut u8>() = ptr4.cast_mut();
+                    *base
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<u8>() = (kind3.clone() as i32) as u8;
+                    *base
+                        .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i64>() = _rt::as_i64(size3);
+                    match created3 {
+                        Some(e) => {
+                            *base
+                                .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (1i32) as u8;
+                            let vec5 = (e.into_bytes()).into_boxed_slice();
+                            let ptr5 = vec5.as_ptr().cast::<u8>();
+                            let len5 = vec5.len();
+                            ::core::mem::forget(vec5);
+                            *base
+                                .add(16 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len5;
+                            *base
+                                .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>() = ptr5.cast_mut();
+                        }
+                        None => {
+                            *base
+                                .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                    match modified3 {
+                        Some(e) => {
+                            *base
+                                .add(16 + 5 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (1i32) as u8;
+                            let vec6 = (e.into_bytes()).into_boxed_slice();
+                            let ptr6 = vec6.as_ptr().cast::<u8>();
+                            let len6 = vec6.len();
+                            ::core::mem::forget(vec6);
+                            *base
+                                .add(16 + 7 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len6;
+                            *base
+                                .add(16 + 6 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>() = ptr6.cast_mut();
+                        }
+                        None => {
+                            *base
+                                .add(16 + 5 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                    match accessed3 {
+                        Some(e) => {
+                            *base
+                                .add(16 + 8 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (1i32) as u8;
+                            let vec7 = (e.into_bytes()).into_boxed_slice();
+                            let ptr7 = vec7.as_ptr().cast::<u8>();
+                            let len7 = vec7.len();
+                            ::core::mem::forget(vec7);
+                            *base
+                                .add(16 + 10 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len7;
+                            *base
+                                .add(16 + 9 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>() = ptr7.cast_mut();
+                        }
+                        None => {
+                            *base
+                                .add(16 + 8 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                    *base
+                        .add(16 + 11 * ::core::mem::size_of::<*const u8>())
+                        .cast::<u8>() = (match readonly3 {
+                        true => 1,
+                        false => 0,
+                    }) as u8;
+                    match symlink_target3 {
+                        Some(e) => {
+                            *base
+                                .add(16 + 12 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (1i32) as u8;
+                            let vec8 = (e.into_bytes()).into_boxed_slice();
+                            let ptr8 = vec8.as_ptr().cast::<u8>();
+                            let len8 = vec8.len();
+                            ::core::mem::forget(vec8);
+                            *base
+                                .add(16 + 14 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len8;
+                            *base
+                                .add(16 + 13 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>() = ptr8.cast_mut();
+                        }
+                        None => {
+                            *base
+                                .add(16 + 12 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                }
+            }
+            *ptr2
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>() = len9;
+            *ptr2
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>() = result9;
+        }
+        Err(e) => {
+            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+            let vec10 = (e.into_bytes()).into_boxed_slice();
+            let ptr10 = vec10.as_ptr().cast::<u8>();
+            let len10 = vec10.len();
+            ::core::mem::forget(vec10);
+            *ptr2
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>() = len10;
+            *ptr2
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>() = ptr10.cast_mut();
+        }
+    };
+    ptr2
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_list_directory_detailed<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>();
+            let l2 = *arg0
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            let base17 = l1;
+            let len17 = l2;
+            for i in 0..len17 {
+                let base = base17.add(i * (24 + 14 * ::core::mem::size_of::<*const u8>()));
+                {
+                    let l3 = *base.add(0).cast::<*mut u8>();
+                    let l4 = *base
+                        .add(::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l3, l4, 1);
+                    let l5 = i32::from(
+                        *base
+                            .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    match l5 {
+                        0 => {}
+                        _ => {
+                            let l6 = *base
+                                .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l7 = *base
+                                .add(16 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            _rt::cabi_dealloc(l6, l7, 1);
+                        }
+                    }
+                    let l8 = i32::from(
+                        *base
+                            .add(16 + 5 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    match l8 {
+                        0 => {}
+                        _ => {
+                            let l9 = *base
+                                .add(16 + 6 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l10 = *base
+                                .add(16 + 7 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            _rt::cabi_dealloc(l9, l10, 1);
+                        }
+                    }
+                    let l11 = i32::from(
+                        *base
+                            .add(16 + 8 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    match l11 {
+                        0 => {}
+                        _ => {
+                            let l12 = *base
+                                .add(16 + 9 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l13 = *base
+                                .add(16 + 10 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            _rt::cabi_dealloc(l12, l13, 1);
+                        }
+                    }
+                    let l14 = i32::from(
+                        *base
+                            .add(16 + 12 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    match l14 {
+                        0 => {}
+                        _ => {
+                            let l15 = *base
+                                .add(16 + 13 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l16 = *base
+                                .add(16 + 14 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            _rt::cabi_dealloc(l15, l16, 1);
+                        }
+                    }
+                }
+            }
+            _rt::cabi_dealloc(
+                base17,
+                len17 * (24 + 14 * ::core::mem::size_of::<*const u8>()),
+                8,
+            );
+        }
+        _ => {
+            let l18 = *arg0
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>();
+            let l19 = *arg0
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            _rt::cabi_dealloc(l18, l19, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_write_file_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+    arg2: *mut u8,
+    arg3: usize,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let len1 = arg3;
+    let bytes1 = _rt::Vec::from_raw_parts(arg2.cast(), len1, len1);
+    let result2 = T::write_file(_rt::string_lift(bytes0), _rt::string_lift(bytes1));
+    let ptr3 = (&raw mut _RET_AREA.0).cast::<u8>();
+    match result2 {
+        Ok(_) => {
+            *ptr3.add(0).cast::<u8>() = (0i32) as u8;
+        }
+        Err(e) => {
+            *ptr3.add(0).cast::<u8>() = (1i32) as u8;
+            let vec4 = (e.into_bytes()).into_boxed_slice();
+            let ptr4 = vec4.as_ptr().cast::<u8>();
+            let len4 = vec4.len();
+            ::core::mem::forget(vec4);
+            *ptr3
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>() = len4;
+            *ptr3
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>() = ptr4.cast_mut();
+        }
+    };
+    ptr3
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_write_file<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {}
+        _ => {
+            let l1 = *arg0
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>();
+            let l2 = *arg0
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_append_file_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+    arg2: *mut u8,
+    arg3: usize,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let len1 = arg3;
+    let bytes1 = _rt::Vec::from_raw_parts(arg2.cast(), len1, len1);
+    let result2 = T::append_file(_rt::string_lift(bytes0), _rt::string_lift(bytes1));
+    let ptr3 = (&raw mut _RET_AREA.0).cast::<u8>();
+    match result2 {
+        Ok(_) => {
+            *ptr3.add(0).cast::<u8>() = (0i32) as u8;
+        }
+        Err(e) => {
+            *ptr3.add(0).cast::<u8>() = (1i32) as u8;
+            let vec4 = (e.into_bytes()).into_boxed_slice();
+            let ptr4 = vec4.as_ptr().cast::<u8>();
+            let len4 = vec4.len();
+            ::core::mem::forget(vec4);
+            *ptr3
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>() = len4;
+            *ptr3
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>() = ptr4.cast_mut();
+        }
+    };
+    ptr3
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_append_file<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {}
+        _ => {
+            let l1 = *arg0
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>();
+            let l2 = *arg0
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_edit_file_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+    arg2: *mut u8,
+    arg3: usize,
+    arg4: i32,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let base7 = arg2;
+    let len7 = arg3;
+    let mut result7 = _rt::Vec::with_capacity(len7);
+    for i in 0..len7 {
+        let base = base7.add(i * (4 * ::core::mem::size_of::<*const u8>()));
+        let e7 = {
+            let l1 = *base.add(0).cast::<*mut u8>();
+            let l2 = *base
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            let len3 = l2;
+            let bytes3 = _rt::Vec::from_raw_parts(l1.cast(), len3, len3);
+            let l4 = *base
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>();
+            let l5 = *base
+                .add(3 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            let len6 = l5;
+            let bytes6 = _rt::Vec::from_raw_parts(l4.cast(), len6, len6);
+            Edit {
+                old_text: _rt::string_lift(bytes3),
+                new_text: _rt::string_lift(bytes6),
+            }
+        };
+        result7.push(e7);
+    }
+    _rt::cabi_dealloc(
+        base7,
+        len7 * (4 * ::core::mem::size_of::<*const u8>()),
+        ::core::mem::size_of::<*const u8>(),
+    );
+    let result8 = T::edit_file(
+        _rt::string_lift(bytes0),
+        result7,
+        _rt::bool_lift(arg4 as u8),
+    );
+    let ptr9 = (&raw mut _RET_AREA.0).cast::<u8>();
+    match result8 {
+        Ok(e) => {
+            *ptr9.add(0).cast::<u8>() = (0i32) as u8;
+            let vec10 = (e.into_bytes()).into_boxed_slice();
+            let ptr10 = vec10.as_ptr().cast::<u8>();
+            let len10 = vec10.len();
+            ::core::mem::forget(vec10);
+            *ptr9
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>() = len10;
+            *ptr9
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>() = ptr10.cast_mut();
+        }
+        Err(e) => {
+            *ptr9.add(0).cast::<u8>() = (1i32) as u8;
+            let vec11 = (e.into_bytes()).into_boxed_slice();
+            let ptr11 = vec11.as_ptr().cast::<u8>();
+            let len11 = vec11.len();
+            ::core::mem::forget(vec11);
+            *ptr9
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>() = len11;
+            *ptr9
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>() = ptr11.cast_mut();
+        }
+    };
+    ptr9
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_edit_file<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>();
+            let l2 = *arg0
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+        _ => {
+            let l3 = *arg0
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>();
+            let l4 = *arg0
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            _rt::cabi_dealloc(l3, l4, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_create_directory_cabi<T: Guest>(arg0: *mut u8, arg1: usize) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let result1 = T::create_directory(_rt::string_lift(bytes0));
+    let ptr2 = (&raw mut _RET_AREA.0).cast::<u8>();
+    match result1 {
+        Ok(_) => {
+            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+        }
+        Err(e) => {
+            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+            let vec3 = (e.into_bytes()).into_boxed_slice();
+            let ptr3 = vec3.as_ptr().cast::<u8>();
+            let len3 = vec3.len();
+            ::core::mem::forget(vec3);
+            *ptr2
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>() = len3;
+            *ptr2
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>() = ptr3.cast_mut();
+        }
+    };
+    ptr2
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_create_directory<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {}
+        _ => {
+            let l1 = *arg0
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>();
+            let l2 = *arg0
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_move_file_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+    arg2: *mut u8,
+    arg3: usize,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let len1 = arg3;
+    let bytes1 = _rt::Vec::from_raw_parts(arg2.cast(), len1, len1);
+    let result2 = T::move_file(_rt::string_lift(bytes0), _rt::string_lift(bytes1));
+    let ptr3 = (&raw mut _RET_AREA.0).cast::<u8>();
+    match result2 {
+        Ok(_) => {
+            *ptr3.add(0).cast::<u8>() = (0i32) as u8;
+        }
+        Err(e) => {
+            *ptr3.add(0).cast::<u8>() = (1i32) as u8;
+            let vec4 = (e.into_bytes()).into_boxed_slice();
+            let ptr4 = vec4.as_ptr().cast::<u8>();
+            let len4 = vec4.len();
+            ::core::mem::forget(vec4);
+            *ptr3
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>() = len4;
+            *ptr3
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>() = ptr4.cast_mut();
+        }
+    };
+    ptr3
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_move_file<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {}
+        _ => {
+            let l1 = *arg0
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>();
+            let l2 = *arg0
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_copy_file_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+    arg2: *mut u8,
+    arg3: usize,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let len1 = arg3;
+    let bytes1 = _rt::Vec::from_raw_parts(arg2.cast(), len1, len1);
+    let result2 = T::copy_file(_rt::string_lift(bytes0), _rt::string_lift(bytes1));
+    let ptr3 = (&raw mut _RET_AREA.0).cast::<u8>();
+    match result2 {
+        Ok(_) => {
+            *ptr3.add(0).cast::<u8>() = (0i32) as u8;
+        }
+        Err(e) => {
+            *ptr3.add(0).cast::<u8>() = (1i32) as u8;
+            let vec4 = (e.into_bytes()).into_boxed_slice();
+            let ptr4 = vec4.as_ptr().cast::<u8>();
+            let len4 = vec4.len();
+            ::core::mem::forget(vec4);
+            *ptr3
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>() = len4;
+            *ptr3
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>() = ptr4.cast_mut();
+        }
+    };
+    ptr3
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_copy_file<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {}
+        _ => {
+            let l1 = *arg0
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>();
+            let l2 = *arg0
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_delete_cabi<T: Guest>(arg0: *mut u8, arg1: usize, arg2: i32) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let result1 = T::delete(_rt::string_lift(bytes0), _rt::bool_lift(arg2 as u8));
+    let ptr2 = (&raw mut _RET_AREA.0).cast::<u8>();
+    match result1 {
+        Ok(_) => {
+            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+        }
+        Err(e) => {
+            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+            let vec3 = (e.into_bytes()).into_boxed_slice();
+            let ptr3 = vec3.as_ptr().cast::<u8>();
+            let len3 = vec3.len();
+            ::core::mem::forget(vec3);
+            *ptr2
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>() = len3;
+            *ptr2
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>() = ptr3.cast_mut();
+        }
+    };
+    ptr2
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_delete<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {}
+        _ => {
+            let l1 = *arg0
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>();
+            let l2 = *arg0
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+    }
+}
+pub trait Guest {
+    /// Get a detailed listing of all files and directories in a specified path.
+    /// Results clearly distinguish between files and directories with [FILE] and [DIR] \
+    /// prefixes. This tool is essential for understanding directory structure and \
+    /// finding specific files within a directory. Only works within allowed directories.
+    fn list_directory(path: _rt::String) -> Result<_rt::Vec<_rt::String>, _rt::String>;
+    /// Read the complete contents of a text file from the file system. Binary and
+    /// non-UTF-8 files are refused; use `read-bytes` for those. Files over 10 MiB are
+    /// refused too; use `read-file-range` to read them in parts.
+    fn read_file(path: _rt::String) -> Result<_rt::String, _rt::String>;
+    /// Read some lines of a text file without loading all of it, keeping their line
+    /// endings. Suited to large files such as logs.
+    fn read_file_range(path: _rt::String, range: LineRange) -> Result<_rt::String, _rt::String>;
+    /// Read up to `length` bytes starting at byte `offset`, base64 encoded. At most
+    /// 1 MiB is returned per call.
+    fn read_bytes(path: _rt::String, offset: u64, length: u32) -> Result<FileBytes, _rt::String>;
+    /// Recursively search for files and directories matching a pattern and return their
+    /// paths. The pattern is a glob such as `**/*.rs`, matched against paths relative to
+    /// `path` when it contains a `/` and against file names otherwise; plain text without
+    /// glob characters matches anywhere in a file name, ignoring case. Entries matching
+    /// an `exclude` glob such as `target/**` or `.git` are skipped with everything beneath
+    /// them. `max-depth` limits how many directory levels are searched (1 searches only
+    /// the entries of `path`; 0 is an error) and `max-results` defaults to 1000. Symbolic
+    /// links leading outside the allowed directories are left out, link cycles are
+    /// detected and not followed, and subdirectories that cannot be read are skipped.
+    fn search_file(
+        path: _rt::String,
+        pattern: _rt::String,
+        exclude: _rt::Vec<_rt::String>,
+        max_depth: Option<u32>,
+        max_results: Option<u32>,
+    ) -> Result<_rt::Vec<_rt::String>, _rt::String>;
+    /// Search file contents for a regular expression and return the matching lines. `path`
+    /// may be a file or a directory, which is searched recursively; `include` globs such
+    /// as `**/*.rs` limit the files searched. Binary files are skipped. `context` lines
+    /// before and after each match are included, and `max-matches` defaults to 200.
+    /// Symbolic links leading outside the allowed directories are not searched.
+    fn grep(
+        path: _rt::String,
+        pattern: _rt::String,
+        include: _rt::Vec<_rt::String>,
+        case_sensitive: bool,
+        context: u32,
+        max_matches: Option<u32>,
+    ) -> Result<_rt::Vec<GrepMatch>, _rt::String>;
+    /// Retrieve detailed metadata about a file, directory or symbolic link.
+    fn get_file_info(path: _rt::String) -> Result<FileInfo, _rt::String>;
+    /// Retrieve the metadata of every entry in a directory, sorted by path.
+    fn list_directory_detailed(path: _rt::String) -> Result<_rt::Vec<FileInfo>, _rt::String>;
+    /// Create a file or replace its contents. The parent directory must exist.
+    fn write_file(path: _rt::String, content: _rt::String) -> Result<(), _rt::String>;
+    /// Append to the end of a file, creating it if it does not exist.
+    fn append_file(path: _rt::String, content: _rt::String) -> Result<(), _rt::String>;
+    /// Make a list of text replacements in a file and return a unified diff of the change.
+    /// Edits are applied in order, each to the result of the previous one, and each
+    /// old text must match exactly once. With `dry-run` set the diff is returned and the
+    /// file is left unchanged.
+    fn edit_file(
+        path: _rt::String,
+        edits: _rt::Vec<Edit>,
+        dry_run: bool,
+    ) -> Result<_rt::String, _rt::String>;
+    /// Create a directory along with any missing parent directories.
+    fn create_directory(path: _rt::String) -> Result<(), _rt::String>;
+    /// Move or rename a file or directory. Fails if the destination already exists.
+    fn move_file(source: _rt::String, destination: _rt::String) -> Result<(), _rt::String>;
+    /// Copy a file. Fails if the destination already exists.
+    fn copy_file(source: _rt::String, destination: _rt::String) -> Result<(), _rt::String>;
+    /// Delete a file, symbolic link or empty directory. Set `recursive` to delete a
+    /// directory with all of its contents. Symbolic links are removed, not followed.
+    fn delete(path: _rt::String, recursive: bool) -> Result<(), _rt::String>;
+}
+#[doc(hidden)]
+macro_rules! __export_world_fs_cabi {
+    ($ty:ident with_types_in $($path_to_types:tt)*) => {
+        const _ : () = { #[unsafe (export_name = "list-directory")] unsafe extern "C" fn
+        export_list_directory(arg0 : * mut u8, arg1 : usize,) -> * mut u8 { unsafe {
+        $($path_to_types)*:: _export_list_directory_cabi::<$ty > (arg0, arg1) } }
+        #[unsafe (export_name = "cabi_post_list-directory")] unsafe extern "C" fn
+        _post_return_list_directory(arg0 : * mut u8,) { unsafe { $($path_to_types)*::
+        __post_return_list_directory::<$ty > (arg0) } } #[unsafe (export_name =
+        "read-file")] unsafe extern "C" fn export_read_file(arg0 : * mut u8, arg1 :
+        usize,) -> * mut u8 { unsafe { $($path_to_types)*:: _export_read_file_cabi::<$ty
+        > (arg0, arg1) } } #[unsafe (export_name = "cabi_post_read-file")] unsafe extern
+        "C" fn _post_return_read_file(arg0 : * mut u8,) { unsafe { $($path_to_types)*::
+        __post_return_read_file::<$ty > (arg0) } } #[unsafe (export_name =
+        "read-file-range")] unsafe extern "C" fn export_read_file_range(arg0 : * mut u8,
+        arg1 : usize, arg2 : i32, arg3 : i32, arg4 : i32, arg5 : i32,) -> * mut u8 {
+        unsafe { $($path_to_types)*:: _export_read_file_range_cabi::<$ty > (arg0, arg1,
+        arg2, arg3, arg4, arg5) } } #[unsafe (export_name = "cabi_post_read-file-range")]
+        unsafe extern "C" fn _post_return_read_file_range(arg0 : * mut u8,) { unsafe {
+        $($path_to_types)*:: __post_return_read_file_range::<$ty > (arg0) } } #[unsafe
+        (export_name = "read-bytes")] unsafe extern "C" fn export_read_bytes(arg0 : * mut
+        u8, arg1 : usize, arg2 : i64, arg3 : i32,) -> * mut u8 { unsafe {
+        $($path_to_types)*:: _export_read_bytes_cabi::<$ty > (arg0, arg1, arg2, arg3) } }
+        #[unsafe (export_name = "cabi_post_read-bytes")] unsafe extern "C" fn
+        _post_return_read_bytes(arg0 : * mut u8,) { unsafe { $($path_to_types)*::
+        __post_return_read_bytes::<$ty > (arg0) } } #[unsafe (export_name =
+        "search-file")] unsafe extern "C" fn export_search_file(arg0 : * mut u8, arg1 :
+        usize, arg2 : * mut u8, arg3 : usize, arg4 : * mut u8, arg5 : usize, arg6 : i32,
+        arg7 : i32, arg8 : i32, arg9 : i32,) -> * mut u8 { unsafe { $($path_to_types)*::
+        _export_search_file_cabi::<$ty > (arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7,
+        arg8, arg9) } } #[unsafe (export_name = "cabi_post_search-file")] unsafe extern
+        "C" fn _post_return_search_file(arg0 : * mut u8,) { unsafe { $($path_to_types)*::
+        __post_return_search_file::<$ty > (arg0) } } #[unsafe (export_name = "grep")]
+        unsafe extern "C" fn export_grep(arg0 : * mut u8, arg1 : usize, arg2 : * mut u8,
+        arg3 : usize, arg4 : * mut u8, arg5 : usize, arg6 : i32, arg7 : i32, arg8 : i32,
+        arg9 : i32,) -> * mut u8 { unsafe { $($path_to_types)*:: _export_grep_cabi::<$ty
+        > (arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9) } } #[unsafe
+        (export_name = "cabi_post_grep")] unsafe extern "C" fn _post_return_grep(arg0 : *
+        mut u8,) { unsafe { $($path_to_types)*:: __post_return_grep::<$ty > (arg0) } }
+        #[unsafe (export_name = "get-file-info")] unsafe extern "C" fn
+        export_get_file_info(arg0 : * mut u8, arg1 : usize,) -> * mut u8 { unsafe {
+        $($path_to_types)*:: _export_get_file_info_cabi::<$ty > (arg0, arg1) } } #[unsafe
+        (export_name = "cabi_post_get-file-info")] unsafe extern "C" fn
+        _post_return_get_file_info(arg0 : * mut u8,) { unsafe { $($path_to_types)*::
+        __post_return_get_file_info::<$ty > (arg0) } } #[unsafe (export_name =
+        "list-directory-detailed")] unsafe extern "C" fn
+        export_list_directory_detailed(arg0 : * mut u8, arg1 : usize,) -> * mut u8 {
+        unsafe { $($path_to_types)*:: _export_list_directory_detailed_cabi::<$ty > (arg0,
+        arg1) } } #[unsafe (export_name = "cabi_post_list-directory-detailed")] unsafe
+        extern "C" fn _post_return_list_directory_detailed(arg0 : * mut u8,) { unsafe {
+        $($path_to_types)*:: __post_return_list_directory_detailed::<$ty > (arg0) } }
+        #[unsafe (export_name = "write-file")] unsafe extern "C" fn
+        export_write_file(arg0 : * mut u8, arg1 : usize, arg2 : * mut u8, arg3 : usize,)
+        -> * mut u8 { unsafe { $($path_to_types)*:: _export_write_file_cabi::<$ty >
+        (arg0, arg1, arg2, arg3) } } #[unsafe (export_name = "cabi_post_write-file")]
+        unsafe extern "C" fn _post_return_write_file(arg0 : * mut u8,) { unsafe {
+        $($path_to_types)*:: __post_return_write_file::<$ty > (arg0) } } #[unsafe
+        (export_name = "append-file")] unsafe extern "C" fn export_append_file(arg0 : *
+        mut u8, arg1 : usize, arg2 : * mut u8, arg3 : usize,) -> * mut u8 { unsafe {
+        $($path_to_types)*:: _export_append_file_cabi::<$ty > (arg0, arg1, arg2, arg3) }
+        } #[unsafe (export_name = "cabi_post_append-file")] unsafe extern "C" fn
+        _post_return_append_file(arg0 : * mut u8,) { unsafe { $($path_to_types)*::
+        __post_return_append_file::<$ty > (arg0) } } #[unsafe (export_name =
+        "edit-file")] unsafe extern "C" fn export_edit_file(arg0 : * mut u8, arg1 :
+        usize, arg2 : * mut u8, arg3 : usize, arg4 : i32,) -> * mut u8 { unsafe {
+        $($path_to_types)*:: _export_edit_file_cabi::<$ty > (arg0, arg1, arg2, arg3,
+        arg4) } } #[unsafe (export_name = "cabi_post_edit-file")] unsafe extern "C" fn
+        _post_return_edit_file(arg0 : * mut u8,) { unsafe { $($path_to_types)*::
+        __post_return_edit_file::<$ty > (arg0) } } #[unsafe (export_name =
+        "create-directory")] unsafe extern "C" fn export_create_directory(arg0 : * mut
+        u8, arg1 : usize,) -> * mut u8 { unsafe { $($path_to_types)*::
+        _export_create_directory_cabi::<$ty > (arg0, arg1) } } #[unsafe (export_name =
+        "cabi_post_create-directory")] unsafe extern "C" fn
+        _post_return_create_directory(arg0 : * mut u8,) { unsafe { $($path_to_types)*::
+        __post_return_create_directory::<$ty > (arg0) } } #[unsafe (export_name =
+        "move-file")] unsafe extern "C" fn export_move_file(arg0 : * mut u8, arg1 :
+        usize, arg2 : * mut u8, arg3 : usize,) -> * mut u8 { unsafe {
+        $($path_to_types)*:: _export_move_file_cabi::<$ty > (arg0, arg1, arg2, arg3) } }
+        #[unsafe (export_name = "cabi_post_move-file")] unsafe extern "C" fn
+        _post_return_move_file(arg0 : * mut u8,) { unsafe { $($path_to_types)*::
+        __post_return_move_file::<$ty > (arg0) } } #[unsafe (export_name = "copy-file")]
+        unsafe extern "C" fn export_copy_file(arg0 : * mut u8, arg1 : usize, arg2 : * mut
+        u8, arg3 : usize,) -> * mut u8 { unsafe { $($path_to_types)*::
+        _export_copy_file_cabi::<$ty > (arg0, arg1, arg2, arg3) } } #[unsafe (export_name
+        = "cabi_post_copy-file")] unsafe extern "C" fn _post_return_copy_file(arg0 : *
+        mut u8,) { unsafe { $($path_to_types)*:: __post_return_copy_file::<$ty > (arg0) }
+        } #[unsafe (export_name = "delete")] unsafe extern "C" fn export_delete(arg0 : *
+        mut u8, arg1 : usize, arg2 : i32,) -> * mut u8 { unsafe { $($path_to_types)*::
+        _export_delete_cabi::<$ty > (arg0, arg1, arg2) } } #[unsafe (export_name =
+        "cabi_post_delete")] unsafe extern "C" fn _post_return_delete(arg0 : * mut u8,) {
+        unsafe { $($path_to_types)*:: __post_return_delete::<$ty > (arg0) } } };
+    };
+}
+#[doc(hidden)]
+pub(crate) use __export_world_fs_cabi;
+#[repr(align(8))]
+struct _RetArea([::core::mem::MaybeUninit<u8>; 32 + 14 * ::core::mem::size_of::<*const u8>()]);
+static mut _RET_AREA: _RetArea =
+    _RetArea([::core::mem::MaybeUninit::uninit(); 32 + 14 * ::core::mem::size_of::<*const u8>()]);
+#[rustfmt::skip]
+mod _rt {
+    #![allow(dead_code, clippy::all)]
+    pub use alloc_crate::string::String;
+    pub use alloc_crate::vec::Vec;
+    #[cfg(target_arch = "wasm32")]
+    pub fn run_ctors_once() {
+        wit_bindgen_rt::run_ctors_once();
+    }
+    pub unsafe fn string_lift(bytes: Vec<u8>) -> String {
+        if cfg!(debug_assertions) {
+            String::from_utf8(bytes).unwrap()
+        } else {
+            String::from_utf8_unchecked(bytes)
+        }
+    }
+    pub use alloc_crate::alloc;
+    pub unsafe fn cabi_dealloc(ptr: *mut u8, size: usize, align: usize) {
+        if size == 0 {
+            return;
+        }
+        let layout = alloc::Layout::from_size_align_unchecked(size, align);
+        alloc::dealloc(ptr, layout);
+    }
+    pub unsafe fn invalid_enum_discriminant<T>() -> T {
+        if cfg!(debug_assertions) {
+            panic!("invalid enum discriminant")
+        } else {
+            unsafe { core::hint::unreachable_unchecked() }
+        }
+    }
+    pub fn as_i64<T: AsI64>(t: T) -> i64 {
+        t.as_i64()
+    }
+    pub trait AsI64 {
+        fn as_i64(self) -> i64;
+    }
+    impl<'a, T: Copy + AsI64> AsI64 for &'a T {
+        fn as_i64(self) -> i64 {
+            (*self).as_i64()
+        }
+    }
+    impl AsI64 for i64 {
+        #[inline]
+        fn as_i64(self) -> i64 {
+            self as i64
+        }
+    }
+    impl AsI64 for u64 {
+        #[inline]
+        fn as_i64(self) -> i64 {
+            self as i64
+        }
+    }
+    pub unsafe fn bool_lift(val: u8) -> bool {
+        if cfg!(debug_assertions) {
+            match val {
+                0 => false,
+                1 => true,
+                _ => panic!("invalid bool discriminant"),
+            }
+        } else {
+            val != 0
+        }
+    }
+    pub fn as_i32<T: AsI32>(t: T) -> i32 {
+        t.as_i32()
+    }
+    pub trait AsI32 {
+        fn as_i32(self) -> i32;
+    }
+    impl<'a, T: Copy + AsI32> AsI32 for &'a T {
+        fn as_i32(self) -> i32 {
+            (*self).as_i32()
+        }
+    }
+    impl AsI32 for i32 {
+        #[inline]
+        fn as_i32(self) -> i32 {
+            self as i32
+        }
+    }
+    impl AsI32 for u32 {
+        #[inline]
+        fn as_i32(self) -> i32 {
+            self as i32
+        }
+    }
+    impl AsI32 for i16 {
+        #[inline]
+        fn as_i32(self) -> i32 {
+            self as i32
+        }
+    }
+    impl AsI32 for u16 {
+        #[inline]
+        fn as_i32(self) -> i32 {
+            self as i32
+        }
+    }
+    impl AsI32 for i8 {
+        #[inline]
+        fn as_i32(self) -> i32 {
+            self as i32
+        }
+    }
+    impl AsI32 for u8 {
+        #[inline]
+        fn as_i32(self) -> i32 {
+            self as i32
+        }
+    }
+    impl AsI32 for char {
+        #[inline]
+        fn as_i32(self) -> i32 {
+            self as i32
+        }
+    }
+    impl AsI32 for usize {
+        #[inline]
+        fn as_i32(self) -> i32 {
+            self as i32
+        }
+    }
     extern crate alloc as alloc_crate;
 }
 /// Generates `#[unsafe(no_mangle)]` functions to export the specified type as
@@ -411,14 +2190,32 @@ pub(crate) use __export_fs_impl as export;
 #[unsafe(link_section = "component-type:wit-bindgen:0.41.0:component:filesystem:fs:encoded world")]
 #[doc(hidden)]
 #[allow(clippy::octal_escapes)]
-pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 279] = *b"\
-\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\x9e\x01\x01A\x02\x01\
-A\x0a\x01ps\x01j\x01\0\x01s\x01@\x01\x04paths\0\x01\x04\0\x0elist-directory\x01\x02\
-\x01j\x01s\x01s\x01@\x01\x04paths\0\x03\x04\0\x09read-file\x01\x04\x01@\x02\x04p\
-aths\x07patterns\0\x03\x04\0\x0bsearch-file\x01\x05\x04\0\x0dget-file-info\x01\x04\
-\x04\0\x17component:filesystem/fs\x04\0\x0b\x08\x01\0\x02fs\x03\0\0\0G\x09produc\
-ers\x01\x0cprocessed-by\x02\x0dwit-component\x070.227.1\x10wit-bindgen-rust\x060\
-.41.0";
+pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 1129] = *b"\
+\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\xf0\x07\x01A\x02\x01\
+A7\x01m\x03\x04file\x09directory\x07symlink\x03\0\x09file-kind\x03\0\0\x01ks\x01\
+r\x08\x04paths\x04kind\x01\x04sizew\x07created\x02\x08modified\x02\x08accessed\x02\
+\x08readonly\x7f\x0esymlink-target\x02\x03\0\x09file-info\x03\0\x03\x01ky\x01r\x02\
+\x06offsety\x05limit\x05\x03\0\x0bline-window\x03\0\x06\x01q\x03\x05lines\x01\x07\
+\0\x04head\x01y\0\x04tail\x01y\0\x03\0\x0aline-range\x03\0\x08\x01r\x02\x04datas\
+\x0atotal-sizew\x03\0\x0afile-bytes\x03\0\x0a\x01ps\x01r\x05\x04paths\x0bline-nu\
+mbery\x04lines\x0econtext-before\x0c\x0dcontext-after\x0c\x03\0\x0agrep-match\x03\
+\0\x0d\x01r\x02\x08old-texts\x08new-texts\x03\0\x04edit\x03\0\x0f\x01j\x01\x0c\x01\
+s\x01@\x01\x04paths\0\x11\x04\0\x0elist-directory\x01\x12\x01j\x01s\x01s\x01@\x01\
+\x04paths\0\x13\x04\0\x09read-file\x01\x14\x01@\x02\x04paths\x05range\x09\0\x13\x04\
+\0\x0fread-file-range\x01\x15\x01j\x01\x0b\x01s\x01@\x03\x04paths\x06offsetw\x06\
+lengthy\0\x16\x04\0\x0aread-bytes\x01\x17\x01@\x05\x04paths\x07patterns\x07exclu\
+de\x0c\x09max-depth\x05\x0bmax-results\x05\0\x11\x04\0\x0bsearch-file\x01\x18\x01\
+p\x0e\x01j\x01\x19\x01s\x01@\x06\x04paths\x07patterns\x07include\x0c\x0ecase-sen\
+sitive\x7f\x07contexty\x0bmax-matches\x05\0\x1a\x04\0\x04grep\x01\x1b\x01j\x01\x04\
+\x01s\x01@\x01\x04paths\0\x1c\x04\0\x0dget-file-info\x01\x1d\x01p\x04\x01j\x01\x1e\
+\x01s\x01@\x01\x04paths\0\x1f\x04\0\x17list-directory-detailed\x01\x20\x01j\0\x01\
+s\x01@\x02\x04paths\x07contents\0!\x04\0\x0awrite-file\x01\"\x04\0\x0bappend-fil\
+e\x01\"\x01p\x10\x01@\x03\x04paths\x05edits#\x07dry-run\x7f\0\x13\x04\0\x09edit-\
+file\x01$\x01@\x01\x04paths\0!\x04\0\x10create-directory\x01%\x01@\x02\x06source\
+s\x0bdestinations\0!\x04\0\x09move-file\x01&\x04\0\x09copy-file\x01&\x01@\x02\x04\
+paths\x09recursive\x7f\0!\x04\0\x06delete\x01'\x04\0\x17component:filesystem/fs\x04\
+\0\x0b\x08\x01\0\x02fs\x03\0\0\0G\x09producers\x01\x0cprocessed-by\x02\x0dwit-co\
+mponent\x070.227.1\x10wit-bindgen-rust\x060.41.0";
 #[inline(never)]
 #[doc(hidden)]
 pub fn __link_custom_section_describing_imports() {
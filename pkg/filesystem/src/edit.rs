@@ -0,0 +1,80 @@
+//! Targeted text replacements in a file, reported as a unified diff.
+
+use std::fs;
+
+use anyhow::{Result, anyhow};
+use similar::TextDiff;
+
+use crate::sandbox::Sandbox;
+
+/// A replacement of one unique piece of text.
+#[derive(Debug, Clone)]
+pub struct Edit {
+    pub old_text: String,
+    pub new_text: String,
+}
+
+/// Apply `edits` to a file in order and return a unified diff of the result.
+///
+/// Each old text must occur exactly once in the content left by the edits
+/// before it, otherwise nothing is written. With `dry_run` the diff is computed
+/// without writing, which is also allowed in a read-only sandbox.
+pub fn edit_file(sandbox: &Sandbox, path: &str, edits: &[Edit], dry_run: bool) -> Result<String> {
+    if !dry_run {
+        sandbox.check_writable()?;
+    }
+    if edits.is_empty() {
+        return Err(anyhow!("No edits given"));
+    }
+    let resolved = sandbox.resolve(path)?;
+    let original = fs::read_to_string(&resolved)
+        .map_err(|e| anyhow!("Failed to read {}: {e}", resolved.display()))?;
+
+    let edited = apply_edits(&original, edits)?;
+    let name = resolved.to_string_lossy();
+    let diff = TextDiff::from_lines(&original, &edited)
+        .unified_diff()
+        .context_radius(3)
+        .header(&name, &name)
+        .to_string();
+
+    if !dry_run && edited != original {
+        fs::write(&resolved, &edited)
+            .map_err(|e| anyhow!("Failed to write {}: {e}", resolved.display()))?;
+    }
+    Ok(diff)
+}
+
+/// Apply edits to text, failing on the first old text that is missing or ambiguous.
+pub fn apply_edits(text: &str, edits: &[Edit]) -> Result<String> {
+    let mut text = text.to_string();
+    for (i, edit) in edits.iter().enumerate() {
+        let number = i + 1;
+        if edit.old_text.is_empty() {
+            return Err(anyhow!("Edit {number}: old text is empty"));
+        }
+        match count_matches(&text, &edit.old_text) {
+            0 => return Err(anyhow!("Edit {number}: old text not found")),
+            1 => text = text.replacen(&edit.old_text, &edit.new_text, 1),
+            count => {
+                return Err(anyhow!(
+                    "Edit {number}: old text matches {count} times; include more surrounding text to make it unique"
+                ));
+            }
+        }
+    }
+    Ok(text)
+}
+
+/// Count the places `pattern` occurs in `text`, including overlapping ones, so
+/// that `"aa"` is found twice in `"aaa"`.
+fn count_matches(text: &str, pattern: &str) -> usize {
+    let mut count = 0;
+    let mut from = 0;
+    while let Some(found) = text[from..].find(pattern) {
+        count += 1;
+        from += found;
+        from += text[from..].chars().next().map_or(1, char::len_utf8);
+    }
+    count
+}
@@ -0,0 +1,137 @@
+//! Regular expression search through file contents.
+
+use std::{fs, path::Path};
+
+use anyhow::{Result, anyhow};
+use regex::{Regex, RegexBuilder};
+
+use crate::{
+    read::is_binary,
+    sandbox::Sandbox,
+    search::{PathGlob, Walk},
+};
+
+/// Number of matches returned when no maximum is given.
+pub const DEFAULT_MAX_MATCHES: usize = 200;
+
+/// Files larger than this are skipped rather than read into memory.
+pub const MAX_FILE_SIZE: u64 = 10 * 1024 * 1024;
+
+/// What [`grep`] looks for.
+#[derive(Debug, Clone)]
+pub struct GrepOptions {
+    /// A regular expression, matched against each line
+    pub pattern: String,
+    /// Globs selecting the files to search, such as `**/*.rs` or `*.toml`; all
+    /// files are searched when empty
+    pub include: Vec<String>,
+    pub case_sensitive: bool,
+    /// Number of lines to return before and after each matching line
+    pub context: usize,
+    pub max_matches: usize,
+}
+
+impl Default for GrepOptions {
+    fn default() -> Self {
+        Self {
+            pattern: String::new(),
+            include: Vec::new(),
+            case_sensitive: true,
+            context: 0,
+            max_matches: DEFAULT_MAX_MATCHES,
+        }
+    }
+}
+
+/// A line matching a [`grep`] pattern.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GrepMatch {
+    pub path: String,
+    /// 1-based line number
+    pub line_number: usize,
+    pub line: String,
+    pub context_before: Vec<String>,
+    pub context_after: Vec<String>,
+}
+
+/// Search the lines of a file, or of every file under a directory, for a
+/// regular expression.
+///
+/// Directories are walked like [`crate::search::search_files`]. Binary files,
+/// recognised by a NUL byte near the start, are skipped, as are files larger
+/// than [`MAX_FILE_SIZE`] and files that cannot be read. Invalid UTF-8 is
+/// replaced rather than rejected.
+pub fn grep(sandbox: &Sandbox, root: &str, options: &GrepOptions) -> Result<Vec<GrepMatch>> {
+    let regex = RegexBuilder::new(&options.pattern)
+        .case_insensitive(!options.case_sensitive)
+        .build()
+        .map_err(|e| anyhow!("Invalid regular expression: {e}"))?;
+    let root = sandbox.resolve(root)?;
+    let mut matches = Vec::new();
+
+    if !root.is_dir() {
+        grep_file(&root, &root, &regex, options, &mut matches)?;
+        return Ok(matches);
+    }
+
+    let includes = options
+        .include
+        .iter()
+        .map(|pattern| PathGlob::new(pattern))
+        .collect::<Result<Vec<_>>>()?;
+    Walk::new(sandbox, &root, &[], None)
+        .run(&mut |path, relative, file| {
+            let included =
+                includes.is_empty() || includes.iter().any(|glob| glob.is_match(relative));
+            if included && file.is_file() {
+                // Unreadable files are skipped like binary ones
+                let _ = grep_file(path, file, &regex, options, &mut matches);
+            }
+            matches.len() < options.max_matches
+        })
+        .map_err(|e| anyhow!("Failed to search directory: {e}"))?;
+    Ok(matches)
+}
+
+/// Search `file`, which must already be resolved, reporting matches under `path`.
+fn grep_file(
+    path: &Path,
+    file: &Path,
+    regex: &Regex,
+    options: &GrepOptions,
+    matches: &mut Vec<GrepMatch>,
+) -> Result<()> {
+    let metadata =
+        fs::metadata(file).map_err(|e| anyhow!("Failed to read {}: {e}", path.display()))?;
+    if metadata.len() > MAX_FILE_SIZE {
+        return Ok(());
+    }
+    let bytes = fs::read(file).map_err(|e| anyhow!("Failed to read {}: {e}", path.display()))?;
+    if is_binary(&bytes) {
+        return Ok(());
+    }
+    let text = String::from_utf8_lossy(&bytes);
+    let lines: Vec<&str> = text.lines().collect();
+
+    for (i, line) in lines.iter().enumerate() {
+        if matches.len() >= options.max_matches {
+            break;
+        }
+        if !regex.is_match(line) {
+            continue;
+        }
+        let before = i.saturating_sub(options.context);
+        let after = i
+            .saturating_add(1)
+            .saturating_add(options.context)
+            .min(lines.len());
+        matches.push(GrepMatch {
+            path: path.to_string_lossy().into_owned(),
+            line_number: i + 1,
+            line: line.to_string(),
+            context_before: lines[before..i].iter().map(|l| l.to_string()).collect(),
+            context_after: lines[i + 1..after].iter().map(|l| l.to_string()).collect(),
+        });
+    }
+    Ok(())
+}
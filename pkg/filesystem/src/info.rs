@@ -0,0 +1,89 @@
+//! Structured metadata about files, directories and symbolic links.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+use anyhow::{Result, anyhow};
+use chrono::{DateTime, SecondsFormat, Utc};
+
+use crate::sandbox::Sandbox;
+
+/// The type of a file system entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileKind {
+    File,
+    Directory,
+    Symlink,
+}
+
+/// Metadata about an entry, describing a symbolic link itself rather than its target.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileInfo {
+    pub path: PathBuf,
+    pub kind: FileKind,
+    pub size: u64,
+    /// RFC 3339 timestamps in UTC, `None` where the platform does not record them
+    pub created: Option<String>,
+    pub modified: Option<String>,
+    pub accessed: Option<String>,
+    pub readonly: bool,
+    pub symlink_target: Option<PathBuf>,
+}
+
+/// Metadata about the entry at a user-supplied path, without following a
+/// symbolic link in the last component.
+pub fn file_info(sandbox: &Sandbox, path: &str) -> Result<FileInfo> {
+    entry_info(&sandbox.resolve_no_follow(path)?)
+}
+
+/// Metadata about every entry in a directory, sorted by path.
+pub fn list_directory_detailed(sandbox: &Sandbox, path: &str) -> Result<Vec<FileInfo>> {
+    let dir = sandbox.resolve(path)?;
+    let entries = fs::read_dir(&dir).map_err(|e| anyhow!("Failed to read directory: {e}"))?;
+    let mut infos = entries
+        .map(|entry| {
+            let entry = entry.map_err(|e| anyhow!("Error reading entry: {e}"))?;
+            entry_info(&entry.path())
+        })
+        .collect::<Result<Vec<_>>>()?;
+    infos.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok(infos)
+}
+
+/// Metadata about an already resolved path.
+pub fn entry_info(path: &Path) -> Result<FileInfo> {
+    let metadata = fs::symlink_metadata(path)
+        .map_err(|e| anyhow!("Failed to get metadata for {}: {e}", path.display()))?;
+    let file_type = metadata.file_type();
+    let kind = if file_type.is_symlink() {
+        FileKind::Symlink
+    } else if file_type.is_dir() {
+        FileKind::Directory
+    } else {
+        FileKind::File
+    };
+    let symlink_target = match kind {
+        FileKind::Symlink => Some(
+            fs::read_link(path)
+                .map_err(|e| anyhow!("Failed to read link {}: {e}", path.display()))?,
+        ),
+        _ => None,
+    };
+    Ok(FileInfo {
+        path: path.to_path_buf(),
+        kind,
+        size: metadata.len(),
+        created: metadata.created().ok().map(rfc3339),
+        modified: metadata.modified().ok().map(rfc3339),
+        accessed: metadata.accessed().ok().map(rfc3339),
+        readonly: metadata.permissions().readonly(),
+        symlink_target,
+    })
+}
+
+fn rfc3339(time: SystemTime) -> String {
+    DateTime::<Utc>::from(time).to_rfc3339_opts(SecondsFormat::Secs, true)
+}
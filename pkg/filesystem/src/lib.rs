@@ -1,14 +1,19 @@
 #[allow(warnings)]
 mod bindings;
+pub mod edit;
+pub mod grep;
+pub mod info;
+pub mod read;
+pub mod sandbox;
+pub mod search;
+pub mod write;
 
-use std::{
-    env, fs,
-    path::{Path, PathBuf},
-};
+use std::{fs, path::PathBuf};
 
-use anyhow::{Result, anyhow};
+use anyhow::Result;
 
 use bindings::Guest;
+use sandbox::Sandbox;
 
 struct Component;
 
@@ -46,74 +51,162 @@ impl Guest for Component {
     }
 
     fn read_file(path: String) -> Result<String, String> {
-        match get_path(&path) {
-            Ok(path) => match fs::read_to_string(&path) {
-                Ok(text) => Ok(text),
-                Err(e) => Err(format!("Failed to read file: {}", e)),
+        let sandbox = Sandbox::from_env().map_err(|e| e.to_string())?;
+        read::read_text(&sandbox, &path).map_err(|e| e.to_string())
+    }
+
+    fn read_file_range(path: String, range: bindings::LineRange) -> Result<String, String> {
+        let sandbox = Sandbox::from_env().map_err(|e| e.to_string())?;
+        let range = match range {
+            bindings::LineRange::Lines(window) => read::LineRange::Lines {
+                offset: window.offset as usize,
+                limit: window.limit.map(|limit| limit as usize),
             },
-            Err(e) => Err(e.to_string()),
-        }
+            bindings::LineRange::Head(count) => read::LineRange::Head(count as usize),
+            bindings::LineRange::Tail(count) => read::LineRange::Tail(count as usize),
+        };
+        read::read_lines(&sandbox, &path, range).map_err(|e| e.to_string())
     }
 
-    fn search_file(path: String, pattern: String) -> Result<String, String> {
-        let path = match get_path(&path) {
-            Ok(p) => p,
-            Err(e) => {
-                return Err(e.to_string());
-            }
+    fn read_bytes(path: String, offset: u64, length: u32) -> Result<bindings::FileBytes, String> {
+        let sandbox = Sandbox::from_env().map_err(|e| e.to_string())?;
+        read::read_bytes(&sandbox, &path, offset, length as usize)
+            .map(|bytes| bindings::FileBytes {
+                data: bytes.data,
+                total_size: bytes.total_size,
+            })
+            .map_err(|e| e.to_string())
+    }
+
+    fn search_file(
+        path: String,
+        pattern: String,
+        exclude: Vec<String>,
+        max_depth: Option<u32>,
+        max_results: Option<u32>,
+    ) -> Result<Vec<String>, String> {
+        let sandbox = Sandbox::from_env().map_err(|e| e.to_string())?;
+        let options = search::SearchOptions {
+            pattern,
+            exclude,
+            max_depth: max_depth.map(|depth| depth as usize),
+            max_results: max_results.map_or(search::DEFAULT_MAX_RESULTS, |max| max as usize),
         };
-        let mut matches = Vec::new();
-        if let Err(e) = search_directory(&path, &pattern, &mut matches) {
-            return Err(format!("Failed to search directory: {}", e));
-        }
-        Ok(matches.join("\n"))
+        search::search_files(&sandbox, &path, &options).map_err(|e| e.to_string())
     }
 
-    fn get_file_info(path: String) -> Result<String, String> {
-        match get_path(&path) {
-            Ok(path) => match fs::metadata(&path) {
-                Ok(metadata) => Ok(format!("{:?}", metadata)),
-                Err(e) => Err(format!("Failed to get metadata: {}", e)),
-            },
-            Err(e) => Err(e.to_string()),
-        }
+    fn grep(
+        path: String,
+        pattern: String,
+        include: Vec<String>,
+        case_sensitive: bool,
+        context: u32,
+        max_matches: Option<u32>,
+    ) -> Result<Vec<bindings::GrepMatch>, String> {
+        let sandbox = Sandbox::from_env().map_err(|e| e.to_string())?;
+        let options = grep::GrepOptions {
+            pattern,
+            include,
+            case_sensitive,
+            context: context as usize,
+            max_matches: max_matches.map_or(grep::DEFAULT_MAX_MATCHES, |max| max as usize),
+        };
+        let matches = grep::grep(&sandbox, &path, &options).map_err(|e| e.to_string())?;
+        Ok(matches
+            .into_iter()
+            .map(|m| bindings::GrepMatch {
+                path: m.path,
+                line_number: m.line_number as u32,
+                line: m.line,
+                context_before: m.context_before,
+                context_after: m.context_after,
+            })
+            .collect())
     }
-}
 
-fn search_directory(dir: &Path, pattern: &str, matches: &mut Vec<String>) -> Result<()> {
-    for entry in std::fs::read_dir(dir)? {
-        let entry = entry?;
-        let path = entry.path();
-        let name = path
-            .file_name()
-            .unwrap_or_default()
-            .to_string_lossy()
-            .to_lowercase();
-
-        if name.contains(&pattern.to_lowercase()) {
-            matches.push(path.to_string_lossy().to_string());
-        }
-        if path.is_dir() {
-            search_directory(&path, pattern, matches)?;
-        }
+    fn get_file_info(path: String) -> Result<bindings::FileInfo, String> {
+        let sandbox = Sandbox::from_env().map_err(|e| e.to_string())?;
+        info::file_info(&sandbox, &path)
+            .map(to_wit_info)
+            .map_err(|e| e.to_string())
     }
-    Ok(())
-}
 
-fn get_path(path_str: &str) -> Result<PathBuf> {
-    if path_str == "~" || path_str.starts_with("~/") {
-        let home_dir =
-            env::var("HOME").map_err(|_| anyhow!("Cannot determine home directory from $HOME"))?;
+    fn list_directory_detailed(path: String) -> Result<Vec<bindings::FileInfo>, String> {
+        let sandbox = Sandbox::from_env().map_err(|e| e.to_string())?;
+        info::list_directory_detailed(&sandbox, &path)
+            .map(|infos| infos.into_iter().map(to_wit_info).collect())
+            .map_err(|e| e.to_string())
+    }
 
-        if path_str == "~" {
-            return Ok(PathBuf::from(home_dir));
-        }
-        let suffix = &path_str[2..];
-        let combined = Path::new(&home_dir).join(suffix);
-        return Ok(combined);
+    fn write_file(path: String, content: String) -> Result<(), String> {
+        let sandbox = Sandbox::from_env().map_err(|e| e.to_string())?;
+        write::write_file(&sandbox, &path, &content, false).map_err(|e| e.to_string())
+    }
+
+    fn append_file(path: String, content: String) -> Result<(), String> {
+        let sandbox = Sandbox::from_env().map_err(|e| e.to_string())?;
+        write::write_file(&sandbox, &path, &content, true).map_err(|e| e.to_string())
+    }
+
+    fn edit_file(
+        path: String,
+        edits: Vec<bindings::Edit>,
+        dry_run: bool,
+    ) -> Result<String, String> {
+        let sandbox = Sandbox::from_env().map_err(|e| e.to_string())?;
+        let edits: Vec<edit::Edit> = edits
+            .into_iter()
+            .map(|e| edit::Edit {
+                old_text: e.old_text,
+                new_text: e.new_text,
+            })
+            .collect();
+        edit::edit_file(&sandbox, &path, &edits, dry_run).map_err(|e| e.to_string())
+    }
+
+    fn create_directory(path: String) -> Result<(), String> {
+        let sandbox = Sandbox::from_env().map_err(|e| e.to_string())?;
+        write::create_directory(&sandbox, &path).map_err(|e| e.to_string())
+    }
+
+    fn move_file(source: String, destination: String) -> Result<(), String> {
+        let sandbox = Sandbox::from_env().map_err(|e| e.to_string())?;
+        write::move_path(&sandbox, &source, &destination).map_err(|e| e.to_string())
     }
 
-    Ok(PathBuf::from(path_str))
+    fn copy_file(source: String, destination: String) -> Result<(), String> {
+        let sandbox = Sandbox::from_env().map_err(|e| e.to_string())?;
+        write::copy_file(&sandbox, &source, &destination).map_err(|e| e.to_string())
+    }
+
+    fn delete(path: String, recursive: bool) -> Result<(), String> {
+        let sandbox = Sandbox::from_env().map_err(|e| e.to_string())?;
+        write::delete(&sandbox, &path, recursive).map_err(|e| e.to_string())
+    }
+}
+
+fn to_wit_info(info: info::FileInfo) -> bindings::FileInfo {
+    bindings::FileInfo {
+        path: info.path.to_string_lossy().into_owned(),
+        kind: match info.kind {
+            info::FileKind::File => bindings::FileKind::File,
+            info::FileKind::Directory => bindings::FileKind::Directory,
+            info::FileKind::Symlink => bindings::FileKind::Symlink,
+        },
+        size: info.size,
+        created: info.created,
+        modified: info.modified,
+        accessed: info.accessed,
+        readonly: info.readonly,
+        symlink_target: info
+            .symlink_target
+            .map(|target| target.to_string_lossy().into_owned()),
+    }
+}
+
+/// Resolve a user-supplied path and check it against the configured sandbox.
+fn get_path(path_str: &str) -> Result<PathBuf> {
+    Sandbox::from_env()?.resolve(path_str)
 }
 
 bindings::export!(Component with_types_in bindings);
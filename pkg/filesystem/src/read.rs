@@ -0,0 +1,182 @@
+//! Reading parts of files, as lines of text or as raw bytes.
+
+use std::{
+    collections::VecDeque,
+    fs::File,
+    io::{BufRead, BufReader, Read, Seek, SeekFrom},
+    path::Path,
+};
+
+use anyhow::{Result, anyhow};
+use base64::{Engine, engine::general_purpose::STANDARD};
+
+use crate::sandbox::Sandbox;
+
+/// Maximum number of bytes returned by one [`read_bytes`] call.
+pub const MAX_READ_BYTES: usize = 1024 * 1024;
+
+/// Largest file [`read_text`] reads whole; larger ones are read with [`read_lines`].
+pub const MAX_TEXT_FILE_SIZE: u64 = 10 * 1024 * 1024;
+
+/// Number of leading bytes inspected when deciding whether a file is binary.
+const BINARY_SNIFF_LEN: usize = 8000;
+
+/// Which lines of a file to read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineRange {
+    /// Skip `offset` lines, then read up to `limit` lines, or all the rest
+    Lines { offset: usize, limit: Option<usize> },
+    /// The first lines of the file
+    Head(usize),
+    /// The last lines of the file
+    Tail(usize),
+}
+
+/// A slice of a file's bytes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileBytes {
+    /// The bytes read, base64 encoded
+    pub data: String,
+    /// Size of the whole file in bytes
+    pub total_size: u64,
+}
+
+/// Read a whole text file, refusing binary and non-UTF-8 files with an error
+/// that points to [`read_bytes`], and files over [`MAX_TEXT_FILE_SIZE`] with one
+/// that points to [`read_lines`].
+///
+/// The start of the file and its size are checked before the rest is read.
+pub fn read_text(sandbox: &Sandbox, path: &str) -> Result<String> {
+    let path = sandbox.resolve(path)?;
+    let file = File::open(&path).map_err(|e| anyhow!("Failed to read file: {e}"))?;
+    let size = file
+        .metadata()
+        .map_err(|e| anyhow!("Failed to read file: {e}"))?
+        .len();
+    let mut reader = BufReader::new(file);
+    if is_binary(reader.fill_buf()?) {
+        return Err(not_text(&path, size, "a binary file"));
+    }
+    if size > MAX_TEXT_FILE_SIZE {
+        return Err(too_large(&path, size));
+    }
+
+    let mut bytes = Vec::with_capacity(size as usize);
+    reader
+        .take(MAX_TEXT_FILE_SIZE + 1)
+        .read_to_end(&mut bytes)
+        .map_err(|e| anyhow!("Failed to read file: {e}"))?;
+    // The file may have grown since its size was checked
+    if bytes.len() as u64 > MAX_TEXT_FILE_SIZE {
+        return Err(too_large(&path, bytes.len() as u64));
+    }
+    String::from_utf8(bytes)
+        .map_err(|e| not_text(&path, e.as_bytes().len() as u64, "not valid UTF-8"))
+}
+
+/// Read a range of lines from a text file, keeping their line endings.
+///
+/// The file is streamed, so only the requested lines are held in memory. Binary
+/// files are refused, and invalid UTF-8 in a text file is replaced.
+pub fn read_lines(sandbox: &Sandbox, path: &str, range: LineRange) -> Result<String> {
+    let path = sandbox.resolve(path)?;
+    let file = File::open(&path).map_err(|e| anyhow!("Failed to read file: {e}"))?;
+    let size = file
+        .metadata()
+        .map_err(|e| anyhow!("Failed to read file: {e}"))?
+        .len();
+    let mut reader = BufReader::new(file);
+    if is_binary(reader.fill_buf()?) {
+        return Err(not_text(&path, size, "a binary file"));
+    }
+
+    let (skip, take) = match range {
+        LineRange::Lines { offset, limit } => (offset, limit),
+        LineRange::Head(count) => (0, Some(count)),
+        LineRange::Tail(count) => {
+            let mut lines = VecDeque::with_capacity(count.min(1024));
+            let mut line = Vec::new();
+            while count > 0 && read_line(&mut reader, &mut line)? {
+                if lines.len() == count {
+                    lines.pop_front();
+                }
+                lines.push_back(String::from_utf8_lossy(&line).into_owned());
+            }
+            return Ok(lines.into_iter().collect());
+        }
+    };
+
+    let mut text = String::new();
+    let mut line = Vec::new();
+    let mut index = 0;
+    while take.is_none_or(|take| index < skip.saturating_add(take))
+        && read_line(&mut reader, &mut line)?
+    {
+        if index >= skip {
+            text.push_str(&String::from_utf8_lossy(&line));
+        }
+        index += 1;
+    }
+    if index < skip {
+        return Err(anyhow!(
+            "Line offset {skip} is past the end of {} ({index} lines)",
+            path.display()
+        ));
+    }
+    Ok(text)
+}
+
+/// Read up to `length` bytes from `offset`, at most [`MAX_READ_BYTES`].
+pub fn read_bytes(sandbox: &Sandbox, path: &str, offset: u64, length: usize) -> Result<FileBytes> {
+    let path = sandbox.resolve(path)?;
+    let mut file = File::open(&path).map_err(|e| anyhow!("Failed to read file: {e}"))?;
+    let total_size = file
+        .metadata()
+        .map_err(|e| anyhow!("Failed to read file: {e}"))?
+        .len();
+    if offset > total_size {
+        return Err(anyhow!(
+            "Offset {offset} is past the end of {} ({total_size} bytes)",
+            path.display()
+        ));
+    }
+    file.seek(SeekFrom::Start(offset))
+        .map_err(|e| anyhow!("Failed to read file: {e}"))?;
+    let mut data = Vec::new();
+    file.take(length.min(MAX_READ_BYTES) as u64)
+        .read_to_end(&mut data)
+        .map_err(|e| anyhow!("Failed to read file: {e}"))?;
+    Ok(FileBytes {
+        data: STANDARD.encode(data),
+        total_size,
+    })
+}
+
+/// Whether content looks binary: it has a NUL byte near the start.
+pub fn is_binary(bytes: &[u8]) -> bool {
+    bytes[..bytes.len().min(BINARY_SNIFF_LEN)].contains(&0)
+}
+
+/// Read one line including its terminator, returning false at the end of the file.
+fn read_line(reader: &mut impl BufRead, line: &mut Vec<u8>) -> Result<bool> {
+    line.clear();
+    let read = reader
+        .read_until(b'\n', line)
+        .map_err(|e| anyhow!("Failed to read file: {e}"))?;
+    Ok(read > 0)
+}
+
+fn too_large(path: &Path, size: u64) -> anyhow::Error {
+    anyhow!(
+        "{} is too large to read whole ({size} bytes, limit {} MiB); use read-file-range to read it in parts",
+        path.display(),
+        MAX_TEXT_FILE_SIZE / (1024 * 1024)
+    )
+}
+
+fn not_text(path: &Path, size: u64, reason: &str) -> anyhow::Error {
+    anyhow!(
+        "{} is {reason} ({size} bytes) and cannot be read as text; use read-bytes to read it as base64",
+        path.display()
+    )
+}
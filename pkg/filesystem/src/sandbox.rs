@@ -0,0 +1,212 @@
+use std::{
+    env,
+    ffi::OsString,
+    fs,
+    path::{Component, Path, PathBuf},
+};
+
+use anyhow::{Result, anyhow};
+
+/// Environment variable listing the directories the component may access,
+/// separated like `PATH` entries.
+pub const ALLOWED_DIRS_VAR: &str = "FILESYSTEM_ALLOWED_DIRS";
+
+/// Environment variable that disables every operation that modifies files when
+/// set to `1` or `true`.
+pub const READ_ONLY_VAR: &str = "FILESYSTEM_READ_ONLY";
+
+/// Maximum number of symbolic links followed while resolving one path.
+const MAX_SYMLINKS: usize = 40;
+
+/// Restricts file access to a set of allowed root directories.
+///
+/// Paths are made absolute, `..` segments and symbolic links are resolved, and
+/// the result must lie within one of the roots. Without roots every path is
+/// allowed, leaving access control to the host's preopened directories.
+/// A read-only sandbox also refuses every modification.
+#[derive(Debug, Clone, Default)]
+pub struct Sandbox {
+    roots: Option<Vec<PathBuf>>,
+    read_only: bool,
+}
+
+impl Sandbox {
+    /// A sandbox limited to `roots`, which are resolved the same way as paths.
+    pub fn new<I, P>(roots: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<Path>,
+    {
+        let roots = roots
+            .into_iter()
+            .map(|root| resolve(root.as_ref()))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self {
+            roots: Some(roots),
+            read_only: false,
+        })
+    }
+
+    /// A sandbox with no restrictions.
+    pub fn unrestricted() -> Self {
+        Self {
+            roots: None,
+            read_only: false,
+        }
+    }
+
+    /// Set whether modifications are refused.
+    pub fn with_read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self
+    }
+
+    /// The sandbox configured by [`ALLOWED_DIRS_VAR`] and [`READ_ONLY_VAR`].
+    /// Without allowed directories it is unrestricted.
+    pub fn from_env() -> Result<Self> {
+        let sandbox = match env::var_os(ALLOWED_DIRS_VAR) {
+            Some(dirs) if !dirs.is_empty() => {
+                Self::new(env::split_paths(&dirs).filter(|dir| !dir.as_os_str().is_empty()))?
+            }
+            _ => Self::unrestricted(),
+        };
+        let read_only = env::var(READ_ONLY_VAR)
+            .is_ok_and(|value| matches!(value.trim().to_lowercase().as_str(), "1" | "true"));
+        Ok(sandbox.with_read_only(read_only))
+    }
+
+    /// Fail if modifications are disabled.
+    pub fn check_writable(&self) -> Result<()> {
+        if self.read_only {
+            return Err(anyhow!(
+                "Read-only mode: modifying files is disabled by {READ_ONLY_VAR}"
+            ));
+        }
+        Ok(())
+    }
+
+    /// Whether `path`, already resolved, is one of the allowed directories.
+    pub fn is_root(&self, path: &Path) -> bool {
+        self.roots
+            .as_ref()
+            .is_some_and(|roots| roots.iter().any(|root| root == path))
+    }
+
+    /// Resolve a user-supplied path, expanding `~`, and check that it is allowed.
+    ///
+    /// The returned path is absolute with all symbolic links resolved, so it is
+    /// the file that will actually be accessed. A path that does not exist yet is
+    /// resolved as far as it does exist.
+    pub fn resolve(&self, path: &str) -> Result<PathBuf> {
+        let resolved = resolve(&expand_home(path)?)?;
+        self.check(path, &resolved)?;
+        Ok(resolved)
+    }
+
+    /// Like [`Sandbox::resolve`], but without following a symbolic link in the
+    /// last component, for operations on the link itself such as deleting it.
+    pub fn resolve_no_follow(&self, path: &str) -> Result<PathBuf> {
+        let expanded = absolute(&expand_home(path)?)?;
+        let resolved = match (expanded.parent(), expanded.file_name()) {
+            (Some(parent), Some(name)) => resolve(parent)?.join(name),
+            _ => resolve(&expanded)?,
+        };
+        self.check(path, &resolved)?;
+        Ok(resolved)
+    }
+
+    fn check(&self, path: &str, resolved: &Path) -> Result<()> {
+        let Some(roots) = &self.roots else {
+            return Ok(());
+        };
+        if roots.iter().any(|root| resolved.starts_with(root)) {
+            return Ok(());
+        }
+        let roots = roots
+            .iter()
+            .map(|root| root.display().to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        Err(anyhow!(
+            "Access denied: {path} resolves to {}, which is outside the allowed directories ({roots})",
+            resolved.display()
+        ))
+    }
+}
+
+/// Expand a leading `~` to the home directory given by `$HOME`.
+pub fn expand_home(path_str: &str) -> Result<PathBuf> {
+    if path_str == "~" || path_str.starts_with("~/") {
+        let home_dir =
+            env::var("HOME").map_err(|_| anyhow!("Cannot determine home directory from $HOME"))?;
+
+        if path_str == "~" {
+            return Ok(PathBuf::from(home_dir));
+        }
+        let suffix = &path_str[2..];
+        let combined = Path::new(&home_dir).join(suffix);
+        return Ok(combined);
+    }
+
+    Ok(PathBuf::from(path_str))
+}
+
+fn absolute(path: &Path) -> Result<PathBuf> {
+    if path.is_absolute() {
+        return Ok(path.to_path_buf());
+    }
+    let cwd = env::current_dir()
+        .map_err(|e| anyhow!("Cannot resolve relative path without a current directory: {e}"))?;
+    Ok(cwd.join(path))
+}
+
+/// Make a path absolute and resolve `.`, `..` and symbolic links component by
+/// component.
+///
+/// This does the work of `fs::canonicalize`, which WASI does not support, and
+/// also accepts paths whose trailing components do not exist yet.
+fn resolve(path: &Path) -> Result<PathBuf> {
+    let path = absolute(path)?;
+    let mut resolved = PathBuf::from("/");
+    let mut pending: Vec<OsString> = components(&path);
+    pending.reverse();
+    let mut links = 0;
+
+    while let Some(part) = pending.pop() {
+        if part == ".." {
+            resolved.pop();
+            continue;
+        }
+        let candidate = resolved.join(&part);
+        match fs::symlink_metadata(&candidate) {
+            Ok(metadata) if metadata.file_type().is_symlink() => {
+                links += 1;
+                if links > MAX_SYMLINKS {
+                    return Err(anyhow!(
+                        "Too many levels of symbolic links resolving {}",
+                        path.display()
+                    ));
+                }
+                let target = fs::read_link(&candidate)
+                    .map_err(|e| anyhow!("Failed to read link {}: {e}", candidate.display()))?;
+                if target.is_absolute() {
+                    resolved = PathBuf::from("/");
+                }
+                pending.extend(components(&target).into_iter().rev());
+            }
+            _ => resolved = candidate,
+        }
+    }
+    Ok(resolved)
+}
+
+/// The normal and `..` components of a path, in order.
+fn components(path: &Path) -> Vec<OsString> {
+    path.components()
+        .filter_map(|component| match component {
+            Component::Normal(name) => Some(name.to_os_string()),
+            Component::ParentDir => Some(OsString::from("..")),
+            Component::RootDir | Component::CurDir | Component::Prefix(_) => None,
+        })
+        .collect()
+}
@@ -0,0 +1,211 @@
+//! Recursive file search by name or glob pattern.
+
+use std::{
+    collections::HashSet,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Result, anyhow};
+use globset::{GlobBuilder, GlobMatcher};
+
+use crate::sandbox::Sandbox;
+
+/// Number of results returned when no maximum is given.
+pub const DEFAULT_MAX_RESULTS: usize = 1000;
+
+/// What [`search_files`] looks for.
+#[derive(Debug, Clone)]
+pub struct SearchOptions {
+    /// A glob such as `**/*.rs`, or plain text matched case-insensitively
+    /// anywhere in file names
+    pub pattern: String,
+    /// Globs for paths to skip, such as `target/**` or `.git`
+    pub exclude: Vec<String>,
+    /// How many directory levels to descend, at least 1; 1 searches only the
+    /// root's entries
+    pub max_depth: Option<usize>,
+    pub max_results: usize,
+}
+
+impl Default for SearchOptions {
+    fn default() -> Self {
+        Self {
+            pattern: String::new(),
+            exclude: Vec::new(),
+            max_depth: None,
+            max_results: DEFAULT_MAX_RESULTS,
+        }
+    }
+}
+
+/// A compiled glob, matched against the path relative to the search root when
+/// it contains a `/` and against the file name otherwise.
+pub(crate) struct PathGlob {
+    matcher: GlobMatcher,
+    anchored: bool,
+}
+
+impl PathGlob {
+    pub(crate) fn new(pattern: &str) -> Result<Self> {
+        let glob = GlobBuilder::new(pattern)
+            .literal_separator(true)
+            .build()
+            .map_err(|e| anyhow!("Invalid glob pattern \"{pattern}\": {e}"))?;
+        Ok(Self {
+            matcher: glob.compile_matcher(),
+            anchored: pattern.contains('/'),
+        })
+    }
+
+    pub(crate) fn is_match(&self, relative: &Path) -> bool {
+        if self.anchored {
+            self.matcher.is_match(relative)
+        } else {
+            relative
+                .file_name()
+                .is_some_and(|name| self.matcher.is_match(name))
+        }
+    }
+}
+
+enum Matcher {
+    Substring(String),
+    Glob(PathGlob),
+}
+
+impl Matcher {
+    fn new(pattern: &str) -> Result<Self> {
+        if pattern.contains(['*', '?', '[', '{', '/']) {
+            Ok(Self::Glob(PathGlob::new(pattern)?))
+        } else {
+            Ok(Self::Substring(pattern.to_lowercase()))
+        }
+    }
+
+    fn is_match(&self, relative: &Path) -> bool {
+        match self {
+            Self::Substring(text) => relative
+                .file_name()
+                .is_some_and(|name| name.to_string_lossy().to_lowercase().contains(text)),
+            Self::Glob(glob) => glob.is_match(relative),
+        }
+    }
+}
+
+/// Search a directory tree for entries matching a pattern, returning their paths.
+///
+/// Entries are visited in name order, depth first. Excluded entries are skipped
+/// along with everything beneath them; an exclude pattern ending in `/**` also
+/// excludes the directory itself. Symbolic links that lead outside the sandbox
+/// are left out of the results. Links to directories inside it are followed,
+/// and each directory is searched only once, so link cycles end the descent
+/// rather than repeating it.
+pub fn search_files(sandbox: &Sandbox, root: &str, options: &SearchOptions) -> Result<Vec<String>> {
+    let root = sandbox.resolve(root)?;
+    if !root.is_dir() {
+        return Err(anyhow!("{} is not a directory", root.display()));
+    }
+    if options.max_depth == Some(0) {
+        return Err(anyhow!("max-depth must be at least 1"));
+    }
+    let matcher = Matcher::new(&options.pattern)?;
+    let exclude_patterns: Vec<&str> = options
+        .exclude
+        .iter()
+        .flat_map(|pattern| [pattern.as_str(), pattern.trim_end_matches("/**")])
+        .filter(|pattern| !pattern.is_empty())
+        .collect();
+    let excludes = exclude_patterns
+        .iter()
+        .map(|pattern| PathGlob::new(pattern))
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut matches = Vec::new();
+    Walk::new(sandbox, &root, &excludes, options.max_depth)
+        .run(&mut |path, relative, _| {
+            if matcher.is_match(relative) {
+                matches.push(path.to_string_lossy().into_owned());
+            }
+            matches.len() < options.max_results
+        })
+        .map_err(|e| anyhow!("Failed to search directory: {e}"))?;
+    Ok(matches)
+}
+
+/// The callback given to [`Walk::run`].
+pub(crate) type Visit<'f> = dyn FnMut(&Path, &Path, &Path) -> bool + 'f;
+
+/// A depth-first walk of a directory tree in name order, shared by file search
+/// and grep.
+///
+/// Every entry is resolved through the sandbox, and entries that resolve
+/// outside it, such as symbolic links leading elsewhere, are left out, so no
+/// walker can hand them to its caller. Excluded entries are skipped along with
+/// everything beneath them. Symbolic links to directories are followed only the
+/// first time their target is reached. Subdirectories that cannot be read are
+/// skipped; only an unreadable root is an error.
+pub(crate) struct Walk<'a> {
+    sandbox: &'a Sandbox,
+    root: &'a Path,
+    excludes: &'a [PathGlob],
+    max_depth: Option<usize>,
+    visited: HashSet<PathBuf>,
+}
+
+impl<'a> Walk<'a> {
+    /// A walk of `root`, which must already be resolved.
+    pub(crate) fn new(
+        sandbox: &'a Sandbox,
+        root: &'a Path,
+        excludes: &'a [PathGlob],
+        max_depth: Option<usize>,
+    ) -> Self {
+        Self {
+            sandbox,
+            root,
+            excludes,
+            max_depth,
+            visited: HashSet::from([root.to_path_buf()]),
+        }
+    }
+
+    /// Call `visit` with the path of each entry, the path relative to the root
+    /// and the resolved path to read it through, stopping early when it returns
+    /// false.
+    pub(crate) fn run(&mut self, visit: &mut Visit<'_>) -> Result<()> {
+        self.directory(self.root, 1, visit).map(|_| ())
+    }
+
+    fn directory(&mut self, dir: &Path, depth: usize, visit: &mut Visit<'_>) -> Result<bool> {
+        let entries = match fs::read_dir(dir) {
+            Ok(entries) => entries,
+            Err(_) if dir != self.root => return Ok(true),
+            Err(e) => return Err(e.into()),
+        };
+        let mut paths: Vec<PathBuf> = entries
+            .filter_map(|entry| Some(entry.ok()?.path()))
+            .collect();
+        paths.sort();
+
+        for path in paths {
+            let relative = path.strip_prefix(self.root).unwrap_or(&path);
+            if self.excludes.iter().any(|glob| glob.is_match(relative)) {
+                continue;
+            }
+            let Ok(resolved) = self.sandbox.resolve(&path.to_string_lossy()) else {
+                continue;
+            };
+            if !visit(&path, relative, &resolved) {
+                return Ok(false);
+            }
+            if !resolved.is_dir() || self.max_depth.is_some_and(|max| depth >= max) {
+                continue;
+            }
+            if self.visited.insert(resolved) && !self.directory(&path, depth + 1, visit)? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+}
@@ -0,0 +1,126 @@
+//! Operations that modify the file system. Each one checks that the sandbox
+//! allows writing and resolves every path through it.
+
+use std::{
+    fs,
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Result, anyhow};
+
+use crate::sandbox::Sandbox;
+
+/// Write `content` to a file, creating it or replacing its contents, or append
+/// to the end of it. Missing parent directories are not created.
+pub fn write_file(sandbox: &Sandbox, path: &str, content: &str, append: bool) -> Result<()> {
+    sandbox.check_writable()?;
+    let path = sandbox.resolve(path)?;
+    if path.is_dir() {
+        return Err(anyhow!("{} is a directory", path.display()));
+    }
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .write(true)
+        .append(append)
+        .truncate(!append)
+        .open(&path)
+        .map_err(|e| anyhow!("Failed to open {} for writing: {e}", path.display()))?;
+    file.write_all(content.as_bytes())
+        .map_err(|e| anyhow!("Failed to write {}: {e}", path.display()))
+}
+
+/// Create a directory and any missing parents. An existing directory is not an error.
+pub fn create_directory(sandbox: &Sandbox, path: &str) -> Result<()> {
+    sandbox.check_writable()?;
+    let path = sandbox.resolve(path)?;
+    fs::create_dir_all(&path)
+        .map_err(|e| anyhow!("Failed to create directory {}: {e}", path.display()))
+}
+
+/// Move or rename a file or directory. The destination must not exist.
+pub fn move_path(sandbox: &Sandbox, source: &str, destination: &str) -> Result<()> {
+    sandbox.check_writable()?;
+    let source = existing(sandbox.resolve_no_follow(source)?)?;
+    refuse_root(sandbox, &source, "move")?;
+    let destination = vacant(sandbox.resolve(destination)?)?;
+    fs::rename(&source, &destination).map_err(|e| {
+        anyhow!(
+            "Failed to move {} to {}: {e}",
+            source.display(),
+            destination.display()
+        )
+    })
+}
+
+/// Copy a file. The destination must not exist.
+pub fn copy_file(sandbox: &Sandbox, source: &str, destination: &str) -> Result<()> {
+    sandbox.check_writable()?;
+    let source = existing(sandbox.resolve(source)?)?;
+    if source.is_dir() {
+        return Err(anyhow!(
+            "{} is a directory; only files can be copied",
+            source.display()
+        ));
+    }
+    let destination = vacant(sandbox.resolve(destination)?)?;
+    fs::copy(&source, &destination).map(|_| ()).map_err(|e| {
+        anyhow!(
+            "Failed to copy {} to {}: {e}",
+            source.display(),
+            destination.display()
+        )
+    })
+}
+
+/// Delete a file, symbolic link or empty directory, or a directory and all of
+/// its contents when `recursive` is set. Symbolic links are removed, not followed.
+pub fn delete(sandbox: &Sandbox, path: &str, recursive: bool) -> Result<()> {
+    sandbox.check_writable()?;
+    let path = existing(sandbox.resolve_no_follow(path)?)?;
+    refuse_root(sandbox, &path, "delete")?;
+    let metadata =
+        fs::symlink_metadata(&path).map_err(|e| anyhow!("Failed to get metadata: {e}"))?;
+
+    let result = if !metadata.is_dir() {
+        fs::remove_file(&path)
+    } else if recursive {
+        fs::remove_dir_all(&path)
+    } else {
+        fs::remove_dir(&path).map_err(|e| {
+            if fs::read_dir(&path).is_ok_and(|mut entries| entries.next().is_some()) {
+                std::io::Error::other(
+                    "directory is not empty; set recursive to delete its contents",
+                )
+            } else {
+                e
+            }
+        })
+    };
+    result.map_err(|e| anyhow!("Failed to delete {}: {e}", path.display()))
+}
+
+fn existing(path: PathBuf) -> Result<PathBuf> {
+    if fs::symlink_metadata(&path).is_err() {
+        return Err(anyhow!("{} does not exist", path.display()));
+    }
+    Ok(path)
+}
+
+fn vacant(path: PathBuf) -> Result<PathBuf> {
+    if fs::symlink_metadata(&path).is_ok() {
+        return Err(anyhow!("{} already exists", path.display()));
+    }
+    Ok(path)
+}
+
+/// Refuse to move or delete one of the allowed directories themselves.
+fn refuse_root(sandbox: &Sandbox, path: &Path, action: &str) -> Result<()> {
+    if sandbox.is_root(path) {
+        return Err(anyhow!(
+            "Refusing to {action} {}, which is an allowed directory",
+            path.display()
+        ));
+    }
+    Ok(())
+}
@@ -0,0 +1,45 @@
+//! Temporary directory trees shared by the filesystem tests.
+
+#![allow(dead_code)]
+
+use std::{fs, os::unix::fs::symlink};
+
+use filesystem::sandbox::Sandbox;
+use tempfile::TempDir;
+
+/// An entry of a test tree, with its path relative to the tree's root.
+pub enum Entry {
+    File(&'static str, &'static [u8]),
+    Dir(&'static str),
+    /// A symbolic link and the target it points to, as written in the link
+    Link(&'static str, &'static str),
+}
+
+/// Create a temporary directory holding `entries`, creating parent directories
+/// as needed.
+pub fn tree(entries: &[Entry]) -> TempDir {
+    let tmp = tempfile::tempdir().unwrap();
+    let root = tmp.path();
+    for entry in entries {
+        let (Entry::File(path, _) | Entry::Dir(path) | Entry::Link(path, _)) = entry;
+        let path = root.join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        match entry {
+            Entry::File(_, content) => fs::write(&path, content).unwrap(),
+            Entry::Dir(_) => fs::create_dir_all(&path).unwrap(),
+            Entry::Link(_, target) => symlink(target, &path).unwrap(),
+        }
+    }
+    tmp
+}
+
+/// A sandbox allowing only `allowed`, relative to the tree's root; an empty
+/// path allows the whole tree.
+pub fn sandbox(tmp: &TempDir, allowed: &str) -> Sandbox {
+    Sandbox::new([tmp.path().join(allowed)]).unwrap()
+}
+
+/// The absolute path of `relative` in the tree, as the string the API takes.
+pub fn path(tmp: &TempDir, relative: &str) -> String {
+    tmp.path().join(relative).to_string_lossy().into_owned()
+}
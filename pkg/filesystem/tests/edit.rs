@@ -0,0 +1,93 @@
+//! Tests for text replacements with `edit_file`.
+#![cfg(unix)]
+
+mod common;
+
+use std::fs;
+
+use common::{
+    Entry::{self, *},
+    path, sandbox, tree,
+};
+use filesystem::edit::{Edit, apply_edits, edit_file};
+
+const SOURCE: &str = "fn main() {\n    let name = \"world\";\n    println!(\"hello {name}\");\n}\n";
+
+fn edit(old_text: &str, new_text: &str) -> Edit {
+    Edit {
+        old_text: old_text.to_string(),
+        new_text: new_text.to_string(),
+    }
+}
+
+const TREE: &[Entry] = &[File("main.rs", SOURCE.as_bytes())];
+
+#[test]
+fn applies_edits_in_order_and_returns_a_diff() {
+    let tmp = tree(TREE);
+    let sandbox = sandbox(&tmp, "");
+    let path = path(&tmp, "main.rs");
+    let edits = [
+        edit("\"world\"", "\"there\""),
+        edit("hello {name}", "hi {name}!"),
+    ];
+    let diff = edit_file(&sandbox, &path, &edits, false).unwrap();
+    assert_eq!(
+        diff,
+        format!(
+            "--- {path}\n+++ {path}\n@@ -1,4 +1,4 @@\n fn main() {{\n-    let name = \"world\";\n-    println!(\"hello {{name}}\");\n+    let name = \"there\";\n+    println!(\"hi {{name}}!\");\n }}\n"
+        )
+    );
+    assert_eq!(
+        fs::read_to_string(&path).unwrap(),
+        "fn main() {\n    let name = \"there\";\n    println!(\"hi {name}!\");\n}\n"
+    );
+}
+
+#[test]
+fn dry_run_leaves_the_file_unchanged() {
+    let tmp = tree(TREE);
+    let sandbox = sandbox(&tmp, "");
+    let path = path(&tmp, "main.rs");
+    let sandbox = sandbox.with_read_only(true);
+    let diff = edit_file(&sandbox, &path, &[edit("main", "start")], true).unwrap();
+    assert!(diff.contains("+fn start() {"), "{diff}");
+    assert_eq!(fs::read_to_string(&path).unwrap(), SOURCE);
+
+    let err = edit_file(&sandbox, &path, &[edit("main", "start")], false).unwrap_err();
+    assert!(err.to_string().starts_with("Read-only mode"), "{err}");
+}
+
+#[test]
+fn rejects_missing_and_ambiguous_text_without_writing() {
+    let tmp = tree(TREE);
+    let sandbox = sandbox(&tmp, "");
+    let path = path(&tmp, "main.rs");
+    let err = edit_file(
+        &sandbox,
+        &path,
+        &[edit("main", "start"), edit("goodbye", "")],
+        false,
+    )
+    .unwrap_err();
+    assert_eq!(err.to_string(), "Edit 2: old text not found");
+
+    let err = edit_file(&sandbox, &path, &[edit("name", "who")], false).unwrap_err();
+    assert!(
+        err.to_string()
+            .starts_with("Edit 1: old text matches 2 times"),
+        "{err}"
+    );
+    assert_eq!(fs::read_to_string(&path).unwrap(), SOURCE);
+}
+
+#[test]
+fn overlapping_matches_are_ambiguous() {
+    let err = apply_edits("aaa", &[edit("aa", "b")]).unwrap_err();
+    assert!(
+        err.to_string()
+            .starts_with("Edit 1: old text matches 2 times"),
+        "{err}"
+    );
+    assert_eq!(apply_edits("ééa", &[edit("éa", "x")]).unwrap(), "éx");
+}
@@ -0,0 +1,159 @@
+//! Tests for regular expression search through file contents.
+#![cfg(unix)]
+
+mod common;
+
+use common::{
+    Entry::{self, *},
+    path, sandbox, tree,
+};
+use filesystem::{
+    grep::{GrepOptions, grep},
+    sandbox::Sandbox,
+};
+use tempfile::TempDir;
+
+const TREE: &[Entry] = &[
+    // Uses `parse` twice
+    File(
+        "src/lib.rs",
+        b"// parser\npub fn parse(input: &str) {}\n\npub fn run() {\n    parse(\"x\");\n}\n",
+    ),
+    // Calls Parse once
+    File("src/main.rs", b"fn main() {\n    lib::Parse();\n}\n"),
+    File("notes.md", b"parse everything\n"),
+    // Contains parse after a NUL byte
+    File("data.bin", b"\0\x01parse\n"),
+];
+
+fn found(tmp: &TempDir, sandbox: &Sandbox, options: &GrepOptions) -> Vec<(String, usize)> {
+    let root = sandbox.resolve(&tmp.path().to_string_lossy()).unwrap();
+    grep(sandbox, &tmp.path().to_string_lossy(), options)
+        .unwrap()
+        .into_iter()
+        .map(|m| {
+            let path = m
+                .path
+                .strip_prefix(&format!("{}/", root.display()))
+                .unwrap();
+            (path.to_string(), m.line_number)
+        })
+        .collect()
+}
+
+#[test]
+fn finds_lines_in_included_files() {
+    let tmp = tree(TREE);
+    let sandbox = sandbox(&tmp, "");
+    let options = GrepOptions {
+        pattern: r"\bparse\(".into(),
+        include: vec!["**/*.rs".into()],
+        ..Default::default()
+    };
+    assert_eq!(
+        found(&tmp, &sandbox, &options),
+        [("src/lib.rs".to_string(), 2), ("src/lib.rs".to_string(), 5)]
+    );
+}
+
+#[test]
+fn ignores_case_and_skips_binary_files() {
+    let tmp = tree(TREE);
+    let sandbox = sandbox(&tmp, "");
+    let options = GrepOptions {
+        pattern: "parse".into(),
+        case_sensitive: false,
+        ..Default::default()
+    };
+    assert_eq!(
+        found(&tmp, &sandbox, &options),
+        [
+            ("notes.md".to_string(), 1),
+            ("src/lib.rs".to_string(), 1),
+            ("src/lib.rs".to_string(), 2),
+            ("src/lib.rs".to_string(), 5),
+            ("src/main.rs".to_string(), 2),
+        ]
+    );
+}
+
+#[test]
+fn returns_context_lines() {
+    let tmp = tree(TREE);
+    let sandbox = sandbox(&tmp, "");
+    let options = GrepOptions {
+        pattern: "parse\\(\"".into(),
+        context: 2,
+        ..Default::default()
+    };
+    let matches = grep(&sandbox, &path(&tmp, "src/lib.rs"), &options).unwrap();
+    assert_eq!(matches.len(), 1);
+    assert_eq!(matches[0].line, "    parse(\"x\");");
+    assert_eq!(matches[0].context_before, ["", "pub fn run() {"]);
+    assert_eq!(matches[0].context_after, ["}"]);
+}
+
+#[test]
+fn huge_context_returns_the_whole_file() {
+    let tmp = tree(TREE);
+    let sandbox = sandbox(&tmp, "");
+    let options = GrepOptions {
+        pattern: "parse\\(\"".into(),
+        context: usize::MAX,
+        ..Default::default()
+    };
+    let matches = grep(&sandbox, &path(&tmp, "src/lib.rs"), &options).unwrap();
+    assert_eq!(matches[0].context_before.len(), 4);
+    assert_eq!(matches[0].context_after, ["}"]);
+}
+
+#[test]
+fn stops_at_the_maximum_match_count() {
+    let tmp = tree(TREE);
+    let sandbox = sandbox(&tmp, "");
+    let options = GrepOptions {
+        pattern: "parse".into(),
+        max_matches: 2,
+        ..Default::default()
+    };
+    assert_eq!(
+        found(&tmp, &sandbox, &options),
+        [("notes.md".to_string(), 1), ("src/lib.rs".to_string(), 1)]
+    );
+}
+
+#[test]
+fn rejects_invalid_patterns() {
+    let tmp = tree(TREE);
+    let sandbox = sandbox(&tmp, "");
+    let options = GrepOptions {
+        pattern: "parse(".into(),
+        ..Default::default()
+    };
+    let err = grep(&sandbox, &tmp.path().to_string_lossy(), &options).unwrap_err();
+    assert!(
+        err.to_string().starts_with("Invalid regular expression"),
+        "{err}"
+    );
+}
+
+#[test]
+fn skips_files_linked_from_outside_the_sandbox() {
+    let tmp = tree(&[
+        File("allowed/notes.txt", b"public password\n"),
+        Link("allowed/leak.txt", "../secret/key.txt"),
+        File("secret/key.txt", b"TOPSECRET password\n"),
+    ]);
+    let sandbox = sandbox(&tmp, "allowed");
+
+    let options = GrepOptions {
+        pattern: "password".into(),
+        ..Default::default()
+    };
+    let matches = grep(&sandbox, &path(&tmp, "allowed"), &options).unwrap();
+    let lines: Vec<&str> = matches.iter().map(|m| m.line.as_str()).collect();
+    assert_eq!(lines, ["public password"]);
+
+    let err = grep(&sandbox, &path(&tmp, "allowed/leak.txt"), &options).unwrap_err();
+    assert!(err.to_string().starts_with("Access denied: "), "{err}");
+}
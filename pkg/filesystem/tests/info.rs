@@ -0,0 +1,71 @@
+//! Tests for structured file metadata.
+#![cfg(unix)]
+
+mod common;
+
+use std::path::PathBuf;
+
+use common::{
+    Entry::{self, *},
+    path, sandbox, tree,
+};
+use filesystem::info::{FileKind, file_info, list_directory_detailed};
+
+const TREE: &[Entry] = &[
+    Dir("sub"),
+    File("notes.txt", b"hello"),
+    Link("link", "notes.txt"),
+];
+
+#[test]
+fn describes_a_file() {
+    let tmp = tree(TREE);
+    let sandbox = sandbox(&tmp, "");
+    let info = file_info(&sandbox, &path(&tmp, "notes.txt")).unwrap();
+    assert_eq!(info.kind, FileKind::File);
+    assert_eq!(info.size, 5);
+    assert!(!info.readonly);
+    assert_eq!(info.symlink_target, None);
+    let modified = info.modified.unwrap();
+    assert!(
+        chrono::DateTime::parse_from_rfc3339(&modified).is_ok(),
+        "{modified}"
+    );
+    assert!(modified.ends_with('Z'), "{modified}");
+}
+
+#[test]
+fn describes_a_symlink_itself() {
+    let tmp = tree(TREE);
+    let sandbox = sandbox(&tmp, "");
+    let info = file_info(&sandbox, &path(&tmp, "link")).unwrap();
+    assert_eq!(info.kind, FileKind::Symlink);
+    assert_eq!(info.symlink_target, Some(PathBuf::from("notes.txt")));
+}
+
+#[test]
+fn lists_entries_sorted_by_path() {
+    let tmp = tree(TREE);
+    let sandbox = sandbox(&tmp, "");
+    let infos = list_directory_detailed(&sandbox, &path(&tmp, "")).unwrap();
+    let entries: Vec<(String, FileKind)> = infos
+        .iter()
+        .map(|info| {
+            let name = info
+                .path
+                .file_name()
+                .unwrap()
+                .to_string_lossy()
+                .into_owned();
+            (name, info.kind)
+        })
+        .collect();
+    assert_eq!(
+        entries,
+        [
+            ("link".to_string(), FileKind::Symlink),
+            ("notes.txt".to_string(), FileKind::File),
+            ("sub".to_string(), FileKind::Directory),
+        ]
+    );
+}
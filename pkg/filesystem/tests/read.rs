@@ -0,0 +1,173 @@
+//! Tests for reading line ranges and raw bytes.
+#![cfg(unix)]
+
+mod common;
+
+use std::fs;
+
+use base64::{Engine, engine::general_purpose::STANDARD};
+use common::{
+    Entry::{self, *},
+    path, sandbox, tree,
+};
+use filesystem::read::{LineRange, MAX_TEXT_FILE_SIZE, read_bytes, read_lines, read_text};
+
+const TREE: &[Entry] = &[
+    File(
+        "app.log",
+        b"line 1\nline 2\nline 3\nline 4\nline 5\nline 6\nline 7\nline 8\nline 9\nline 10\n",
+    ),
+    File("image.png", b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR"),
+    File("latin1.txt", b"caf\xe9\n"),
+];
+
+#[test]
+fn reads_line_windows_head_and_tail() {
+    let tmp = tree(TREE);
+    let sandbox = sandbox(&tmp, "");
+    let log = path(&tmp, "app.log");
+    let window = LineRange::Lines {
+        offset: 3,
+        limit: Some(2),
+    };
+    assert_eq!(
+        read_lines(&sandbox, &log, window).unwrap(),
+        "line 4\nline 5\n"
+    );
+    let rest = LineRange::Lines {
+        offset: 8,
+        limit: None,
+    };
+    assert_eq!(
+        read_lines(&sandbox, &log, rest).unwrap(),
+        "line 9\nline 10\n"
+    );
+    assert_eq!(
+        read_lines(&sandbox, &log, LineRange::Head(2)).unwrap(),
+        "line 1\nline 2\n"
+    );
+    assert_eq!(
+        read_lines(&sandbox, &log, LineRange::Tail(3)).unwrap(),
+        "line 8\nline 9\nline 10\n"
+    );
+    assert_eq!(
+        read_lines(&sandbox, &log, LineRange::Tail(50))
+            .unwrap()
+            .lines()
+            .count(),
+        10
+    );
+}
+
+#[test]
+fn huge_limits_do_not_overflow() {
+    let tmp = tree(TREE);
+    let sandbox = sandbox(&tmp, "");
+    let log = path(&tmp, "app.log");
+    let unbounded = LineRange::Lines {
+        offset: 8,
+        limit: Some(usize::MAX),
+    };
+    assert_eq!(
+        read_lines(&sandbox, &log, unbounded).unwrap(),
+        "line 9
+line 10
+"
+    );
+    let past = LineRange::Lines {
+        offset: usize::MAX,
+        limit: Some(usize::MAX),
+    };
+    let err = read_lines(&sandbox, &log, past).unwrap_err();
+    assert!(
+        err.to_string()
+            .starts_with(&format!("Line offset {} is past the end", usize::MAX)),
+        "{err}"
+    );
+}
+
+#[test]
+fn rejects_an_offset_past_the_end() {
+    let tmp = tree(TREE);
+    let sandbox = sandbox(&tmp, "");
+    let past = LineRange::Lines {
+        offset: 11,
+        limit: None,
+    };
+    let err = read_lines(&sandbox, &path(&tmp, "app.log"), past).unwrap_err();
+    assert!(
+        err.to_string()
+            .starts_with("Line offset 11 is past the end"),
+        "{err}"
+    );
+}
+
+#[test]
+fn refuses_binary_files_as_text() {
+    let tmp = tree(TREE);
+    let sandbox = sandbox(&tmp, "");
+    let image = path(&tmp, "image.png");
+    for err in [
+        read_text(&sandbox, &image).unwrap_err(),
+        read_lines(&sandbox, &image, LineRange::Head(1)).unwrap_err(),
+    ] {
+        assert!(
+            err.to_string()
+                .ends_with("is a binary file (16 bytes) and cannot be read as text; use read-bytes to read it as base64"),
+            "{err}"
+        );
+    }
+    let err = read_text(&sandbox, &path(&tmp, "latin1.txt")).unwrap_err();
+    assert!(err.to_string().contains("is not valid UTF-8"), "{err}");
+    assert_eq!(
+        read_lines(&sandbox, &path(&tmp, "latin1.txt"), LineRange::Head(1)).unwrap(),
+        "caf\u{fffd}\n"
+    );
+}
+
+#[test]
+fn reads_bytes_as_base64() {
+    let tmp = tree(TREE);
+    let sandbox = sandbox(&tmp, "");
+    let image = path(&tmp, "image.png");
+    let bytes = read_bytes(&sandbox, &image, 1, 3).unwrap();
+    assert_eq!(STANDARD.decode(&bytes.data).unwrap(), b"PNG");
+    assert_eq!(bytes.total_size, 16);
+
+    let end = read_bytes(&sandbox, &image, 12, 100).unwrap();
+    assert_eq!(STANDARD.decode(&end.data).unwrap(), b"IHDR");
+
+    let err = read_bytes(&sandbox, &image, 17, 1).unwrap_err();
+    assert!(
+        err.to_string().starts_with("Offset 17 is past the end"),
+        "{err}"
+    );
+}
+
+#[test]
+fn refuses_files_too_large_to_read_whole() {
+    let tmp = tree(TREE);
+    let sandbox = sandbox(&tmp, "");
+    let large = path(&tmp, "large.txt");
+    fs::write(&large, "a".repeat(MAX_TEXT_FILE_SIZE as usize)).unwrap();
+    assert_eq!(
+        read_text(&sandbox, &large).unwrap().len(),
+        MAX_TEXT_FILE_SIZE as usize
+    );
+
+    fs::write(&large, "a".repeat(MAX_TEXT_FILE_SIZE as usize + 1)).unwrap();
+    let err = read_text(&sandbox, &large).unwrap_err();
+    assert!(
+        err.to_string().ends_with(&format!(
+            "is too large to read whole ({} bytes, limit 10 MiB); use read-file-range to read it in parts",
+            MAX_TEXT_FILE_SIZE + 1
+        )),
+        "{err}"
+    );
+    assert_eq!(
+        read_lines(&sandbox, &large, LineRange::Head(1))
+            .unwrap()
+            .len(),
+        MAX_TEXT_FILE_SIZE as usize + 1
+    );
+}
@@ -0,0 +1,128 @@
+//! Tests for path resolution and the allowed-directories check.
+#![cfg(unix)]
+
+mod common;
+
+use std::{fs, os::unix::fs::symlink};
+
+use common::{
+    Entry::{self, *},
+    path, sandbox, tree,
+};
+use filesystem::sandbox::Sandbox;
+
+/// An allowed directory, a sibling sharing its prefix and a secret beside them.
+const TREE: &[Entry] = &[
+    File("allowed/notes.txt", b"notes"),
+    Dir("allowed/sub"),
+    Link("allowed/inner", "sub"),
+    Link("allowed/escape", "../secret"),
+    Dir("allowed-evil"),
+    File("secret/key.txt", b"key"),
+];
+
+fn assert_denied(sandbox: &Sandbox, path: &str) {
+    let error = sandbox.resolve(path).unwrap_err().to_string();
+    assert!(error.starts_with("Access denied: "), "{path}: {error}");
+}
+
+#[test]
+fn allows_paths_inside_roots() {
+    let tmp = tree(TREE);
+    let sandbox = sandbox(&tmp, "allowed");
+    let notes = sandbox.resolve(&path(&tmp, "allowed/notes.txt")).unwrap();
+    assert_eq!(fs::read_to_string(notes).unwrap(), "notes");
+    assert!(sandbox.resolve(&path(&tmp, "allowed")).is_ok());
+    assert!(
+        sandbox
+            .resolve(&path(&tmp, "allowed/./sub/../notes.txt"))
+            .is_ok()
+    );
+}
+
+#[test]
+fn allows_paths_that_do_not_exist_yet() {
+    let tmp = tree(TREE);
+    let sandbox = sandbox(&tmp, "allowed");
+    let resolved = sandbox
+        .resolve(&path(&tmp, "allowed/new/dir/file.txt"))
+        .unwrap();
+    assert!(resolved.ends_with("allowed/new/dir/file.txt"));
+    assert_denied(&sandbox, &path(&tmp, "elsewhere/file.txt"));
+}
+
+#[test]
+fn rejects_parent_directory_traversal() {
+    let tmp = tree(TREE);
+    let sandbox = sandbox(&tmp, "allowed");
+    assert_denied(&sandbox, &path(&tmp, "allowed/../secret/key.txt"));
+    assert_denied(&sandbox, &path(&tmp, "allowed/sub/../../secret"));
+    assert_denied(&sandbox, &path(&tmp, "allowed/../../../../etc/passwd"));
+}
+
+#[test]
+fn rejects_sibling_with_shared_prefix() {
+    let tmp = tree(TREE);
+    assert_denied(&sandbox(&tmp, "allowed"), &path(&tmp, "allowed-evil"));
+}
+
+#[test]
+fn follows_symlinks_inside_roots() {
+    let tmp = tree(TREE);
+    let sandbox = sandbox(&tmp, "allowed");
+    let resolved = sandbox.resolve(&path(&tmp, "allowed/inner")).unwrap();
+    assert_eq!(
+        resolved,
+        sandbox.resolve(&path(&tmp, "allowed/sub")).unwrap()
+    );
+}
+
+#[test]
+fn rejects_symlink_escapes() {
+    let tmp = tree(TREE);
+    symlink(
+        tmp.path().join("secret"),
+        tmp.path().join("allowed/absolute"),
+    )
+    .unwrap();
+    let sandbox = sandbox(&tmp, "allowed");
+    assert_denied(&sandbox, &path(&tmp, "allowed/escape"));
+    assert_denied(&sandbox, &path(&tmp, "allowed/escape/key.txt"));
+    assert_denied(&sandbox, &path(&tmp, "allowed/absolute/key.txt"));
+    assert_denied(&sandbox, &path(&tmp, "allowed/inner/../escape/key.txt"));
+}
+
+#[test]
+fn resolves_link_itself_without_following() {
+    let tmp = tree(TREE);
+    let link = sandbox(&tmp, "allowed")
+        .resolve_no_follow(&path(&tmp, "allowed/escape"))
+        .unwrap();
+    assert!(link.ends_with("allowed/escape"));
+}
+
+#[test]
+fn rejects_symlink_loops() {
+    let tmp = tree(&[
+        Link("allowed/loop-a", "loop-b"),
+        Link("allowed/loop-b", "loop-a"),
+    ]);
+    let error = sandbox(&tmp, "allowed")
+        .resolve(&path(&tmp, "allowed/loop-a"))
+        .unwrap_err();
+    assert!(
+        error
+            .to_string()
+            .starts_with("Too many levels of symbolic links")
+    );
+}
+
+#[test]
+fn unrestricted_allows_everything() {
+    let tmp = tree(TREE);
+    assert!(
+        Sandbox::unrestricted()
+            .resolve(&path(&tmp, "allowed/escape/key.txt"))
+            .is_ok()
+    );
+}
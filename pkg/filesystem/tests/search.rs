@@ -0,0 +1,187 @@
+//! Tests for glob search with exclusions, limits and symlink cycles.
+#![cfg(unix)]
+
+mod common;
+
+use std::{
+    fs,
+    os::unix::fs::{PermissionsExt, symlink},
+};
+
+use common::{
+    Entry::{self, *},
+    sandbox, tree,
+};
+use filesystem::{
+    sandbox::Sandbox,
+    search::{SearchOptions, search_files},
+};
+use tempfile::TempDir;
+
+const TREE: &[Entry] = &[
+    File("Cargo.toml", b""),
+    File("README.md", b""),
+    File("src/lib.rs", b""),
+    File("src/util/mod.rs", b""),
+    File("target/debug/build.rs", b""),
+    File(".git/config", b""),
+    // A cycle back to the root
+    Link("src/util/loop", "../.."),
+];
+
+fn search(tmp: &TempDir, sandbox: &Sandbox, options: SearchOptions) -> Vec<String> {
+    let root = sandbox.resolve(&tmp.path().to_string_lossy()).unwrap();
+    search_files(sandbox, &tmp.path().to_string_lossy(), &options)
+        .unwrap()
+        .into_iter()
+        .map(|path| {
+            path.strip_prefix(&format!("{}/", root.display()))
+                .unwrap()
+                .to_string()
+        })
+        .collect()
+}
+
+#[test]
+fn matches_globs_against_relative_paths() {
+    let tmp = tree(TREE);
+    let sandbox = sandbox(&tmp, "");
+    let options = SearchOptions {
+        pattern: "**/*.rs".into(),
+        ..Default::default()
+    };
+    assert_eq!(
+        search(&tmp, &sandbox, options),
+        ["src/lib.rs", "src/util/mod.rs", "target/debug/build.rs"]
+    );
+}
+
+#[test]
+fn matches_plain_text_in_names_ignoring_case() {
+    let tmp = tree(TREE);
+    let sandbox = sandbox(&tmp, "");
+    let options = SearchOptions {
+        pattern: "readme".into(),
+        ..Default::default()
+    };
+    assert_eq!(search(&tmp, &sandbox, options), ["README.md"]);
+}
+
+#[test]
+fn skips_excluded_paths() {
+    let tmp = tree(TREE);
+    let sandbox = sandbox(&tmp, "");
+    let options = SearchOptions {
+        pattern: "*".into(),
+        exclude: vec!["target/**".into(), ".git".into(), "*.md".into()],
+        ..Default::default()
+    };
+    assert_eq!(
+        search(&tmp, &sandbox, options),
+        [
+            "Cargo.toml",
+            "src",
+            "src/lib.rs",
+            "src/util",
+            "src/util/loop",
+            "src/util/mod.rs"
+        ]
+    );
+}
+
+#[test]
+fn limits_depth_and_results() {
+    let tmp = tree(TREE);
+    let sandbox = sandbox(&tmp, "");
+    let options = SearchOptions {
+        pattern: "*".into(),
+        max_depth: Some(1),
+        ..Default::default()
+    };
+    assert_eq!(
+        search(&tmp, &sandbox, options),
+        [".git", "Cargo.toml", "README.md", "src", "target"]
+    );
+
+    let options = SearchOptions {
+        pattern: "**/*.rs".into(),
+        max_results: 2,
+        ..Default::default()
+    };
+    assert_eq!(
+        search(&tmp, &sandbox, options),
+        ["src/lib.rs", "src/util/mod.rs"]
+    );
+}
+
+#[test]
+fn does_not_follow_symlink_cycles() {
+    let tmp = tree(TREE);
+    let sandbox = sandbox(&tmp, "");
+    let options = SearchOptions {
+        pattern: "lib.rs".into(),
+        ..Default::default()
+    };
+    assert_eq!(search(&tmp, &sandbox, options), ["src/lib.rs"]);
+}
+
+#[test]
+fn rejects_invalid_globs() {
+    let tmp = tree(TREE);
+    let sandbox = sandbox(&tmp, "");
+    let options = SearchOptions {
+        pattern: "src/[".into(),
+        ..Default::default()
+    };
+    let err = search_files(&sandbox, &tmp.path().to_string_lossy(), &options).unwrap_err();
+    assert!(err.to_string().starts_with("Invalid glob pattern"), "{err}");
+}
+
+#[test]
+fn rejects_zero_depth() {
+    let tmp = tree(TREE);
+    let sandbox = sandbox(&tmp, "");
+    let options = SearchOptions {
+        pattern: "*".into(),
+        max_depth: Some(0),
+        ..Default::default()
+    };
+    let err = search_files(&sandbox, &tmp.path().to_string_lossy(), &options).unwrap_err();
+    assert_eq!(err.to_string(), "max-depth must be at least 1");
+}
+
+#[test]
+fn skips_unreadable_subdirectories() {
+    let tmp = tree(TREE);
+    let sandbox = sandbox(&tmp, "");
+    let target = tmp.path().join("target");
+    fs::set_permissions(&target, fs::Permissions::from_mode(0o000)).unwrap();
+    // Permissions do not apply to a privileged user
+    let readable = fs::read_dir(&target).is_ok();
+    let options = SearchOptions {
+        pattern: "**/*.rs".into(),
+        ..Default::default()
+    };
+    let found = search(&tmp, &sandbox, options);
+    fs::set_permissions(&target, fs::Permissions::from_mode(0o755)).unwrap();
+    if !readable {
+        assert_eq!(found, ["src/lib.rs", "src/util/mod.rs"]);
+    }
+}
+
+#[test]
+fn leaves_out_links_outside_the_sandbox() {
+    let tmp = tree(TREE);
+    let sandbox = sandbox(&tmp, "");
+    let outside = tree(&[File("key.rs", b"secret")]);
+    symlink(outside.path().join("key.rs"), tmp.path().join("src/key.rs")).unwrap();
+    symlink(outside.path(), tmp.path().join("src/outside")).unwrap();
+    let options = SearchOptions {
+        pattern: "**/*.rs".into(),
+        ..Default::default()
+    };
+    assert_eq!(
+        search(&tmp, &sandbox, options),
+        ["src/lib.rs", "src/util/mod.rs", "target/debug/build.rs"]
+    );
+}
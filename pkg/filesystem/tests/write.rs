@@ -0,0 +1,143 @@
+//! Tests for the operations that modify files.
+#![cfg(unix)]
+
+mod common;
+
+use std::fs;
+
+use common::{
+    Entry::{self, *},
+    path, sandbox, tree,
+};
+use filesystem::write;
+
+/// An allowed directory and a secret directory beside it.
+const TREE: &[Entry] = &[
+    File("allowed/notes.txt", b"notes"),
+    File("allowed/sub/a.txt", b"a"),
+    Link("allowed/escape", "../secret"),
+    File("secret/key.txt", b"key"),
+];
+
+#[test]
+fn writes_and_appends() {
+    let tmp = tree(TREE);
+    let sandbox = sandbox(&tmp, "allowed");
+    let file = path(&tmp, "allowed/new.txt");
+    write::write_file(&sandbox, &file, "one\n", false).unwrap();
+    write::write_file(&sandbox, &file, "two\n", true).unwrap();
+    assert_eq!(fs::read_to_string(&file).unwrap(), "one\ntwo\n");
+    write::write_file(&sandbox, &file, "three\n", false).unwrap();
+    assert_eq!(fs::read_to_string(&file).unwrap(), "three\n");
+}
+
+#[test]
+fn refuses_writes_outside_the_sandbox() {
+    let tmp = tree(TREE);
+    let sandbox = sandbox(&tmp, "allowed");
+    for target in [
+        "secret/new.txt",
+        "allowed/../secret/key.txt",
+        "allowed/escape/key.txt",
+    ] {
+        let err = write::write_file(&sandbox, &path(&tmp, target), "x", false).unwrap_err();
+        assert!(
+            err.to_string().starts_with("Access denied"),
+            "{target}: {err}"
+        );
+    }
+    assert_eq!(
+        fs::read_to_string(tmp.path().join("secret/key.txt")).unwrap(),
+        "key"
+    );
+}
+
+#[test]
+fn creates_nested_directories() {
+    let tmp = tree(TREE);
+    let sandbox = sandbox(&tmp, "allowed");
+    write::create_directory(&sandbox, &path(&tmp, "allowed/x/y/z")).unwrap();
+    assert!(tmp.path().join("allowed/x/y/z").is_dir());
+    write::create_directory(&sandbox, &path(&tmp, "allowed/x")).unwrap();
+}
+
+#[test]
+fn moves_and_copies_without_overwriting() {
+    let tmp = tree(TREE);
+    let sandbox = sandbox(&tmp, "allowed");
+    let notes = path(&tmp, "allowed/notes.txt");
+    let copy = path(&tmp, "allowed/sub/copy.txt");
+    write::copy_file(&sandbox, &notes, &copy).unwrap();
+    assert_eq!(fs::read_to_string(&copy).unwrap(), "notes");
+
+    let err = write::copy_file(&sandbox, &notes, &copy).unwrap_err();
+    assert!(err.to_string().ends_with("already exists"), "{err}");
+    let err = write::move_path(&sandbox, &notes, &copy).unwrap_err();
+    assert!(err.to_string().ends_with("already exists"), "{err}");
+
+    let moved = path(&tmp, "allowed/moved");
+    write::move_path(&sandbox, &path(&tmp, "allowed/sub"), &moved).unwrap();
+    assert!(tmp.path().join("allowed/moved/a.txt").is_file());
+
+    let err = write::move_path(&sandbox, &notes, &path(&tmp, "secret/notes.txt")).unwrap_err();
+    assert!(err.to_string().starts_with("Access denied"), "{err}");
+}
+
+#[test]
+fn deletes_directories_only_when_recursive() {
+    let tmp = tree(TREE);
+    let sandbox = sandbox(&tmp, "allowed");
+    let sub = path(&tmp, "allowed/sub");
+    let err = write::delete(&sandbox, &sub, false).unwrap_err();
+    assert!(err.to_string().contains("not empty"), "{err}");
+    write::delete(&sandbox, &sub, true).unwrap();
+    assert!(!tmp.path().join("allowed/sub").exists());
+
+    write::delete(&sandbox, &path(&tmp, "allowed/notes.txt"), false).unwrap();
+    let err = write::delete(&sandbox, &path(&tmp, "allowed/notes.txt"), false).unwrap_err();
+    assert!(err.to_string().ends_with("does not exist"), "{err}");
+}
+
+#[test]
+fn deletes_symlinks_without_following_them() {
+    let tmp = tree(TREE);
+    let sandbox = sandbox(&tmp, "allowed");
+    write::delete(&sandbox, &path(&tmp, "allowed/escape"), true).unwrap();
+    assert!(fs::symlink_metadata(tmp.path().join("allowed/escape")).is_err());
+    assert!(tmp.path().join("secret/key.txt").is_file());
+}
+
+#[test]
+fn refuses_to_delete_an_allowed_directory() {
+    let tmp = tree(TREE);
+    let sandbox = sandbox(&tmp, "allowed");
+    let err = write::delete(&sandbox, &path(&tmp, "allowed"), true).unwrap_err();
+    assert!(err.to_string().starts_with("Refusing to delete"), "{err}");
+    assert!(tmp.path().join("allowed/notes.txt").is_file());
+}
+
+#[test]
+fn read_only_refuses_every_modification() {
+    let tmp = tree(TREE);
+    let sandbox = sandbox(&tmp, "allowed");
+    let sandbox = sandbox.with_read_only(true);
+    let notes = path(&tmp, "allowed/notes.txt");
+    let results = [
+        write::write_file(&sandbox, &notes, "x", false),
+        write::write_file(&sandbox, &notes, "x", true),
+        write::create_directory(&sandbox, &path(&tmp, "allowed/new")),
+        write::copy_file(&sandbox, &notes, &path(&tmp, "allowed/copy.txt")),
+        write::move_path(&sandbox, &notes, &path(&tmp, "allowed/moved.txt")),
+        write::delete(&sandbox, &notes, false),
+    ];
+    for result in results {
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .starts_with("Read-only mode")
+        );
+    }
+    assert_eq!(fs::read_to_string(&notes).unwrap(), "notes");
+    assert!(!tmp.path().join("allowed/new").exists());
+}
@@ -1,6 +1,239 @@
 // Generated by `wit-bindgen` 0.41.0. DO NOT EDIT!
 // Options used:
 //   * runtime_path: "wit_bindgen_rt"
+/// How headings are written
+#[repr(u8)]
+#[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+pub enum HeadingStyle {
+    /// `# Heading`
+    Atx,
+    /// `Heading` underlined with `===` or `---`, for levels 1 and 2 only
+    Setext,
+}
+impl ::core::fmt::Debug for HeadingStyle {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        match self {
+            HeadingStyle::Atx => f.debug_tuple("HeadingStyle::Atx").finish(),
+            HeadingStyle::Setext => f.debug_tuple("HeadingStyle::Setext").finish(),
+        }
+    }
+}
+impl HeadingStyle {
+    #[doc(hidden)]
+    pub unsafe fn _lift(val: u8) -> HeadingStyle {
+        if !cfg!(debug_assertions) {
+            return ::core::mem::transmute(val);
+        }
+        match val {
+            0 => HeadingStyle::Atx,
+            1 => HeadingStyle::Setext,
+            _ => panic!("invalid enum discriminant"),
+        }
+    }
+}
+/// How links and images are written
+#[repr(u8)]
+#[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+pub enum LinkStyle {
+    /// `[text](url)`
+    Inline,
+    /// `[text][1]` with a `[1]: url` definition at the end of the document
+    Reference,
+}
+impl ::core::fmt::Debug for LinkStyle {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        match self {
+            LinkStyle::Inline => f.debug_tuple("LinkStyle::Inline").finish(),
+            LinkStyle::Reference => f.debug_tuple("LinkStyle::Reference").finish(),
+        }
+    }
+}
+impl LinkStyle {
+    #[doc(hidden)]
+    pub unsafe fn _lift(val: u8) -> LinkStyle {
+        if !cfg!(debug_assertions) {
+            return ::core::mem::transmute(val);
+        }
+        match val {
+            0 => LinkStyle::Inline,
+            1 => LinkStyle::Reference,
+            _ => panic!("invalid enum discriminant"),
+        }
+    }
+}
+/// Options for converting HTML to markdown
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct ConversionOptions {
+    /// How headings are written
+    pub heading_style: HeadingStyle,
+    /// Bullet used for unordered list items, one of '-', '*' or '+'
+    pub bullet: char,
+    /// Whether to keep images, or drop them from the output
+    pub keep_images: bool,
+    /// How links and images are written
+    pub link_style: LinkStyle,
+    /// Wrap paragraphs at this many characters, or never when zero
+    pub max_line_width: u32,
+    /// Whether to keep elements without a markdown equivalent as raw HTML
+    pub preserve_raw_html: bool,
+}
+impl ::core::fmt::Debug for ConversionOptions {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct("ConversionOptions")
+            .field("heading-style", &self.heading_style)
+            .field("bullet", &self.bullet)
+            .field("keep-images", &self.keep_images)
+            .field("link-style", &self.link_style)
+            .field("max-line-width", &self.max_line_width)
+            .field("preserve-raw-html", &self.preserve_raw_html)
+            .finish()
+    }
+}
+/// Options for sanitising HTML
+#[derive(Clone)]
+pub struct SanitizeOptions {
+    /// Tags to keep, or a default set of formatting, list, table and media tags when none.
+    /// Other tags are unwrapped, keeping their content
+    pub allowed_tags: Option<_rt::Vec<_rt::String>>,
+    /// Attributes to keep on allowed tags, or a default set when none.
+    /// Event handlers are always removed
+    pub allowed_attributes: Option<_rt::Vec<_rt::String>>,
+    /// Whether to also remove navigation, sidebars, page headers and footers,
+    /// forms and hidden elements
+    pub remove_boilerplate: bool,
+}
+impl ::core::fmt::Debug for SanitizeOptions {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct("SanitizeOptions")
+            .field("allowed-tags", &self.allowed_tags)
+            .field("allowed-attributes", &self.allowed_attributes)
+            .field("remove-boilerplate", &self.remove_boilerplate)
+            .finish()
+    }
+}
+/// A heading in a markdown document outline
+#[derive(Clone)]
+pub struct Heading {
+    /// Heading level, from 1 to 6
+    pub level: u8,
+    /// Heading text with inline markup removed
+    pub text: _rt::String,
+    /// GitHub-style anchor slug, unique within the document
+    pub anchor: _rt::String,
+    /// Index of the enclosing heading in the outline, if any
+    pub parent: Option<u32>,
+    /// Character offset where the heading starts
+    pub start: u32,
+    /// Character offset where the heading's section ends
+    pub end: u32,
+}
+impl ::core::fmt::Debug for Heading {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct("Heading")
+            .field("level", &self.level)
+            .field("text", &self.text)
+            .field("anchor", &self.anchor)
+            .field("parent", &self.parent)
+            .field("start", &self.start)
+            .field("end", &self.end)
+            .finish()
+    }
+}
+/// Whether a link points to a page or embeds an image
+#[repr(u8)]
+#[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+pub enum LinkKind {
+    Link,
+    Image,
+}
+impl ::core::fmt::Debug for LinkKind {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        match self {
+            LinkKind::Link => f.debug_tuple("LinkKind::Link").finish(),
+            LinkKind::Image => f.debug_tuple("LinkKind::Image").finish(),
+        }
+    }
+}
+impl LinkKind {
+    #[doc(hidden)]
+    pub unsafe fn _lift(val: u8) -> LinkKind {
+        if !cfg!(debug_assertions) {
+            return ::core::mem::transmute(val);
+        }
+        match val {
+            0 => LinkKind::Link,
+            1 => LinkKind::Image,
+            _ => panic!("invalid enum discriminant"),
+        }
+    }
+}
+/// A link or image found in a markdown document
+#[derive(Clone)]
+pub struct Link {
+    /// Whether this is a hyperlink or an image
+    pub kind: LinkKind,
+    /// Link text, or alt text for images
+    pub text: _rt::String,
+    /// Link destination, with reference-style links resolved
+    pub target: _rt::String,
+    /// Optional link title
+    pub title: _rt::String,
+}
+impl ::core::fmt::Debug for Link {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct("Link")
+            .field("kind", &self.kind)
+            .field("text", &self.text)
+            .field("target", &self.target)
+            .field("title", &self.title)
+            .finish()
+    }
+}
+/// A single entry of an RSS or Atom feed
+#[derive(Clone)]
+pub struct FeedEntry {
+    /// The entry title
+    pub title: _rt::String,
+    /// Link to the entry's web page
+    pub link: Option<_rt::String>,
+    /// Publication date, in RFC 3339 format when it could be parsed
+    pub published: Option<_rt::String>,
+    /// The entry author
+    pub author: Option<_rt::String>,
+    /// Summary of the entry as plain text
+    pub summary: Option<_rt::String>,
+}
+impl ::core::fmt::Debug for FeedEntry {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct("FeedEntry")
+            .field("title", &self.title)
+            .field("link", &self.link)
+            .field("published", &self.published)
+            .field("author", &self.author)
+            .field("summary", &self.summary)
+            .finish()
+    }
+}
+/// A feed rendered as a markdown digest along with its structured entries
+#[derive(Clone)]
+pub struct FeedDigest {
+    /// The feed title
+    pub title: _rt::String,
+    /// Entries in the order they appear in the feed
+    pub entries: _rt::Vec<FeedEntry>,
+    /// Markdown digest of the feed
+    pub markdown: _rt::String,
+}
+impl ::core::fmt::Debug for FeedDigest {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct("FeedDigest")
+            .field("title", &self.title)
+            .field("entries", &self.entries)
+            .field("markdown", &self.markdown)
+            .finish()
+    }
+}
 #[doc(hidden)]
 #[allow(non_snake_case)]
 pub unsafe fn _export_html_to_markdown_cabi<T: Guest>(arg0: *mut u8, arg1: usize) -> *mut u8 {
@@ -31,6 +264,89 @@ pub unsafe fn __post_return_html_to_markdown<T: Guest>(arg0: *mut u8) {
 }
 #[doc(hidden)]
 #[allow(non_snake_case)]
+pub unsafe fn _export_html_to_markdown_with_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+    arg2: i32,
+    arg3: i32,
+    arg4: i32,
+    arg5: i32,
+    arg6: i32,
+    arg7: i32,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let result1 = T::html_to_markdown_with(
+        _rt::string_lift(bytes0),
+        ConversionOptions {
+            heading_style: HeadingStyle::_lift(arg2 as u8),
+            bullet: _rt::char_lift(arg3 as u32),
+            keep_images: _rt::bool_lift(arg4 as u8),
+            link_style: LinkStyle::_lift(arg5 as u8),
+            max_line_width: arg6 as u32,
+            preserve_raw_html: _rt::bool_lift(arg7 as u8),
+        },
+    );
+    let ptr2 = (&raw mut _RET_AREA.0).cast::<u8>();
+    match result1 {
+        Ok(e) => {
+            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+            let vec3 = (e.into_bytes()).into_boxed_slice();
+            let ptr3 = vec3.as_ptr().cast::<u8>();
+            let len3 = vec3.len();
+            ::core::mem::forget(vec3);
+            *ptr2
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>() = len3;
+            *ptr2
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>() = ptr3.cast_mut();
+        }
+        Err(e) => {
+            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+            let vec4 = (e.into_bytes()).into_boxed_slice();
+            let ptr4 = vec4.as_ptr().cast::<u8>();
+            let len4 = vec4.len();
+            ::core::mem::forget(vec4);
+            *ptr2
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>() = len4;
+            *ptr2
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>() = ptr4.cast_mut();
+        }
+    };
+    ptr2
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_html_to_markdown_with<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>();
+            let l2 = *arg0
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+        _ => {
+            let l3 = *arg0
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>();
+            let l4 = *arg0
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            _rt::cabi_dealloc(l3, l4, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
 pub unsafe fn _export_json_to_markdown_cabi<T: Guest>(arg0: *mut u8, arg1: usize) -> *mut u8 {
     #[cfg(target_arch = "wasm32")]
     _rt::run_ctors_once();
@@ -38,6 +354,1123 @@ pub unsafe fn _export_json_to_markdown_cabi<T: Guest>(arg0: *mut u8, arg1: usize
     let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
     let result1 = T::json_to_markdown(_rt::string_lift(bytes0));
     let ptr2 = (&raw mut _RET_AREA.0).cast::<u8>();
+    match result1 {
+        Ok(e) => {
+            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+            let vec3 = (e.into_bytes()).into_boxed_slice();
+            let ptr3 = vec3.as_ptr().cast::<u8>();
+            let len3 = vec3.len();
+            ::core::mem::forget(vec3);
+            *ptr2
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>() = len3;
+            *ptr2
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>() = ptr3.cast_mut();
+        }
+        Err(e) => {
+            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+            let vec4 = (e.into_bytes()).into_boxed_slice();
+            let ptr4 = vec4.as_ptr().cast::<u8>();
+            let len4 = vec4.len();
+            ::core::mem::forget(vec4);
+            *ptr2
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>() = len4;
+            *ptr2
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>() = ptr4.cast_mut();
+        }
+    };
+    ptr2
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_json_to_markdown<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>();
+            let l2 = *arg0
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+        _ => {
+            let l3 = *arg0
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>();
+            let l4 = *arg0
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            _rt::cabi_dealloc(l3, l4, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_csv_to_markdown_cabi<T: Guest>(arg0: *mut u8, arg1: usize) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let result1 = T::csv_to_markdown(_rt::string_lift(bytes0));
+    let ptr2 = (&raw mut _RET_AREA.0).cast::<u8>();
+    match result1 {
+        Ok(e) => {
+            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+            let vec3 = (e.into_bytes()).into_boxed_slice();
+            let ptr3 = vec3.as_ptr().cast::<u8>();
+            let len3 = vec3.len();
+            ::core::mem::forget(vec3);
+            *ptr2
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>() = len3;
+            *ptr2
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>() = ptr3.cast_mut();
+        }
+        Err(e) => {
+            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+            let vec4 = (e.into_bytes()).into_boxed_slice();
+            let ptr4 = vec4.as_ptr().cast::<u8>();
+            let len4 = vec4.len();
+            ::core::mem::forget(vec4);
+            *ptr2
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>() = len4;
+            *ptr2
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>() = ptr4.cast_mut();
+        }
+    };
+    ptr2
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_csv_to_markdown<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>();
+            let l2 = *arg0
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+        _ => {
+            let l3 = *arg0
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>();
+            let l4 = *arg0
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            _rt::cabi_dealloc(l3, l4, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_tsv_to_markdown_cabi<T: Guest>(arg0: *mut u8, arg1: usize) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let result1 = T::tsv_to_markdown(_rt::string_lift(bytes0));
+    let ptr2 = (&raw mut _RET_AREA.0).cast::<u8>();
+    match result1 {
+        Ok(e) => {
+            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+            let vec3 = (e.into_bytes()).into_boxed_slice();
+            let ptr3 = vec3.as_ptr().cast::<u8>();
+            let len3 = vec3.len();
+            ::core::mem::forget(vec3);
+            *ptr2
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>() = len3;
+            *ptr2
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>() = ptr3.cast_mut();
+        }
+        Err(e) => {
+            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+            let vec4 = (e.into_bytes()).into_boxed_slice();
+            let ptr4 = vec4.as_ptr().cast::<u8>();
+            let len4 = vec4.len();
+            ::core::mem::forget(vec4);
+            *ptr2
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>() = len4;
+            *ptr2
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>() = ptr4.cast_mut();
+        }
+    };
+    ptr2
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_tsv_to_markdown<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>();
+            let l2 = *arg0
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+        _ => {
+            let l3 = *arg0
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>();
+            let l4 = *arg0
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            _rt::cabi_dealloc(l3, l4, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_yaml_to_markdown_cabi<T: Guest>(arg0: *mut u8, arg1: usize) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let result1 = T::yaml_to_markdown(_rt::string_lift(bytes0));
+    let ptr2 = (&raw mut _RET_AREA.0).cast::<u8>();
+    match result1 {
+        Ok(e) => {
+            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+            let vec3 = (e.into_bytes()).into_boxed_slice();
+            let ptr3 = vec3.as_ptr().cast::<u8>();
+            let len3 = vec3.len();
+            ::core::mem::forget(vec3);
+            *ptr2
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>() = len3;
+            *ptr2
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>() = ptr3.cast_mut();
+        }
+        Err(e) => {
+            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+            let vec4 = (e.into_bytes()).into_boxed_slice();
+            let ptr4 = vec4.as_ptr().cast::<u8>();
+            let len4 = vec4.len();
+            ::core::mem::forget(vec4);
+            *ptr2
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>() = len4;
+            *ptr2
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>() = ptr4.cast_mut();
+        }
+    };
+    ptr2
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_yaml_to_markdown<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>();
+            let l2 = *arg0
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+        _ => {
+            let l3 = *arg0
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>();
+            let l4 = *arg0
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            _rt::cabi_dealloc(l3, l4, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_toml_to_markdown_cabi<T: Guest>(arg0: *mut u8, arg1: usize) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let result1 = T::toml_to_markdown(_rt::string_lift(bytes0));
+    let ptr2 = (&raw mut _RET_AREA.0).cast::<u8>();
+    match result1 {
+        Ok(e) => {
+            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+            let vec3 = (e.into_bytes()).into_boxed_slice();
+            let ptr3 = vec3.as_ptr().cast::<u8>();
+            let len3 = vec3.len();
+            ::core::mem::forget(vec3);
+            *ptr2
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>() = len3;
+            *ptr2
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>() = ptr3.cast_mut();
+        }
+        Err(e) => {
+            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+            let vec4 = (e.into_bytes()).into_boxed_slice();
+            let ptr4 = vec4.as_ptr().cast::<u8>();
+            let len4 = vec4.len();
+            ::core::mem::forget(vec4);
+            *ptr2
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>() = len4;
+            *ptr2
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>() = ptr4.cast_mut();
+        }
+    };
+    ptr2
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_toml_to_markdown<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>();
+            let l2 = *arg0
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+        _ => {
+            let l3 = *arg0
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>();
+            let l4 = *arg0
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            _rt::cabi_dealloc(l3, l4, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_xml_to_markdown_cabi<T: Guest>(arg0: *mut u8, arg1: usize) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let result1 = T::xml_to_markdown(_rt::string_lift(bytes0));
+    let ptr2 = (&raw mut _RET_AREA.0).cast::<u8>();
+    match result1 {
+        Ok(e) => {
+            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+            let vec3 = (e.into_bytes()).into_boxed_slice();
+            let ptr3 = vec3.as_ptr().cast::<u8>();
+            let len3 = vec3.len();
+            ::core::mem::forget(vec3);
+            *ptr2
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>() = len3;
+            *ptr2
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>() = ptr3.cast_mut();
+        }
+        Err(e) => {
+            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+            let vec4 = (e.into_bytes()).into_boxed_slice();
+            let ptr4 = vec4.as_ptr().cast::<u8>();
+            let len4 = vec4.len();
+            ::core::mem::forget(vec4);
+            *ptr2
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>() = len4;
+            *ptr2
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>() = ptr4.cast_mut();
+        }
+    };
+    ptr2
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_xml_to_markdown<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>();
+            let l2 = *arg0
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+        _ => {
+            let l3 = *arg0
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>();
+            let l4 = *arg0
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            _rt::cabi_dealloc(l3, l4, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_feed_to_markdown_cabi<T: Guest>(arg0: *mut u8, arg1: usize) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let result1 = T::feed_to_markdown(_rt::string_lift(bytes0));
+    let ptr2 = (&raw mut _RET_AREA.0).cast::<u8>();
+    match result1 {
+        Ok(e) => {
+            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+            let FeedDigest {
+                title: title3,
+                entries: entries3,
+                markdown: markdown3,
+            } = e;
+            let vec4 = (title3.into_bytes()).into_boxed_slice();
+            let ptr4 = vec4.as_ptr().cast::<u8>();
+            let len4 = vec4.len();
+            ::core::mem::forget(vec4);
+            *ptr2
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>() = len4;
+            *ptr2
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>() = ptr4.cast_mut();
+            let vec11 = entries3;
+            let len11 = vec11.len();
+            let layout11 = _rt::alloc::Layout::from_size_align_unchecked(
+                vec11.len() * (14 * ::core::mem::size_of::<*const u8>()),
+                ::core::mem::size_of::<*const u8>(),
+            );
+            let result11 = if layout11.size() != 0 {
+                let ptr = _rt::alloc::alloc(layout11).cast::<u8>();
+                if ptr.is_null() {
+                    _rt::alloc::handle_alloc_error(layout11);
+                }
+                ptr
+            } else {
+                ::core::ptr::null_mut()
+            };
+            for (i, e) in vec11.into_iter().enumerate() {
+                let base = result11.add(i * (14 * ::core::mem::size_of::<*const u8>()));
+                {
+                    let FeedEntry {
+                        title: title5,
+                        link: link5,
+                        published: published5,
+                        author: author5,
+                        summary: summary5,
+                    } = e;
+                    let vec6 = (title5.into_bytes()).into_boxed_slice();
+                    let ptr6 = vec6.as_ptr().cast::<u8>();
+                    let len6 = vec6.len();
+                    ::core::mem::forget(vec6);
+                    *base
+                        .add(::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len6;
+                    *base.add(0).cast::<*mut u8>() = ptr6.cast_mut();
+                    match link5 {
+                        Some(e) => {
+                            *base
+                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (1i32) as u8;
+                            let vec7 = (e.into_bytes()).into_boxed_slice();
+                            let ptr7 = vec7.as_ptr().cast::<u8>();
+                            let len7 = vec7.len();
+                            ::core::mem::forget(vec7);
+                            *base
+                                .add(4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len7;
+                            *base
+                                .add(3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>() = ptr7.cast_mut();
+                        }
+                        None => {
+                            *base
+                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                    match published5 {
+                        Some(e) => {
+                            *base
+                                .add(5 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (1i32) as u8;
+                            let vec8 = (e.into_bytes()).into_boxed_slice();
+                            let ptr8 = vec8.as_ptr().cast::<u8>();
+                            let len8 = vec8.len();
+                            ::core::mem::forget(vec8);
+                            *base
+                                .add(7 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len8;
+                            *base
+                                .add(6 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>() = ptr8.cast_mut();
+                        }
+                        None => {
+                            *base
+                                .add(5 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                    match author5 {
+                        Some(e) => {
+                            *base
+                                .add(8 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (1i32) as u8;
+                            let vec9 = (e.into_bytes()).into_boxed_slice();
+                            let ptr9 = vec9.as_ptr().cast::<u8>();
+                            let len9 = vec9.len();
+                            ::core::mem::forget(vec9);
+                            *base
+                                .add(10 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len9;
+                            *base
+                                .add(9 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>() = ptr9.cast_mut();
+                        }
+                        None => {
+                            *base
+                                .add(8 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                    match summary5 {
+                        Some(e) => {
+                            *base
+                                .add(11 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (1i32) as u8;
+                            let vec10 = (e.into_bytes()).into_boxed_slice();
+                            let ptr10 = vec10.as_ptr().cast::<u8>();
+                            let len10 = vec10.len();
+                            ::core::mem::forget(vec10);
+                            *base
+                                .add(13 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len10;
+                            *base
+                                .add(12 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>() = ptr10.cast_mut();
+                        }
+                        None => {
+                            *base
+                                .add(11 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                }
+            }
+            *ptr2
+                .add(4 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>() = len11;
+            *ptr2
+                .add(3 * ::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>() = result11;
+            let vec12 = (markdown3.into_bytes()).into_boxed_slice();
+            let ptr12 = vec12.as_ptr().cast::<u8>();
+            let len12 = vec12.len();
+            ::core::mem::forget(vec12);
+            *ptr2
+                .add(6 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>() = len12;
+            *ptr2
+                .add(5 * ::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>() = ptr12.cast_mut();
+        }
+        Err(e) => {
+            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+            let vec13 = (e.into_bytes()).into_boxed_slice();
+            let ptr13 = vec13.as_ptr().cast::<u8>();
+            let len13 = vec13.len();
+            ::core::mem::forget(vec13);
+            *ptr2
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>() = len13;
+            *ptr2
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>() = ptr13.cast_mut();
+        }
+    };
+    ptr2
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_feed_to_markdown<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>();
+            let l2 = *arg0
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+            let l3 = *arg0
+                .add(3 * ::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>();
+            let l4 = *arg0
+                .add(4 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            let base19 = l3;
+            let len19 = l4;
+            for i in 0..len19 {
+                let base = base19.add(i * (14 * ::core::mem::size_of::<*const u8>()));
+                {
+                    let l5 = *base.add(0).cast::<*mut u8>();
+                    let l6 = *base
+                        .add(::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l5, l6, 1);
+                    let l7 = i32::from(
+                        *base
+                            .add(2 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    match l7 {
+                        0 => {}
+                        _ => {
+                            let l8 = *base
+                                .add(3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l9 = *base
+                                .add(4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            _rt::cabi_dealloc(l8, l9, 1);
+                        }
+                    }
+                    let l10 = i32::from(
+                        *base
+                            .add(5 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    match l10 {
+                        0 => {}
+                        _ => {
+                            let l11 = *base
+                                .add(6 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l12 = *base
+                                .add(7 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            _rt::cabi_dealloc(l11, l12, 1);
+                        }
+                    }
+                    let l13 = i32::from(
+                        *base
+                            .add(8 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    match l13 {
+                        0 => {}
+                        _ => {
+                            let l14 = *base
+                                .add(9 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l15 = *base
+                                .add(10 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            _rt::cabi_dealloc(l14, l15, 1);
+                        }
+                    }
+                    let l16 = i32::from(
+                        *base
+                            .add(11 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    match l16 {
+                        0 => {}
+                        _ => {
+                            let l17 = *base
+                                .add(12 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l18 = *base
+                                .add(13 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            _rt::cabi_dealloc(l17, l18, 1);
+                        }
+                    }
+                }
+            }
+            _rt::cabi_dealloc(
+                base19,
+                len19 * (14 * ::core::mem::size_of::<*const u8>()),
+                ::core::mem::size_of::<*const u8>(),
+            );
+            let l20 = *arg0
+                .add(5 * ::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>();
+            let l21 = *arg0
+                .add(6 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            _rt::cabi_dealloc(l20, l21, 1);
+        }
+        _ => {
+            let l22 = *arg0
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>();
+            let l23 = *arg0
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            _rt::cabi_dealloc(l22, l23, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_outline_cabi<T: Guest>(arg0: *mut u8, arg1: usize) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let result1 = T::outline(_rt::string_lift(bytes0));
+    let ptr2 = (&raw mut _RET_AREA.0).cast::<u8>();
+    let vec6 = result1;
+    let len6 = vec6.len();
+    let layout6 = _rt::alloc::Layout::from_size_align_unchecked(
+        vec6.len() * (16 + 5 * ::core::mem::size_of::<*const u8>()),
+        ::core::mem::size_of::<*const u8>(),
+    );
+    let result6 = if layout6.size() != 0 {
+        let ptr = _rt::alloc::alloc(layout6).cast::<u8>();
+        if ptr.is_null() {
+            _rt::alloc::handle_alloc_error(layout6);
+        }
+        ptr
+    } else {
+        ::core::ptr::null_mut()
+    };
+    for (i, e) in vec6.into_iter().enumerate() {
+        let base = result6.add(i * (16 + 5 * ::core::mem::size_of::<*const u8>()));
+        {
+            let Heading {
+                level: level3,
+                text: text3,
+                anchor: anchor3,
+                parent: parent3,
+                start: start3,
+                end: end3,
+            } = e;
+            *base.add(0).cast::<u8>() = (_rt::as_i32(level3)) as u8;
+            let vec4 = (text3.into_bytes()).into_boxed_slice();
+            let ptr4 = vec4.as_ptr().cast::<u8>();
+            let len4 = vec4.len();
+            ::core::mem::forget(vec4);
+            *base
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>() = len4;
+            *base
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>() = ptr4.cast_mut();
+            let vec5 = (anchor3.into_bytes()).into_boxed_slice();
+            let ptr5 = vec5.as_ptr().cast::<u8>();
+            let len5 = vec5.len();
+            ::core::mem::forget(vec5);
+            *base
+                .add(4 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>() = len5;
+            *base
+                .add(3 * ::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>() = ptr5.cast_mut();
+            match parent3 {
+                Some(e) => {
+                    *base
+                        .add(5 * ::core::mem::size_of::<*const u8>())
+                        .cast::<u8>() = (1i32) as u8;
+                    *base
+                        .add(4 + 5 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(e);
+                }
+                None => {
+                    *base
+                        .add(5 * ::core::mem::size_of::<*const u8>())
+                        .cast::<u8>() = (0i32) as u8;
+                }
+            };
+            *base
+                .add(8 + 5 * ::core::mem::size_of::<*const u8>())
+                .cast::<i32>() = _rt::as_i32(start3);
+            *base
+                .add(12 + 5 * ::core::mem::size_of::<*const u8>())
+                .cast::<i32>() = _rt::as_i32(end3);
+        }
+    }
+    *ptr2
+        .add(::core::mem::size_of::<*const u8>())
+        .cast::<usize>() = len6;
+    *ptr2.add(0).cast::<*mut u8>() = result6;
+    ptr2
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_outline<T: Guest>(arg0: *mut u8) {
+    let l0 = *arg0.add(0).cast::<*mut u8>();
+    let l1 = *arg0
+        .add(::core::mem::size_of::<*const u8>())
+        .cast::<usize>();
+    let base6 = l0;
+    let len6 = l1;
+    for i in 0..len6 {
+        let base = base6.add(i * (16 + 5 * ::core::mem::size_of::<*const u8>()));
+        {
+            let l2 = *base
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>();
+            let l3 = *base
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            _rt::cabi_dealloc(l2, l3, 1);
+            let l4 = *base
+                .add(3 * ::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>();
+            let l5 = *base
+                .add(4 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            _rt::cabi_dealloc(l4, l5, 1);
+        }
+    }
+    _rt::cabi_dealloc(
+        base6,
+        len6 * (16 + 5 * ::core::mem::size_of::<*const u8>()),
+        ::core::mem::size_of::<*const u8>(),
+    );
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_extract_links_cabi<T: Guest>(arg0: *mut u8, arg1: usize) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let result1 = T::extract_links(_rt::string_lift(bytes0));
+    let ptr2 = (&raw mut _RET_AREA.0).cast::<u8>();
+    let vec7 = result1;
+    let len7 = vec7.len();
+    let layout7 = _rt::alloc::Layout::from_size_align_unchecked(
+        vec7.len() * (7 * ::core::mem::size_of::<*const u8>()),
+        ::core::mem::size_of::<*const u8>(),
+    );
+    let result7 = if layout7.size() != 0 {
+        let ptr = _rt::alloc::alloc(layout7).cast::<u8>();
+        if ptr.is_null() {
+            _rt::alloc::handle_alloc_error(layout7);
+        }
+        ptr
+    } else {
+        ::core::ptr::null_mut()
+    };
+    for (i, e) in vec7.into_iter().enumerate() {
+        let base = result7.add(i * (7 * ::core::mem::size_of::<*const u8>()));
+        {
+            let Link {
+                kind: kind3,
+                text: text3,
+                target: target3,
+                title: title3,
+            } = e;
+            *base.add(0).cast::<u8>() = (kind3.clone() as i32) as u8;
+            let vec4 = (text3.into_bytes()).into_boxed_slice();
+            let ptr4 = vec4.as_ptr().cast::<u8>();
+            let len4 = vec4.len();
+            ::core::mem::forget(vec4);
+            *base
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>() = len4;
+            *base
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>() = ptr4.cast_mut();
+            let vec5 = (target3.into_bytes()).into_boxed_slice();
+            let ptr5 = vec5.as_ptr().cast::<u8>();
+            let len5 = vec5.len();
+            ::core::mem::forget(vec5);
+            *base
+                .add(4 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>() = len5;
+            *base
+                .add(3 * ::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>() = ptr5.cast_mut();
+            let vec6 = (title3.into_bytes()).into_boxed_slice();
+            let ptr6 = vec6.as_ptr().cast::<u8>();
+            let len6 = vec6.len();
+            ::core::mem::forget(vec6);
+            *base
+                .add(6 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>() = len6;
+            *base
+                .add(5 * ::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>() = ptr6.cast_mut();
+        }
+    }
+    *ptr2
+        .add(::core::mem::size_of::<*const u8>())
+        .cast::<usize>() = len7;
+    *ptr2.add(0).cast::<*mut u8>() = result7;
+    ptr2
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_extract_links<T: Guest>(arg0: *mut u8) {
+    let l0 = *arg0.add(0).cast::<*mut u8>();
+    let l1 = *arg0
+        .add(::core::mem::size_of::<*const u8>())
+        .cast::<usize>();
+    let base8 = l0;
+    let len8 = l1;
+    for i in 0..len8 {
+        let base = base8.add(i * (7 * ::core::mem::size_of::<*const u8>()));
+        {
+            let l2 = *base
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>();
+            let l3 = *base
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            _rt::cabi_dealloc(l2, l3, 1);
+            let l4 = *base
+                .add(3 * ::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>();
+            let l5 = *base
+                .add(4 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            _rt::cabi_dealloc(l4, l5, 1);
+            let l6 = *base
+                .add(5 * ::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>();
+            let l7 = *base
+                .add(6 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            _rt::cabi_dealloc(l6, l7, 1);
+        }
+    }
+    _rt::cabi_dealloc(
+        base8,
+        len8 * (7 * ::core::mem::size_of::<*const u8>()),
+        ::core::mem::size_of::<*const u8>(),
+    );
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_section_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+    arg2: *mut u8,
+    arg3: usize,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let base4 = arg2;
+    let len4 = arg3;
+    let mut result4 = _rt::Vec::with_capacity(len4);
+    for i in 0..len4 {
+        let base = base4.add(i * (2 * ::core::mem::size_of::<*const u8>()));
+        let e4 = {
+            let l1 = *base.add(0).cast::<*mut u8>();
+            let l2 = *base
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            let len3 = l2;
+            let bytes3 = _rt::Vec::from_raw_parts(l1.cast(), len3, len3);
+            _rt::string_lift(bytes3)
+        };
+        result4.push(e4);
+    }
+    _rt::cabi_dealloc(
+        base4,
+        len4 * (2 * ::core::mem::size_of::<*const u8>()),
+        ::core::mem::size_of::<*const u8>(),
+    );
+    let result5 = T::section(_rt::string_lift(bytes0), result4);
+    let ptr6 = (&raw mut _RET_AREA.0).cast::<u8>();
+    match result5 {
+        Ok(e) => {
+            *ptr6.add(0).cast::<u8>() = (0i32) as u8;
+            let vec7 = (e.into_bytes()).into_boxed_slice();
+            let ptr7 = vec7.as_ptr().cast::<u8>();
+            let len7 = vec7.len();
+            ::core::mem::forget(vec7);
+            *ptr6
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>() = len7;
+            *ptr6
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>() = ptr7.cast_mut();
+        }
+        Err(e) => {
+            *ptr6.add(0).cast::<u8>() = (1i32) as u8;
+            let vec8 = (e.into_bytes()).into_boxed_slice();
+            let ptr8 = vec8.as_ptr().cast::<u8>();
+            let len8 = vec8.len();
+            ::core::mem::forget(vec8);
+            *ptr6
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>() = len8;
+            *ptr6
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>() = ptr8.cast_mut();
+        }
+    };
+    ptr6
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_section<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>();
+            let l2 = *arg0
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+        _ => {
+            let l3 = *arg0
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>();
+            let l4 = *arg0
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            _rt::cabi_dealloc(l3, l4, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_sanitize_html_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+    arg2: i32,
+    arg3: *mut u8,
+    arg4: usize,
+    arg5: i32,
+    arg6: *mut u8,
+    arg7: usize,
+    arg8: i32,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let result9 = T::sanitize_html(
+        _rt::string_lift(bytes0),
+        SanitizeOptions {
+            allowed_tags: match arg2 {
+                0 => None,
+                1 => {
+                    let e = {
+                        let base4 = arg3;
+                        let len4 = arg4;
+                        let mut result4 = _rt::Vec::with_capacity(len4);
+                        for i in 0..len4 {
+                            let base = base4.add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                            let e4 = {
+                                let l1 = *base.add(0).cast::<*mut u8>();
+                                let l2 = *base
+                                    .add(::core::mem::size_of::<*const u8>())
+                                    .cast::<usize>();
+                                let len3 = l2;
+                                let bytes3 = _rt::Vec::from_raw_parts(l1.cast(), len3, len3);
+                                _rt::string_lift(bytes3)
+                            };
+                            result4.push(e4);
+                        }
+                        _rt::cabi_dealloc(
+                            base4,
+                            len4 * (2 * ::core::mem::size_of::<*const u8>()),
+                            ::core::mem::size_of::<*const u8>(),
+                        );
+                        result4
+                    };
+                    Some(e)
+                }
+                _ => _rt::invalid_enum_discriminant(),
+            },
+            allowed_attributes: match arg5 {
+                0 => None,
+                1 => {
+                    let e = {
+                        let base8 = arg6;
+                        let len8 = arg7;
+                        let mut result8 = _rt::Vec::with_capacity(len8);
+                        for i in 0..len8 {
+                            let base = base8.add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                            let e8 = {
+                                let l5 = *base.add(0).cast::<*mut u8>();
+                                let l6 = *base
+                                    .add(::core::mem::size_of::<*const u8>())
+                                    .cast::<usize>();
+                                let len7 = l6;
+                                let bytes7 = _rt::Vec::from_raw_parts(l5.cast(), len7, len7);
+                                _rt::string_lift(bytes7)
+                            };
+                            result8.push(e8);
+                        }
+                        _rt::cabi_dealloc(
+                            base8,
+                            len8 * (2 * ::core::mem::size_of::<*const u8>()),
+                            ::core::mem::size_of::<*const u8>(),
+                        );
+                        result8
+                    };
+                    Some(e)
+                }
+                _ => _rt::invalid_enum_discriminant(),
+            },
+            remove_boilerplate: _rt::bool_lift(arg8 as u8),
+        },
+    );
+    let ptr10 = (&raw mut _RET_AREA.0).cast::<u8>();
+    let vec11 = (result9.into_bytes()).into_boxed_slice();
+    let ptr11 = vec11.as_ptr().cast::<u8>();
+    let len11 = vec11.len();
+    ::core::mem::forget(vec11);
+    *ptr10
+        .add(::core::mem::size_of::<*const u8>())
+        .cast::<usize>() = len11;
+    *ptr10.add(0).cast::<*mut u8>() = ptr11.cast_mut();
+    ptr10
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_sanitize_html<T: Guest>(arg0: *mut u8) {
+    let l0 = *arg0.add(0).cast::<*mut u8>();
+    let l1 = *arg0
+        .add(::core::mem::size_of::<*const u8>())
+        .cast::<usize>();
+    _rt::cabi_dealloc(l0, l1, 1);
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_markdown_to_html_cabi<T: Guest>(arg0: *mut u8, arg1: usize) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let result1 = T::markdown_to_html(_rt::string_lift(bytes0));
+    let ptr2 = (&raw mut _RET_AREA.0).cast::<u8>();
     let vec3 = (result1.into_bytes()).into_boxed_slice();
     let ptr3 = vec3.as_ptr().cast::<u8>();
     let len3 = vec3.len();
@@ -50,7 +1483,35 @@ pub unsafe fn _export_json_to_markdown_cabi<T: Guest>(arg0: *mut u8, arg1: usize
 }
 #[doc(hidden)]
 #[allow(non_snake_case)]
-pub unsafe fn __post_return_json_to_markdown<T: Guest>(arg0: *mut u8) {
+pub unsafe fn __post_return_markdown_to_html<T: Guest>(arg0: *mut u8) {
+    let l0 = *arg0.add(0).cast::<*mut u8>();
+    let l1 = *arg0
+        .add(::core::mem::size_of::<*const u8>())
+        .cast::<usize>();
+    _rt::cabi_dealloc(l0, l1, 1);
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_markdown_to_text_cabi<T: Guest>(arg0: *mut u8, arg1: usize) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let result1 = T::markdown_to_text(_rt::string_lift(bytes0));
+    let ptr2 = (&raw mut _RET_AREA.0).cast::<u8>();
+    let vec3 = (result1.into_bytes()).into_boxed_slice();
+    let ptr3 = vec3.as_ptr().cast::<u8>();
+    let len3 = vec3.len();
+    ::core::mem::forget(vec3);
+    *ptr2
+        .add(::core::mem::size_of::<*const u8>())
+        .cast::<usize>() = len3;
+    *ptr2.add(0).cast::<*mut u8>() = ptr3.cast_mut();
+    ptr2
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_markdown_to_text<T: Guest>(arg0: *mut u8) {
     let l0 = *arg0.add(0).cast::<*mut u8>();
     let l1 = *arg0
         .add(::core::mem::size_of::<*const u8>())
@@ -60,8 +1521,45 @@ pub unsafe fn __post_return_json_to_markdown<T: Guest>(arg0: *mut u8) {
 pub trait Guest {
     /// Convert HTML content to markdown format
     fn html_to_markdown(html: _rt::String) -> _rt::String;
-    /// Convert JSON content to markdown format
-    fn json_to_markdown(json: _rt::String) -> _rt::String;
+    /// Convert HTML content to markdown format with the given conversion options.
+    /// Unlike `html-to-markdown`, this converts lists, tables, code, emphasis and
+    /// images, and escapes page text that would otherwise read as markdown syntax
+    fn html_to_markdown_with(
+        html: _rt::String,
+        options: ConversionOptions,
+    ) -> Result<_rt::String, _rt::String>;
+    /// Convert JSON content to markdown format, failing if the input is not valid JSON
+    fn json_to_markdown(json: _rt::String) -> Result<_rt::String, _rt::String>;
+    /// Convert CSV content to a markdown table, using the first row as the header
+    fn csv_to_markdown(csv: _rt::String) -> Result<_rt::String, _rt::String>;
+    /// Convert tab-separated values to a markdown table, using the first row as the header
+    fn tsv_to_markdown(tsv: _rt::String) -> Result<_rt::String, _rt::String>;
+    /// Convert YAML content to markdown format
+    fn yaml_to_markdown(yaml: _rt::String) -> Result<_rt::String, _rt::String>;
+    /// Convert TOML content to markdown format
+    fn toml_to_markdown(toml: _rt::String) -> Result<_rt::String, _rt::String>;
+    /// Convert XML content to a nested markdown list of elements
+    fn xml_to_markdown(xml: _rt::String) -> Result<_rt::String, _rt::String>;
+    /// Convert an RSS or Atom feed to a markdown digest and a list of its entries
+    fn feed_to_markdown(xml: _rt::String) -> Result<FeedDigest, _rt::String>;
+    /// Get the heading outline of a markdown document in document order.
+    /// The tree structure is given by each heading's parent index.
+    fn outline(markdown: _rt::String) -> _rt::Vec<Heading>;
+    /// Extract every link and image in a markdown document
+    fn extract_links(markdown: _rt::String) -> _rt::Vec<Link>;
+    /// Get the markdown under a heading path, e.g. ["Installation", "Linux"],
+    /// matching heading text case-insensitively
+    fn section(
+        markdown: _rt::String,
+        path: _rt::Vec<_rt::String>,
+    ) -> Result<_rt::String, _rt::String>;
+    /// Sanitise HTML for display, returning the cleaned body content. Scripts, styles,
+    /// embedded frames, event handlers and javascript: URLs are always removed
+    fn sanitize_html(html: _rt::String, options: SanitizeOptions) -> _rt::String;
+    /// Render markdown (CommonMark with GitHub Flavored Markdown extensions) to sanitised HTML
+    fn markdown_to_html(markdown: _rt::String) -> _rt::String;
+    /// Convert markdown to plain text with all markup removed
+    fn markdown_to_text(markdown: _rt::String) -> _rt::String;
 }
 #[doc(hidden)]
 macro_rules! __export_world_markdown_cabi {
@@ -72,29 +1570,108 @@ macro_rules! __export_world_markdown_cabi {
         #[unsafe (export_name = "cabi_post_html-to-markdown")] unsafe extern "C" fn
         _post_return_html_to_markdown(arg0 : * mut u8,) { unsafe { $($path_to_types)*::
         __post_return_html_to_markdown::<$ty > (arg0) } } #[unsafe (export_name =
-        "json-to-markdown")] unsafe extern "C" fn export_json_to_markdown(arg0 : * mut
-        u8, arg1 : usize,) -> * mut u8 { unsafe { $($path_to_types)*::
-        _export_json_to_markdown_cabi::<$ty > (arg0, arg1) } } #[unsafe (export_name =
-        "cabi_post_json-to-markdown")] unsafe extern "C" fn
+        "html-to-markdown-with")] unsafe extern "C" fn export_html_to_markdown_with(arg0
+        : * mut u8, arg1 : usize, arg2 : i32, arg3 : i32, arg4 : i32, arg5 : i32, arg6 :
+        i32, arg7 : i32,) -> * mut u8 { unsafe { $($path_to_types)*::
+        _export_html_to_markdown_with_cabi::<$ty > (arg0, arg1, arg2, arg3, arg4, arg5,
+        arg6, arg7) } } #[unsafe (export_name = "cabi_post_html-to-markdown-with")]
+        unsafe extern "C" fn _post_return_html_to_markdown_with(arg0 : * mut u8,) {
+        unsafe { $($path_to_types)*:: __post_return_html_to_markdown_with::<$ty > (arg0)
+        } } #[unsafe (export_name = "json-to-markdown")] unsafe extern "C" fn
+        export_json_to_markdown(arg0 : * mut u8, arg1 : usize,) -> * mut u8 { unsafe {
+        $($path_to_types)*:: _export_json_to_markdown_cabi::<$ty > (arg0, arg1) } }
+        #[unsafe (export_name = "cabi_post_json-to-markdown")] unsafe extern "C" fn
         _post_return_json_to_markdown(arg0 : * mut u8,) { unsafe { $($path_to_types)*::
-        __post_return_json_to_markdown::<$ty > (arg0) } } };
+        __post_return_json_to_markdown::<$ty > (arg0) } } #[unsafe (export_name =
+        "csv-to-markdown")] unsafe extern "C" fn export_csv_to_markdown(arg0 : * mut u8,
+        arg1 : usize,) -> * mut u8 { unsafe { $($path_to_types)*::
+        _export_csv_to_markdown_cabi::<$ty > (arg0, arg1) } } #[unsafe (export_name =
+        "cabi_post_csv-to-markdown")] unsafe extern "C" fn
+        _post_return_csv_to_markdown(arg0 : * mut u8,) { unsafe { $($path_to_types)*::
+        __post_return_csv_to_markdown::<$ty > (arg0) } } #[unsafe (export_name =
+        "tsv-to-markdown")] unsafe extern "C" fn export_tsv_to_markdown(arg0 : * mut u8,
+        arg1 : usize,) -> * mut u8 { unsafe { $($path_to_types)*::
+        _export_tsv_to_markdown_cabi::<$ty > (arg0, arg1) } } #[unsafe (export_name =
+        "cabi_post_tsv-to-markdown")] unsafe extern "C" fn
+        _post_return_tsv_to_markdown(arg0 : * mut u8,) { unsafe { $($path_to_types)*::
+        __post_return_tsv_to_markdown::<$ty > (arg0) } } #[unsafe (export_name =
+        "yaml-to-markdown")] unsafe extern "C" fn export_yaml_to_markdown(arg0 : * mut
+        u8, arg1 : usize,) -> * mut u8 { unsafe { $($path_to_types)*::
+        _export_yaml_to_markdown_cabi::<$ty > (arg0, arg1) } } #[unsafe (export_name =
+        "cabi_post_yaml-to-markdown")] unsafe extern "C" fn
+        _post_return_yaml_to_markdown(arg0 : * mut u8,) { unsafe { $($path_to_types)*::
+        __post_return_yaml_to_markdown::<$ty > (arg0) } } #[unsafe (export_name =
+        "toml-to-markdown")] unsafe extern "C" fn export_toml_to_markdown(arg0 : * mut
+        u8, arg1 : usize,) -> * mut u8 { unsafe { $($path_to_types)*::
+        _export_toml_to_markdown_cabi::<$ty > (arg0, arg1) } } #[unsafe (export_name =
+        "cabi_post_toml-to-markdown")] unsafe extern "C" fn
+        _post_return_toml_to_markdown(arg0 : * mut u8,) { unsafe { $($path_to_types)*::
+        __post_return_toml_to_markdown::<$ty > (arg0) } } #[unsafe (export_name =
+        "xml-to-markdown")] unsafe extern "C" fn export_xml_to_markdown(arg0 : * mut u8,
+        arg1 : usize,) -> * mut u8 { unsafe { $($path_to_types)*::
+        _export_xml_to_markdown_cabi::<$ty > (arg0, arg1) } } #[unsafe (export_name =
+        "cabi_post_xml-to-markdown")] unsafe extern "C" fn
+        _post_return_xml_to_markdown(arg0 : * mut u8,) { unsafe { $($path_to_types)*::
+        __post_return_xml_to_markdown::<$ty > (arg0) } } #[unsafe (export_name =
+        "feed-to-markdown")] unsafe extern "C" fn export_feed_to_markdown(arg0 : * mut
+        u8, arg1 : usize,) -> * mut u8 { unsafe { $($path_to_types)*::
+        _export_feed_to_markdown_cabi::<$ty > (arg0, arg1) } } #[unsafe (export_name =
+        "cabi_post_feed-to-markdown")] unsafe extern "C" fn
+        _post_return_feed_to_markdown(arg0 : * mut u8,) { unsafe { $($path_to_types)*::
+        __post_return_feed_to_markdown::<$ty > (arg0) } } #[unsafe (export_name =
+        "outline")] unsafe extern "C" fn export_outline(arg0 : * mut u8, arg1 : usize,)
+        -> * mut u8 { unsafe { $($path_to_types)*:: _export_outline_cabi::<$ty > (arg0,
+        arg1) } } #[unsafe (export_name = "cabi_post_outline")] unsafe extern "C" fn
+        _post_return_outline(arg0 : * mut u8,) { unsafe { $($path_to_types)*::
+        __post_return_outline::<$ty > (arg0) } } #[unsafe (export_name =
+        "extract-links")] unsafe extern "C" fn export_extract_links(arg0 : * mut u8, arg1
+        : usize,) -> * mut u8 { unsafe { $($path_to_types)*::
+        _export_extract_links_cabi::<$ty > (arg0, arg1) } } #[unsafe (export_name =
+        "cabi_post_extract-links")] unsafe extern "C" fn _post_return_extract_links(arg0
+        : * mut u8,) { unsafe { $($path_to_types)*:: __post_return_extract_links::<$ty >
+        (arg0) } } #[unsafe (export_name = "section")] unsafe extern "C" fn
+        export_section(arg0 : * mut u8, arg1 : usize, arg2 : * mut u8, arg3 : usize,) ->
+        * mut u8 { unsafe { $($path_to_types)*:: _export_section_cabi::<$ty > (arg0,
+        arg1, arg2, arg3) } } #[unsafe (export_name = "cabi_post_section")] unsafe extern
+        "C" fn _post_return_section(arg0 : * mut u8,) { unsafe { $($path_to_types)*::
+        __post_return_section::<$ty > (arg0) } } #[unsafe (export_name =
+        "sanitize-html")] unsafe extern "C" fn export_sanitize_html(arg0 : * mut u8, arg1
+        : usize, arg2 : i32, arg3 : * mut u8, arg4 : usize, arg5 : i32, arg6 : * mut u8,
+        arg7 : usize, arg8 : i32,) -> * mut u8 { unsafe { $($path_to_types)*::
+        _export_sanitize_html_cabi::<$ty > (arg0, arg1, arg2, arg3, arg4, arg5, arg6,
+        arg7, arg8) } } #[unsafe (export_name = "cabi_post_sanitize-html")] unsafe extern
+        "C" fn _post_return_sanitize_html(arg0 : * mut u8,) { unsafe {
+        $($path_to_types)*:: __post_return_sanitize_html::<$ty > (arg0) } } #[unsafe
+        (export_name = "markdown-to-html")] unsafe extern "C" fn
+        export_markdown_to_html(arg0 : * mut u8, arg1 : usize,) -> * mut u8 { unsafe {
+        $($path_to_types)*:: _export_markdown_to_html_cabi::<$ty > (arg0, arg1) } }
+        #[unsafe (export_name = "cabi_post_markdown-to-html")] unsafe extern "C" fn
+        _post_return_markdown_to_html(arg0 : * mut u8,) { unsafe { $($path_to_types)*::
+        __post_return_markdown_to_html::<$ty > (arg0) } } #[unsafe (export_name =
+        "markdown-to-text")] unsafe extern "C" fn export_markdown_to_text(arg0 : * mut
+        u8, arg1 : usize,) -> * mut u8 { unsafe { $($path_to_types)*::
+        _export_markdown_to_text_cabi::<$ty > (arg0, arg1) } } #[unsafe (export_name =
+        "cabi_post_markdown-to-text")] unsafe extern "C" fn
+        _post_return_markdown_to_text(arg0 : * mut u8,) { unsafe { $($path_to_types)*::
+        __post_return_markdown_to_text::<$ty > (arg0) } } };
     };
 }
 #[doc(hidden)]
 pub(crate) use __export_world_markdown_cabi;
 #[cfg_attr(target_pointer_width = "64", repr(align(8)))]
 #[cfg_attr(target_pointer_width = "32", repr(align(4)))]
-struct _RetArea([::core::mem::MaybeUninit<u8>; 2 * ::core::mem::size_of::<*const u8>()]);
+struct _RetArea([::core::mem::MaybeUninit<u8>; 7 * ::core::mem::size_of::<*const u8>()]);
 static mut _RET_AREA: _RetArea =
-    _RetArea([::core::mem::MaybeUninit::uninit(); 2 * ::core::mem::size_of::<*const u8>()]);
+    _RetArea([::core::mem::MaybeUninit::uninit(); 7 * ::core::mem::size_of::<*const u8>()]);
 #[rustfmt::skip]
 mod _rt {
     #![allow(dead_code, clippy::all)]
+    pub use alloc_crate::vec::Vec;
+    pub use alloc_crate::string::String;
     #[cfg(target_arch = "wasm32")]
     pub fn run_ctors_once() {
         wit_bindgen_rt::run_ctors_once();
     }
-    pub use alloc_crate::vec::Vec;
     pub unsafe fn string_lift(bytes: Vec<u8>) -> String {
         if cfg!(debug_assertions) {
             String::from_utf8(bytes).unwrap()
@@ -109,9 +1686,92 @@ mod _rt {
         let layout = alloc::Layout::from_size_align_unchecked(size, align);
         alloc::dealloc(ptr, layout);
     }
-    pub use alloc_crate::string::String;
-    extern crate alloc as alloc_crate;
+    pub unsafe fn char_lift(val: u32) -> char {
+        if cfg!(debug_assertions) {
+            core::char::from_u32(val).unwrap()
+        } else {
+            core::char::from_u32_unchecked(val)
+        }
+    }
+    pub unsafe fn bool_lift(val: u8) -> bool {
+        if cfg!(debug_assertions) {
+            match val {
+                0 => false,
+                1 => true,
+                _ => panic!("invalid bool discriminant"),
+            }
+        } else {
+            val != 0
+        }
+    }
     pub use alloc_crate::alloc;
+    pub fn as_i32<T: AsI32>(t: T) -> i32 {
+        t.as_i32()
+    }
+    pub trait AsI32 {
+        fn as_i32(self) -> i32;
+    }
+    impl<'a, T: Copy + AsI32> AsI32 for &'a T {
+        fn as_i32(self) -> i32 {
+            (*self).as_i32()
+        }
+    }
+    impl AsI32 for i32 {
+        #[inline]
+        fn as_i32(self) -> i32 {
+            self as i32
+        }
+    }
+    impl AsI32 for u32 {
+        #[inline]
+        fn as_i32(self) -> i32 {
+            self as i32
+        }
+    }
+    impl AsI32 for i16 {
+        #[inline]
+        fn as_i32(self) -> i32 {
+            self as i32
+        }
+    }
+    impl AsI32 for u16 {
+        #[inline]
+        fn as_i32(self) -> i32 {
+            self as i32
+        }
+    }
+    impl AsI32 for i8 {
+        #[inline]
+        fn as_i32(self) -> i32 {
+            self as i32
+        }
+    }
+    impl AsI32 for u8 {
+        #[inline]
+        fn as_i32(self) -> i32 {
+            self as i32
+        }
+    }
+    impl AsI32 for char {
+        #[inline]
+        fn as_i32(self) -> i32 {
+            self as i32
+        }
+    }
+    impl AsI32 for usize {
+        #[inline]
+        fn as_i32(self) -> i32 {
+            self as i32
+        }
+    }
+    pub unsafe fn invalid_enum_discriminant<T>() -> T {
+        if cfg!(debug_assertions) {
+            panic!("invalid enum discriminant")
+        } else {
+            unsafe { core::hint::unreachable_unchecked() }
+        }
+    }
+    extern crate alloc as alloc_crate;
 }
 /// Generates `#[unsafe(no_mangle)]` functions to export the specified type as
 /// the root implementation of all generated traits.
@@ -146,12 +1806,32 @@ pub(crate) use __export_markdown_impl as export;
 #[unsafe(link_section = "component-type:wit-bindgen:0.41.0:component:markdown:markdown:encoded world")]
 #[doc(hidden)]
 #[allow(clippy::octal_escapes)]
-pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 228] = *b"\
-\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07f\x01A\x02\x01A\x04\x01\
-@\x01\x04htmls\0s\x04\0\x10html-to-markdown\x01\0\x01@\x01\x04jsons\0s\x04\0\x10\
-json-to-markdown\x01\x01\x04\0\x1bcomponent:markdown/markdown\x04\0\x0b\x0e\x01\0\
-\x08markdown\x03\0\0\0G\x09producers\x01\x0cprocessed-by\x02\x0dwit-component\x07\
-0.227.1\x10wit-bindgen-rust\x060.41.0";
+pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 1171] = *b"\
+\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\x94\x08\x01A\x02\x01\
+A8\x01m\x02\x03atx\x06setext\x03\0\x0dheading-style\x03\0\0\x01m\x02\x06inline\x09\
+reference\x03\0\x0alink-style\x03\0\x02\x01r\x06\x0dheading-style\x01\x06bullett\
+\x0bkeep-images\x7f\x0alink-style\x03\x0emax-line-widthy\x11preserve-raw-html\x7f\
+\x03\0\x12conversion-options\x03\0\x04\x01ps\x01k\x06\x01r\x03\x0callowed-tags\x07\
+\x12allowed-attributes\x07\x12remove-boilerplate\x7f\x03\0\x10sanitize-options\x03\
+\0\x08\x01ky\x01r\x06\x05level}\x04texts\x06anchors\x06parent\x0a\x05starty\x03e\
+ndy\x03\0\x07heading\x03\0\x0b\x01m\x02\x04link\x05image\x03\0\x09link-kind\x03\0\
+\x0d\x01r\x04\x04kind\x0e\x04texts\x06targets\x05titles\x03\0\x04link\x03\0\x0f\x01\
+ks\x01r\x05\x05titles\x04link\x11\x09published\x11\x06author\x11\x07summary\x11\x03\
+\0\x0afeed-entry\x03\0\x12\x01p\x13\x01r\x03\x05titles\x07entries\x14\x08markdow\
+ns\x03\0\x0bfeed-digest\x03\0\x15\x01@\x01\x04htmls\0s\x04\0\x10html-to-markdown\
+\x01\x17\x01j\x01s\x01s\x01@\x02\x04htmls\x07options\x05\0\x18\x04\0\x15html-to-\
+markdown-with\x01\x19\x01@\x01\x04jsons\0\x18\x04\0\x10json-to-markdown\x01\x1a\x01\
+@\x01\x03csvs\0\x18\x04\0\x0fcsv-to-markdown\x01\x1b\x01@\x01\x03tsvs\0\x18\x04\0\
+\x0ftsv-to-markdown\x01\x1c\x01@\x01\x04yamls\0\x18\x04\0\x10yaml-to-markdown\x01\
+\x1d\x01@\x01\x04tomls\0\x18\x04\0\x10toml-to-markdown\x01\x1e\x01@\x01\x03xmls\0\
+\x18\x04\0\x0fxml-to-markdown\x01\x1f\x01j\x01\x16\x01s\x01@\x01\x03xmls\0\x20\x04\
+\0\x10feed-to-markdown\x01!\x01p\x0c\x01@\x01\x08markdowns\0\"\x04\0\x07outline\x01\
+#\x01p\x10\x01@\x01\x08markdowns\0$\x04\0\x0dextract-links\x01%\x01@\x02\x08mark\
+downs\x04path\x06\0\x18\x04\0\x07section\x01&\x01@\x02\x04htmls\x07options\x09\0\
+s\x04\0\x0dsanitize-html\x01'\x01@\x01\x08markdowns\0s\x04\0\x10markdown-to-html\
+\x01(\x04\0\x10markdown-to-text\x01(\x04\0\x1bcomponent:markdown/markdown\x04\0\x0b\
+\x0e\x01\0\x08markdown\x03\0\0\0G\x09producers\x01\x0cprocessed-by\x02\x0dwit-co\
+mponent\x070.227.1\x10wit-bindgen-rust\x060.41.0";
 #[inline(never)]
 #[doc(hidden)]
 pub fn __link_custom_section_describing_imports() {
@@ -10,8 +10,122 @@ impl Guest for Component {
         common::html_to_markdown(&html)
     }
 
-    fn json_to_markdown(json: String) -> String {
-        common::json_to_markdown(&json)
+    fn html_to_markdown_with(
+        html: String,
+        options: bindings::ConversionOptions,
+    ) -> Result<String, String> {
+        let options = common::ConversionOptions {
+            heading_style: match options.heading_style {
+                bindings::HeadingStyle::Atx => common::HeadingStyle::Atx,
+                bindings::HeadingStyle::Setext => common::HeadingStyle::Setext,
+            },
+            bullet: options.bullet,
+            keep_images: options.keep_images,
+            link_style: match options.link_style {
+                bindings::LinkStyle::Inline => common::LinkStyle::Inline,
+                bindings::LinkStyle::Reference => common::LinkStyle::Reference,
+            },
+            max_line_width: options.max_line_width as usize,
+            preserve_raw_html: options.preserve_raw_html,
+        };
+        common::html_to_markdown_with(&html, &options).map_err(|e| e.to_string())
+    }
+
+    fn json_to_markdown(json: String) -> Result<String, String> {
+        common::json_to_markdown(&json).map_err(|e| e.to_string())
+    }
+
+    fn csv_to_markdown(csv: String) -> Result<String, String> {
+        common::csv_to_markdown(&csv, b',').map_err(|e| e.to_string())
+    }
+
+    fn tsv_to_markdown(tsv: String) -> Result<String, String> {
+        common::csv_to_markdown(&tsv, b'\t').map_err(|e| e.to_string())
+    }
+
+    fn yaml_to_markdown(yaml: String) -> Result<String, String> {
+        common::yaml_to_markdown(&yaml).map_err(|e| e.to_string())
+    }
+
+    fn toml_to_markdown(toml: String) -> Result<String, String> {
+        common::toml_to_markdown(&toml).map_err(|e| e.to_string())
+    }
+
+    fn xml_to_markdown(xml: String) -> Result<String, String> {
+        common::xml_to_markdown(&xml).map_err(|e| e.to_string())
+    }
+
+    fn feed_to_markdown(xml: String) -> Result<bindings::FeedDigest, String> {
+        let feed = common::parse_feed(&xml).map_err(|e| e.to_string())?;
+        let markdown = common::feed_to_markdown(&feed);
+        Ok(bindings::FeedDigest {
+            title: feed.title,
+            entries: feed
+                .entries
+                .into_iter()
+                .map(|entry| bindings::FeedEntry {
+                    title: entry.title,
+                    link: entry.link,
+                    published: entry.published,
+                    author: entry.author,
+                    summary: entry.summary,
+                })
+                .collect(),
+            markdown,
+        })
+    }
+
+    fn outline(markdown: String) -> Vec<bindings::Heading> {
+        common::outline(&markdown)
+            .into_iter()
+            .map(|heading| bindings::Heading {
+                level: heading.level,
+                text: heading.text,
+                anchor: heading.anchor,
+                parent: heading.parent.map(|parent| parent as u32),
+                start: heading.start as u32,
+                end: heading.end as u32,
+            })
+            .collect()
+    }
+
+    fn extract_links(markdown: String) -> Vec<bindings::Link> {
+        common::extract_links(&markdown)
+            .into_iter()
+            .map(|link| bindings::Link {
+                kind: match link.kind {
+                    common::LinkKind::Link => bindings::LinkKind::Link,
+                    common::LinkKind::Image => bindings::LinkKind::Image,
+                },
+                text: link.text,
+                target: link.target,
+                title: link.title,
+            })
+            .collect()
+    }
+
+    fn section(markdown: String, path: Vec<String>) -> Result<String, String> {
+        common::section(&markdown, &path).map_err(|e| e.to_string())
+    }
+
+    fn sanitize_html(html: String, options: bindings::SanitizeOptions) -> String {
+        let defaults = common::SanitizeOptions::default();
+        let options = common::SanitizeOptions {
+            allowed_tags: options.allowed_tags.unwrap_or(defaults.allowed_tags),
+            allowed_attributes: options
+                .allowed_attributes
+                .unwrap_or(defaults.allowed_attributes),
+            remove_boilerplate: options.remove_boilerplate,
+        };
+        common::sanitize_html(&html, &options)
+    }
+
+    fn markdown_to_html(markdown: String) -> String {
+        common::markdown_to_html(&markdown)
+    }
+
+    fn markdown_to_text(markdown: String) -> String {
+        common::markdown_to_text(&markdown)
     }
 }
 
@@ -0,0 +1,547 @@
+// Generated by `wit-bindgen` 0.41.0. DO NOT EDIT!
+// Options used:
+//   * runtime_path: "wit_bindgen_rt"
+/// A header name-value pair
+#[derive(Clone)]
+pub struct Header {
+    pub name: _rt::String,
+    pub value: _rt::String,
+}
+impl ::core::fmt::Debug for Header {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct("Header")
+            .field("name", &self.name)
+            .field("value", &self.value)
+            .finish()
+    }
+}
+/// The page to read, fetched from a URL or given directly
+#[derive(Clone)]
+pub enum Source {
+    Url(_rt::String),
+    Html(_rt::String),
+}
+impl ::core::fmt::Debug for Source {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        match self {
+            Source::Url(e) => f.debug_tuple("Source::Url").field(e).finish(),
+            Source::Html(e) => f.debug_tuple("Source::Html").field(e).finish(),
+        }
+    }
+}
+/// A field to extract with a CSS selector
+#[derive(Clone)]
+pub struct Field {
+    /// Key of the field in each record
+    pub name: _rt::String,
+    /// CSS selector matched within each item, e.g. `.price` or `a.title`
+    pub selector: _rt::String,
+    /// Attribute to read, e.g. `href`, instead of the element's text
+    pub attribute: Option<_rt::String>,
+    /// Collect every match into a list instead of taking the first
+    pub list: bool,
+}
+impl ::core::fmt::Debug for Field {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct("Field")
+            .field("name", &self.name)
+            .field("selector", &self.selector)
+            .field("attribute", &self.attribute)
+            .field("list", &self.list)
+            .finish()
+    }
+}
+/// Output format for html-tables
+#[repr(u8)]
+#[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+pub enum TableFormat {
+    /// An array with one object per table, holding its caption, columns and rows
+    Json,
+    /// A header row and the rows of each table, with a blank line between tables
+    Csv,
+}
+impl ::core::fmt::Debug for TableFormat {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        match self {
+            TableFormat::Json => f.debug_tuple("TableFormat::Json").finish(),
+            TableFormat::Csv => f.debug_tuple("TableFormat::Csv").finish(),
+        }
+    }
+}
+impl TableFormat {
+    #[doc(hidden)]
+    pub unsafe fn _lift(val: u8) -> TableFormat {
+        if !cfg!(debug_assertions) {
+            return ::core::mem::transmute(val);
+        }
+        match val {
+            0 => TableFormat::Json,
+            1 => TableFormat::Csv,
+            _ => panic!("invalid enum discriminant"),
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_scrape_cabi<T: Guest>(
+    arg0: i32,
+    arg1: *mut u8,
+    arg2: usize,
+    arg3: *mut u8,
+    arg4: usize,
+    arg5: i32,
+    arg6: *mut u8,
+    arg7: usize,
+    arg8: *mut u8,
+    arg9: usize,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let v2 = match arg0 {
+        0 => {
+            let e2 = {
+                let len0 = arg2;
+                let bytes0 = _rt::Vec::from_raw_parts(arg1.cast(), len0, len0);
+                _rt::string_lift(bytes0)
+            };
+            Source::Url(e2)
+        }
+        n => {
+            debug_assert_eq!(n, 1, "invalid enum discriminant");
+            let e2 = {
+                let len1 = arg2;
+                let bytes1 = _rt::Vec::from_raw_parts(arg1.cast(), len1, len1);
+                _rt::string_lift(bytes1)
+            };
+            Source::Html(e2)
+        }
+    };
+    let base9 = arg3;
+    let len9 = arg4;
+    let mut result9 = _rt::Vec::with_capacity(len9);
+    for i in 0..len9 {
+        let base = base9.add(i * (4 * ::core::mem::size_of::<*const u8>()));
+        let e9 = {
+            let l3 = *base.add(0).cast::<*mut u8>();
+            let l4 = *base
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            let len5 = l4;
+            let bytes5 = _rt::Vec::from_raw_parts(l3.cast(), len5, len5);
+            let l6 = *base
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>();
+            let l7 = *base
+                .add(3 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            let len8 = l7;
+            let bytes8 = _rt::Vec::from_raw_parts(l6.cast(), len8, len8);
+            Header {
+                name: _rt::string_lift(bytes5),
+                value: _rt::string_lift(bytes8),
+            }
+        };
+        result9.push(e9);
+    }
+    _rt::cabi_dealloc(
+        base9,
+        len9 * (4 * ::core::mem::size_of::<*const u8>()),
+        ::core::mem::size_of::<*const u8>(),
+    );
+    let base22 = arg8;
+    let len22 = arg9;
+    let mut result22 = _rt::Vec::with_capacity(len22);
+    for i in 0..len22 {
+        let base = base22.add(i * (8 * ::core::mem::size_of::<*const u8>()));
+        let e22 = {
+            let l11 = *base.add(0).cast::<*mut u8>();
+            let l12 = *base
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            let len13 = l12;
+            let bytes13 = _rt::Vec::from_raw_parts(l11.cast(), len13, len13);
+            let l14 = *base
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>();
+            let l15 = *base
+                .add(3 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            let len16 = l15;
+            let bytes16 = _rt::Vec::from_raw_parts(l14.cast(), len16, len16);
+            let l17 = i32::from(
+                *base
+                    .add(4 * ::core::mem::size_of::<*const u8>())
+                    .cast::<u8>(),
+            );
+            let l21 = i32::from(
+                *base
+                    .add(7 * ::core::mem::size_of::<*const u8>())
+                    .cast::<u8>(),
+            );
+            Field {
+                name: _rt::string_lift(bytes13),
+                selector: _rt::string_lift(bytes16),
+                attribute: match l17 {
+                    0 => None,
+                    1 => {
+                        let e = {
+                            let l18 = *base
+                                .add(5 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l19 = *base
+                                .add(6 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            let len20 = l19;
+                            let bytes20 = _rt::Vec::from_raw_parts(l18.cast(), len20, len20);
+                            _rt::string_lift(bytes20)
+                        };
+                        Some(e)
+                    }
+                    _ => _rt::invalid_enum_discriminant(),
+                },
+                list: _rt::bool_lift(l21 as u8),
+            }
+        };
+        result22.push(e22);
+    }
+    _rt::cabi_dealloc(
+        base22,
+        len22 * (8 * ::core::mem::size_of::<*const u8>()),
+        ::core::mem::size_of::<*const u8>(),
+    );
+    let result23 = T::scrape(
+        v2,
+        result9,
+        match arg5 {
+            0 => None,
+            1 => {
+                let e = {
+                    let len10 = arg7;
+                    let bytes10 = _rt::Vec::from_raw_parts(arg6.cast(), len10, len10);
+                    _rt::string_lift(bytes10)
+                };
+                Some(e)
+            }
+            _ => _rt::invalid_enum_discriminant(),
+        },
+        result22,
+    );
+    let ptr24 = (&raw mut _RET_AREA.0).cast::<u8>();
+    match result23 {
+        Ok(e) => {
+            *ptr24.add(0).cast::<u8>() = (0i32) as u8;
+            let vec25 = (e.into_bytes()).into_boxed_slice();
+            let ptr25 = vec25.as_ptr().cast::<u8>();
+            let len25 = vec25.len();
+            ::core::mem::forget(vec25);
+            *ptr24
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>() = len25;
+            *ptr24
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>() = ptr25.cast_mut();
+        }
+        Err(e) => {
+            *ptr24.add(0).cast::<u8>() = (1i32) as u8;
+            let vec26 = (e.into_bytes()).into_boxed_slice();
+            let ptr26 = vec26.as_ptr().cast::<u8>();
+            let len26 = vec26.len();
+            ::core::mem::forget(vec26);
+            *ptr24
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>() = len26;
+            *ptr24
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>() = ptr26.cast_mut();
+        }
+    };
+    ptr24
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_scrape<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>();
+            let l2 = *arg0
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+        _ => {
+            let l3 = *arg0
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>();
+            let l4 = *arg0
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            _rt::cabi_dealloc(l3, l4, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_html_tables_cabi<T: Guest>(
+    arg0: i32,
+    arg1: *mut u8,
+    arg2: usize,
+    arg3: *mut u8,
+    arg4: usize,
+    arg5: i32,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let v2 = match arg0 {
+        0 => {
+            let e2 = {
+                let len0 = arg2;
+                let bytes0 = _rt::Vec::from_raw_parts(arg1.cast(), len0, len0);
+                _rt::string_lift(bytes0)
+            };
+            Source::Url(e2)
+        }
+        n => {
+            debug_assert_eq!(n, 1, "invalid enum discriminant");
+            let e2 = {
+                let len1 = arg2;
+                let bytes1 = _rt::Vec::from_raw_parts(arg1.cast(), len1, len1);
+                _rt::string_lift(bytes1)
+            };
+            Source::Html(e2)
+        }
+    };
+    let base9 = arg3;
+    let len9 = arg4;
+    let mut result9 = _rt::Vec::with_capacity(len9);
+    for i in 0..len9 {
+        let base = base9.add(i * (4 * ::core::mem::size_of::<*const u8>()));
+        let e9 = {
+            let l3 = *base.add(0).cast::<*mut u8>();
+            let l4 = *base
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            let len5 = l4;
+            let bytes5 = _rt::Vec::from_raw_parts(l3.cast(), len5, len5);
+            let l6 = *base
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>();
+            let l7 = *base
+                .add(3 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            let len8 = l7;
+            let bytes8 = _rt::Vec::from_raw_parts(l6.cast(), len8, len8);
+            Header {
+                name: _rt::string_lift(bytes5),
+                value: _rt::string_lift(bytes8),
+            }
+        };
+        result9.push(e9);
+    }
+    _rt::cabi_dealloc(
+        base9,
+        len9 * (4 * ::core::mem::size_of::<*const u8>()),
+        ::core::mem::size_of::<*const u8>(),
+    );
+    let result10 = T::html_tables(v2, result9, TableFormat::_lift(arg5 as u8));
+    let ptr11 = (&raw mut _RET_AREA.0).cast::<u8>();
+    match result10 {
+        Ok(e) => {
+            *ptr11.add(0).cast::<u8>() = (0i32) as u8;
+            let vec12 = (e.into_bytes()).into_boxed_slice();
+            let ptr12 = vec12.as_ptr().cast::<u8>();
+            let len12 = vec12.len();
+            ::core::mem::forget(vec12);
+            *ptr11
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>() = len12;
+            *ptr11
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>() = ptr12.cast_mut();
+        }
+        Err(e) => {
+            *ptr11.add(0).cast::<u8>() = (1i32) as u8;
+            let vec13 = (e.into_bytes()).into_boxed_slice();
+            let ptr13 = vec13.as_ptr().cast::<u8>();
+            let len13 = vec13.len();
+            ::core::mem::forget(vec13);
+            *ptr11
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>() = len13;
+            *ptr11
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>() = ptr13.cast_mut();
+        }
+    };
+    ptr11
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_html_tables<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>();
+            let l2 = *arg0
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+        _ => {
+            let l3 = *arg0
+                .add(::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>();
+            let l4 = *arg0
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            _rt::cabi_dealloc(l3, l4, 1);
+        }
+    }
+}
+pub trait Guest {
+    /// Extract records from a page as a pretty-printed JSON array.
+    ///
+    /// Each element matching item-selector, such as `li.product`, gives one record with the
+    /// fields selected within it; without an item selector the whole page is one record.
+    /// A field is the text of its first match, or null if nothing matches, and list fields
+    /// hold every match. Relative href and src attributes are resolved against the URL.
+    /// Headers are only used when fetching a URL.
+    fn scrape(
+        source: Source,
+        headers: _rt::Vec<Header>,
+        item_selector: Option<_rt::String>,
+        fields: _rt::Vec<Field>,
+    ) -> Result<_rt::String, _rt::String>;
+    /// Convert every table on a page, including nested tables, to JSON or CSV.
+    ///
+    /// The first row is used as column names when it is in a thead or made of th cells.
+    /// Headers are only used when fetching a URL.
+    fn html_tables(
+        source: Source,
+        headers: _rt::Vec<Header>,
+        format: TableFormat,
+    ) -> Result<_rt::String, _rt::String>;
+}
+#[doc(hidden)]
+macro_rules! __export_world_scrape_cabi {
+    ($ty:ident with_types_in $($path_to_types:tt)*) => {
+        const _ : () = { #[unsafe (export_name = "scrape")] unsafe extern "C" fn
+        export_scrape(arg0 : i32, arg1 : * mut u8, arg2 : usize, arg3 : * mut u8, arg4 :
+        usize, arg5 : i32, arg6 : * mut u8, arg7 : usize, arg8 : * mut u8, arg9 : usize,)
+        -> * mut u8 { unsafe { $($path_to_types)*:: _export_scrape_cabi::<$ty > (arg0,
+        arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9) } } #[unsafe (export_name =
+        "cabi_post_scrape")] unsafe extern "C" fn _post_return_scrape(arg0 : * mut u8,) {
+        unsafe { $($path_to_types)*:: __post_return_scrape::<$ty > (arg0) } } #[unsafe
+        (export_name = "html-tables")] unsafe extern "C" fn export_html_tables(arg0 :
+        i32, arg1 : * mut u8, arg2 : usize, arg3 : * mut u8, arg4 : usize, arg5 : i32,)
+        -> * mut u8 { unsafe { $($path_to_types)*:: _export_html_tables_cabi::<$ty >
+        (arg0, arg1, arg2, arg3, arg4, arg5) } } #[unsafe (export_name =
+        "cabi_post_html-tables")] unsafe extern "C" fn _post_return_html_tables(arg0 : *
+        mut u8,) { unsafe { $($path_to_types)*:: __post_return_html_tables::<$ty > (arg0)
+        } } };
+    };
+}
+#[doc(hidden)]
+pub(crate) use __export_world_scrape_cabi;
+#[cfg_attr(target_pointer_width = "64", repr(align(8)))]
+#[cfg_attr(target_pointer_width = "32", repr(align(4)))]
+struct _RetArea([::core::mem::MaybeUninit<u8>; 3 * ::core::mem::size_of::<*const u8>()]);
+static mut _RET_AREA: _RetArea =
+    _RetArea([::core::mem::MaybeUninit::uninit(); 3 * ::core::mem::size_of::<*const u8>()]);
+#[rustfmt::skip]
+mod _rt {
+    #![allow(dead_code, clippy::all)]
+    pub use alloc_crate::string::String;
+    #[cfg(target_arch = "wasm32")]
+    pub fn run_ctors_once() {
+        wit_bindgen_rt::run_ctors_once();
+    }
+    pub use alloc_crate::vec::Vec;
+    pub unsafe fn string_lift(bytes: Vec<u8>) -> String {
+        if cfg!(debug_assertions) {
+            String::from_utf8(bytes).unwrap()
+        } else {
+            String::from_utf8_unchecked(bytes)
+        }
+    }
+    pub unsafe fn cabi_dealloc(ptr: *mut u8, size: usize, align: usize) {
+        if size == 0 {
+            return;
+        }
+        let layout = alloc::Layout::from_size_align_unchecked(size, align);
+        alloc::dealloc(ptr, layout);
+    }
+    pub unsafe fn invalid_enum_discriminant<T>() -> T {
+        if cfg!(debug_assertions) {
+            panic!("invalid enum discriminant")
+        } else {
+            unsafe { core::hint::unreachable_unchecked() }
+        }
+    }
+    pub unsafe fn bool_lift(val: u8) -> bool {
+        if cfg!(debug_assertions) {
+            match val {
+                0 => false,
+                1 => true,
+                _ => panic!("invalid bool discriminant"),
+            }
+        } else {
+            val != 0
+        }
+    }
+    extern crate alloc as alloc_crate;
+    pub use alloc_crate::alloc;
+}
+/// Generates `#[unsafe(no_mangle)]` functions to export the specified type as
+/// the root implementation of all generated traits.
+///
+/// For more information see the documentation of `wit_bindgen::generate!`.
+///
+/// ```rust
+/// # macro_rules! export{ ($($t:tt)*) => (); }
+/// # trait Guest {}
+/// struct MyType;
+///
+/// impl Guest for MyType {
+///     // ...
+/// }
+///
+/// export!(MyType);
+/// ```
+#[allow(unused_macros)]
+#[doc(hidden)]
+macro_rules! __export_scrape_impl {
+    ($ty:ident) => {
+        self::export!($ty with_types_in self);
+    };
+    ($ty:ident with_types_in $($path_to_types_root:tt)*) => {
+        $($path_to_types_root)*:: __export_world_scrape_cabi!($ty with_types_in
+        $($path_to_types_root)*);
+    };
+}
+#[doc(inline)]
+pub(crate) use __export_scrape_impl as export;
+#[cfg(target_arch = "wasm32")]
+#[unsafe(link_section = "component-type:wit-bindgen:0.41.0:component:scrape:scrape:encoded world")]
+#[doc(hidden)]
+#[allow(clippy::octal_escapes)]
+pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 411] = *b"\
+\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\x9e\x02\x01A\x02\x01\
+A\x10\x01r\x02\x04names\x05values\x03\0\x06header\x03\0\0\x01q\x02\x03url\x01s\0\
+\x04html\x01s\0\x03\0\x06source\x03\0\x02\x01ks\x01r\x04\x04names\x08selectors\x09\
+attribute\x04\x04list\x7f\x03\0\x05field\x03\0\x05\x01m\x02\x04json\x03csv\x03\0\
+\x0ctable-format\x03\0\x07\x01p\x01\x01p\x06\x01j\x01s\x01s\x01@\x04\x06source\x03\
+\x07headers\x09\x0ditem-selector\x04\x06fields\x0a\0\x0b\x04\0\x06scrape\x01\x0c\
+\x01@\x03\x06source\x03\x07headers\x09\x06format\x08\0\x0b\x04\0\x0bhtml-tables\x01\
+\x0d\x04\0\x17component:scrape/scrape\x04\0\x0b\x0c\x01\0\x06scrape\x03\0\0\0G\x09\
+producers\x01\x0cprocessed-by\x02\x0dwit-component\x070.227.1\x10wit-bindgen-rus\
+t\x060.41.0";
+#[inline(never)]
+#[doc(hidden)]
+pub fn __link_custom_section_describing_imports() {
+    wit_bindgen_rt::maybe_link_cabi_realloc();
+}
@@ -0,0 +1,86 @@
+use common::get;
+use std::collections::HashMap;
+
+#[allow(warnings)]
+mod bindings;
+
+use bindings::Guest;
+
+struct Component;
+
+impl Guest for Component {
+    fn scrape(
+        source: bindings::Source,
+        headers: Vec<bindings::Header>,
+        item_selector: Option<String>,
+        fields: Vec<bindings::Field>,
+    ) -> Result<String, String> {
+        let base_url = match &source {
+            bindings::Source::Url(url) => Some(url.clone()),
+            bindings::Source::Html(_) => None,
+        };
+        let html = load(source, headers)?;
+        let fields: Vec<common::FieldSpec> = fields
+            .into_iter()
+            .map(|field| common::FieldSpec {
+                name: field.name,
+                selector: field.selector,
+                attribute: field.attribute,
+                list: field.list,
+            })
+            .collect();
+
+        let records = common::scrape(
+            &html,
+            base_url.as_deref(),
+            item_selector.as_deref(),
+            &fields,
+        )
+        .map_err(|e| e.to_string())?;
+        serde_json::to_string_pretty(&records).map_err(|e| e.to_string())
+    }
+
+    fn html_tables(
+        source: bindings::Source,
+        headers: Vec<bindings::Header>,
+        format: bindings::TableFormat,
+    ) -> Result<String, String> {
+        let html = load(source, headers)?;
+        let tables = common::html_tables(&html);
+        match format {
+            bindings::TableFormat::Json => {
+                serde_json::to_string_pretty(&common::tables_to_json(&tables))
+                    .map_err(|e| e.to_string())
+            }
+            bindings::TableFormat::Csv => common::tables_to_csv(&tables).map_err(|e| e.to_string()),
+        }
+    }
+}
+
+/// Get the HTML of a source, fetching it if it is a URL.
+fn load(source: bindings::Source, headers: Vec<bindings::Header>) -> Result<String, String> {
+    let url = match source {
+        bindings::Source::Html(html) => return Ok(html),
+        bindings::Source::Url(url) => url,
+    };
+    spin_executor::run(async move {
+        let headers: HashMap<String, String> =
+            headers.into_iter().map(|h| (h.name, h.value)).collect();
+        let response = get(&url, &headers)
+            .await
+            .map_err(|e| format!("fetch error: {e}"))?;
+        let content_type = response
+            .header("content-type")
+            .and_then(|value| value.as_str());
+        if common::is_binary(content_type, response.body()) {
+            return Err(format!(
+                "unsupported content type: {} ({} bytes)",
+                content_type.unwrap_or("unknown binary data"),
+                response.body().len()
+            ));
+        }
+        Ok(String::from_utf8_lossy(response.body()).into_owned())
+    })
+}
+
+bindings::export!(Component with_types_in bindings);